@@ -252,7 +252,7 @@ export class SorobanEventWorker {
 
   /**
    * StreamCreatedEvent
-   * Topics : [Symbol("stream_created"), U64(stream_id)]
+   * Topics : [Symbol("stream_created"), U64(stream_id), Address(sender), Address(recipient)]
    * Body   : Map { stream_id, sender, recipient, rate_per_second,
    *                token_address, deposited_amount, start_time }
    */
@@ -349,7 +349,7 @@ export class SorobanEventWorker {
 
   /**
    * StreamToppedUpEvent
-   * Topics : [Symbol("stream_topped_up"), U64(stream_id)]
+   * Topics : [Symbol("stream_topped_up"), U64(stream_id), Address(sender), Address(recipient)]
    * Body   : Map { stream_id, sender, amount, new_deposited_amount }
    */
   private async handleStreamToppedUp(
@@ -405,7 +405,7 @@ export class SorobanEventWorker {
 
   /**
    * TokensWithdrawnEvent
-   * Topics : [Symbol("tokens_withdrawn"), U64(stream_id)]
+   * Topics : [Symbol("tokens_withdrawn"), U64(stream_id), Address(sender), Address(recipient)]
    * Body   : Map { stream_id, recipient, amount, timestamp }
    */
   private async handleTokensWithdrawn(
@@ -471,7 +471,7 @@ export class SorobanEventWorker {
 
   /**
    * StreamCancelledEvent
-   * Topics : [Symbol("stream_cancelled"), U64(stream_id)]
+   * Topics : [Symbol("stream_cancelled"), U64(stream_id), Address(sender), Address(recipient)]
    * Body   : Map { stream_id, sender, recipient, amount_withdrawn,
    *                refunded_amount }
    */
@@ -0,0 +1,96 @@
+#![no_std]
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+
+/// Error surface for `AliasRegistry`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RegistryError {
+    /// No alias is registered under the supplied label.
+    AliasNotFound = 1,
+}
+
+/// Storage keys for the registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Address registered by an organization under a label.
+    Alias(Address, Symbol),
+}
+
+/// Emitted when an organization registers or updates an alias.
+///
+/// Topic: `("alias_set", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasSetEvent {
+    pub org: Address,
+    pub label: Symbol,
+    pub address: Address,
+}
+
+/// Emitted when an organization removes an alias.
+///
+/// Topic: `("alias_removed", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AliasRemovedEvent {
+    pub org: Address,
+    pub label: Symbol,
+}
+
+/// Per-organization address book mapping human-readable labels to addresses.
+///
+/// Each organization address owns its own namespace, so labels never collide
+/// across organizations and only the organization can edit its entries.
+#[contract]
+pub struct AliasRegistry;
+
+#[contractimpl]
+impl AliasRegistry {
+    /// Register or overwrite `label` in `org`'s address book. Org-only.
+    pub fn set_alias(env: Env, org: Address, label: Symbol, address: Address) {
+        org.require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Alias(org.clone(), label.clone()), &address);
+
+        env.events().publish(
+            (Symbol::new(&env, "alias_set"), org.clone()),
+            AliasSetEvent {
+                org,
+                label,
+                address,
+            },
+        );
+    }
+
+    /// Remove `label` from `org`'s address book. Org-only.
+    ///
+    /// # Errors
+    /// - `AliasNotFound` — `label` is not registered for `org`.
+    pub fn remove_alias(env: Env, org: Address, label: Symbol) -> Result<(), RegistryError> {
+        org.require_auth();
+
+        let key = DataKey::Alias(org.clone(), label.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(RegistryError::AliasNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (Symbol::new(&env, "alias_removed"), org.clone()),
+            AliasRemovedEvent { org, label },
+        );
+        Ok(())
+    }
+
+    /// Returns the address `org` registered under `label`, or `None`.
+    pub fn resolve(env: Env, org: Address, label: Symbol) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Alias(org, label))
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+
+/// Registers AliasRegistry and returns its client.
+fn create_registry(env: &Env) -> AliasRegistryClient<'_> {
+    let id = env.register(AliasRegistry, ());
+    AliasRegistryClient::new(env, &id)
+}
+
+#[test]
+fn test_set_and_resolve_alias() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_registry(&env);
+
+    let org = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let label = Symbol::new(&env, "alice");
+
+    assert_eq!(client.resolve(&org, &label), None);
+    client.set_alias(&org, &label, &alice);
+    assert_eq!(client.resolve(&org, &label), Some(alice));
+}
+
+#[test]
+fn test_aliases_are_namespaced_per_org() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_registry(&env);
+
+    let org_a = Address::generate(&env);
+    let org_b = Address::generate(&env);
+    let label = Symbol::new(&env, "payroll");
+    let addr_a = Address::generate(&env);
+    let addr_b = Address::generate(&env);
+
+    client.set_alias(&org_a, &label, &addr_a);
+    client.set_alias(&org_b, &label, &addr_b);
+    assert_eq!(client.resolve(&org_a, &label), Some(addr_a));
+    assert_eq!(client.resolve(&org_b, &label), Some(addr_b));
+}
+
+#[test]
+fn test_remove_alias() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_registry(&env);
+
+    let org = Address::generate(&env);
+    let label = Symbol::new(&env, "bob");
+    client.set_alias(&org, &label, &Address::generate(&env));
+    client.remove_alias(&org, &label);
+
+    assert_eq!(client.resolve(&org, &label), None);
+    assert_eq!(
+        client.try_remove_alias(&org, &label),
+        Err(Ok(RegistryError::AliasNotFound))
+    );
+}
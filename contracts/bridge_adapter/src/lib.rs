@@ -0,0 +1,249 @@
+#![no_std]
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contracterror, contractimpl, contracttype, token, vec,
+    xdr::FromXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Symbol,
+};
+
+/// Error surface for `BridgeAdapter`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AdapterError {
+    /// `initialize` has already been called.
+    AlreadyInitialized = 1,
+    /// The adapter has not been initialized.
+    NotInitialized = 2,
+    /// Caller is not the adapter admin.
+    NotAdmin = 3,
+    /// The calling bridge is not whitelisted.
+    BridgeNotAllowed = 4,
+    /// The payload is not an XDR-encoded `BridgeMessage`.
+    InvalidPayload = 5,
+    /// A message with the same ID has already been delivered.
+    MessageAlreadyProcessed = 6,
+    /// The bridged amount is zero or negative.
+    InvalidAmount = 7,
+}
+
+/// Storage keys for the adapter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Admin allowed to whitelist bridges (singleton).
+    Admin,
+    /// FlowFi stream contract the adapter funds streams on (singleton).
+    Streams,
+    /// Presence marks a bridge contract as whitelisted.
+    Bridge(Address),
+    /// Presence marks a bridge message ID as delivered.
+    Processed(BytesN<32>),
+}
+
+/// Stream creation parameters a remote payer sends along with the bridged
+/// deposit, XDR-encoded into the bridge message payload.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeMessage {
+    /// Bridge-assigned message ID, delivered at most once.
+    pub message_id: BytesN<32>,
+    /// Payer on the source chain, in that chain's address encoding.
+    pub origin: Bytes,
+    /// Stellar-side recipient of the stream.
+    pub recipient: Address,
+    /// Stream duration in seconds.
+    pub duration: u64,
+}
+
+/// Emitted when a bridged deposit opens a stream.
+///
+/// Topic: `("bridged_stream", bridge)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgedStreamEvent {
+    pub bridge: Address,
+    pub message_id: BytesN<32>,
+    pub origin: Bytes,
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin whitelists or removes a bridge.
+///
+/// Topic: `("bridge_updated", bridge)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeUpdatedEvent {
+    pub bridge: Address,
+    pub allowed: bool,
+}
+
+/// Intake point for streams funded from another chain.
+///
+/// A whitelisted bridge contract delivers a deposit together with a
+/// `BridgeMessage`; the adapter pulls the tokens from the bridge and opens a
+/// FlowFi stream to the message's recipient with itself as sender. The
+/// adapter exposes no way to cancel, so bridged streams always run to
+/// completion. If the stream cannot be created the whole delivery reverts
+/// and the tokens stay with the bridge.
+#[contract]
+pub struct BridgeAdapter;
+
+#[contractimpl]
+impl BridgeAdapter {
+    /// Set the admin and the stream contract bridged deposits are streamed on.
+    ///
+    /// # Errors
+    /// - `AlreadyInitialized` — the adapter is already initialized.
+    pub fn initialize(env: Env, admin: Address, streams: Address) -> Result<(), AdapterError> {
+        admin.require_auth();
+        let storage = env.storage().instance();
+        if storage.has(&DataKey::Admin) {
+            return Err(AdapterError::AlreadyInitialized);
+        }
+        storage.set(&DataKey::Admin, &admin);
+        storage.set(&DataKey::Streams, &streams);
+        Ok(())
+    }
+
+    /// Whitelist `bridge`, or remove it when `allowed` is false. Admin-only.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — the adapter is not initialized.
+    /// - `NotAdmin` — `admin` is not the adapter admin.
+    pub fn set_bridge(
+        env: Env,
+        admin: Address,
+        bridge: Address,
+        allowed: bool,
+    ) -> Result<(), AdapterError> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AdapterError::NotInitialized)?;
+        if stored != admin {
+            return Err(AdapterError::NotAdmin);
+        }
+
+        let key = DataKey::Bridge(bridge.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events().publish(
+            (Symbol::new(&env, "bridge_updated"), bridge.clone()),
+            BridgeUpdatedEvent { bridge, allowed },
+        );
+        Ok(())
+    }
+
+    /// Returns whether `bridge` is whitelisted.
+    pub fn is_bridge(env: Env, bridge: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Bridge(bridge))
+    }
+
+    /// Returns whether the bridge message `message_id` has been delivered.
+    pub fn is_processed(env: Env, message_id: BytesN<32>) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Processed(message_id))
+    }
+
+    /// Deliver a bridged deposit of `amount` of `token` and open a stream
+    /// with the parameters XDR-encoded in `payload`. Bridge-only.
+    ///
+    /// The bridge must authorize the adapter's pull of `amount` from it.
+    /// Returns the new stream's ID.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — the adapter is not initialized.
+    /// - `BridgeNotAllowed` — `bridge` is not whitelisted.
+    /// - `InvalidAmount` — `amount` is not positive.
+    /// - `InvalidPayload` — `payload` does not decode to a `BridgeMessage`.
+    /// - `MessageAlreadyProcessed` — the message was already delivered.
+    pub fn receive_deposit(
+        env: Env,
+        bridge: Address,
+        token: Address,
+        amount: i128,
+        payload: Bytes,
+    ) -> Result<u64, AdapterError> {
+        bridge.require_auth();
+        let streams: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Streams)
+            .ok_or(AdapterError::NotInitialized)?;
+        if !Self::is_bridge(env.clone(), bridge.clone()) {
+            return Err(AdapterError::BridgeNotAllowed);
+        }
+        if amount <= 0 {
+            return Err(AdapterError::InvalidAmount);
+        }
+        let message =
+            BridgeMessage::from_xdr(&env, &payload).map_err(|_| AdapterError::InvalidPayload)?;
+        let processed = DataKey::Processed(message.message_id.clone());
+        if env.storage().persistent().has(&processed) {
+            return Err(AdapterError::MessageAlreadyProcessed);
+        }
+        env.storage().persistent().set(&processed, &true);
+
+        let this = env.current_contract_address();
+        token::Client::new(&env, &token).transfer(&bridge, &this, &amount);
+
+        Self::authorize_pull(&env, &streams, &token, amount);
+        let stream_id: u64 = env.invoke_contract(
+            &streams,
+            &Symbol::new(&env, "create_stream"),
+            vec![
+                &env,
+                this.into_val(&env),
+                message.recipient.into_val(&env),
+                token.into_val(&env),
+                amount.into_val(&env),
+                message.duration.into_val(&env),
+            ],
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "bridged_stream"), bridge.clone()),
+            BridgedStreamEvent {
+                bridge,
+                message_id: message.message_id,
+                origin: message.origin,
+                stream_id,
+                recipient: message.recipient,
+                token,
+                amount,
+            },
+        );
+        Ok(stream_id)
+    }
+}
+
+impl BridgeAdapter {
+    /// Pre-authorizes the stream contract's pull of `amount` from the adapter.
+    fn authorize_pull(env: &Env, streams: &Address, token: &Address, amount: i128) {
+        let this = env.current_contract_address();
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (this, streams.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+    }
+}
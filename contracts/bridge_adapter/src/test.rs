@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, xdr::ToXdr};
+
+/// Minimal stand-in for the stream contract's `create_stream` entrypoint:
+/// pulls the deposit from the sender and records the stream's recipient.
+#[contract]
+struct MockStreams;
+
+#[contractimpl]
+impl MockStreams {
+    pub fn create_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token: Address,
+        amount: i128,
+        _duration: u64,
+    ) -> u64 {
+        sender.require_auth();
+        token::Client::new(&env, &token).transfer(
+            &sender,
+            &env.current_contract_address(),
+            &amount,
+        );
+        let id: u64 = env.storage().instance().get(&0_u32).unwrap_or(0) + 1;
+        env.storage().instance().set(&0_u32, &id);
+        env.storage().instance().set(&id, &recipient);
+        id
+    }
+
+    pub fn recipient(env: Env, stream_id: u64) -> Option<Address> {
+        env.storage().instance().get(&stream_id)
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    adapter: BridgeAdapterClient<'a>,
+    streams: MockStreamsClient<'a>,
+    admin: Address,
+    bridge: Address,
+    token: token::Client<'a>,
+}
+
+/// An initialized adapter with one whitelisted bridge holding 1 000 tokens.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let adapter = BridgeAdapterClient::new(&env, &env.register(BridgeAdapter, ()));
+    let streams = MockStreamsClient::new(&env, &env.register(MockStreams, ()));
+    let admin = Address::generate(&env);
+    let bridge = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&bridge, &1_000);
+
+    adapter.initialize(&admin, &streams.address);
+    adapter.set_bridge(&admin, &bridge, &true);
+    Setup {
+        token: token::Client::new(&env, &token),
+        env,
+        adapter,
+        streams,
+        admin,
+        bridge,
+    }
+}
+
+fn message(env: &Env, id: u8, recipient: &Address) -> Bytes {
+    BridgeMessage {
+        message_id: BytesN::from_array(env, &[id; 32]),
+        origin: Bytes::from_array(env, &[0xab; 20]),
+        recipient: recipient.clone(),
+        duration: 100,
+    }
+    .to_xdr(env)
+}
+
+#[test]
+fn test_bridged_deposit_opens_stream_for_recipient() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let payload = message(&s.env, 1, &recipient);
+
+    let id = s
+        .adapter
+        .receive_deposit(&s.bridge, &s.token.address, &600, &payload);
+    assert_eq!(s.streams.recipient(&id), Some(recipient));
+    assert_eq!(s.token.balance(&s.bridge), 400);
+    assert_eq!(s.token.balance(&s.streams.address), 600);
+    assert_eq!(s.token.balance(&s.adapter.address), 0);
+    assert!(s
+        .adapter
+        .is_processed(&BytesN::from_array(&s.env, &[1; 32])));
+
+    // The same message cannot be delivered twice.
+    assert_eq!(
+        s.adapter
+            .try_receive_deposit(&s.bridge, &s.token.address, &400, &payload),
+        Err(Ok(AdapterError::MessageAlreadyProcessed))
+    );
+}
+
+#[test]
+fn test_receive_deposit_rejects_unknown_bridges_and_bad_payloads() {
+    let s = setup();
+    let recipient = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.adapter.try_receive_deposit(
+            &stranger,
+            &s.token.address,
+            &100,
+            &message(&s.env, 1, &recipient)
+        ),
+        Err(Ok(AdapterError::BridgeNotAllowed))
+    );
+    assert_eq!(
+        s.adapter.try_receive_deposit(
+            &s.bridge,
+            &s.token.address,
+            &0,
+            &message(&s.env, 1, &recipient)
+        ),
+        Err(Ok(AdapterError::InvalidAmount))
+    );
+    // Well-formed XDR of the wrong shape; bytes that are not XDR at all
+    // trap in the host instead.
+    assert_eq!(
+        s.adapter
+            .try_receive_deposit(&s.bridge, &s.token.address, &100, &7_u32.to_xdr(&s.env)),
+        Err(Ok(AdapterError::InvalidPayload))
+    );
+
+    assert_eq!(
+        s.adapter.try_set_bridge(&stranger, &stranger, &true),
+        Err(Ok(AdapterError::NotAdmin))
+    );
+    s.adapter.set_bridge(&s.admin, &s.bridge, &false);
+    assert!(!s.adapter.is_bridge(&s.bridge));
+    assert_eq!(s.token.balance(&s.bridge), 1_000);
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, StreamAcceleratedEvent};
+use crate::storage::{load_stream, save_stream};
+use crate::types::{ActivityKind, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Acceleration ─────────────────────────────────────────────────────────
+
+    /// Release the stream's whole remaining balance to the recipient now,
+    /// e.g. as a final settlement or goodwill bonus, and close the stream.
+    /// Sender-only.
+    ///
+    /// Everything not yet withdrawn, accrued or not, is paid out at once,
+    /// bypassing the cooldown, withdrawal cap and clawback window. The
+    /// stream's withdrawal fee applies to it as to any payout, and the
+    /// payout split and payout address are honored. Any holding bucket and
+    /// outstanding bonus go to the recipient too, and pending yield settles
+    /// under the stream's policy. The stream ends `Completed`.
+    ///
+    /// Returns the amount released, withdrawal fee included.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `NotSender`      — caller is not the stream's sender.
+    /// - `StreamInactive` — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition` — the stream is an unclaimed link.
+    /// - `TokenFrozen`    — the stream's token is frozen.
+    pub fn accelerate(env: Env, sender: Address, stream_id: u64) -> Result<i128, StreamError> {
+        sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        if Self::is_unclaimed_link(&env, &stream) {
+            return Err(StreamError::InvalidTransition);
+        }
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let now = env.ledger().timestamp();
+        let amount = stream.deposited_amount - stream.withdrawn_amount;
+        let withdrawal_fee = Self::withdrawal_fee_on(&env, &stream, amount);
+        Self::collect_withdrawal_fee(&env, &stream, stream_id, amount, withdrawal_fee);
+        if amount > withdrawal_fee {
+            let destination = Self::payout_destination(&stream);
+            Self::pay_with_split(&env, &stream, &destination, amount - withdrawal_fee);
+        }
+        let recipient = stream.recipient.clone();
+        if stream.held_amount > 0 {
+            Self::release_hold(&env, &mut stream, stream_id, &recipient);
+        }
+        if Self::bonus_outstanding(&stream) {
+            Self::settle_bonus(&env, &mut stream, stream_id, &recipient);
+        }
+        Self::settle_yield(&env, &mut stream, stream_id);
+
+        Self::transition(&mut stream, StreamStatus::Completed)?;
+        stream.withdrawn_amount = stream.deposited_amount;
+        stream.settled_balance = 0;
+        stream.last_update_time = now;
+        stream.last_withdrawal_time = now;
+        stream.end_time = stream.end_time.min(now);
+        Self::record_volume(&env, &sender, &stream.token_address, amount);
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_accelerated",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamAcceleratedEvent {
+                stream_id,
+                amount,
+                withdrawal_fee,
+                timestamp: now,
+            },
+        );
+        Self::record_activity(&env, ActivityKind::Withdrawn, stream_id, amount);
+        Ok(amount)
+    }
+}
@@ -0,0 +1,65 @@
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, AcknowledgedEvent};
+use crate::storage::{append_acknowledgement, load_acknowledgements, load_stream};
+use crate::types::Acknowledgement;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Acknowledgements ─────────────────────────────────────────────────────
+
+    /// Record the recipient's acknowledgement of the off-chain memo hashed
+    /// to `memo_hash`, such as the agreed terms or a specific payout.
+    /// Recipient-only.
+    ///
+    /// Each acknowledgement is kept on the stream with the total withdrawn
+    /// at the time, so it can later serve as evidence in a payroll or
+    /// contractor dispute. Finished streams can still be acknowledged.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `NotRecipient`   — caller is not the stream's recipient.
+    pub fn acknowledge(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        memo_hash: BytesN<32>,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        let acknowledgement = Acknowledgement {
+            memo_hash: memo_hash.clone(),
+            withdrawn_amount: stream.withdrawn_amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        append_acknowledgement(&env, stream_id, acknowledgement.clone());
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "acknowledged",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            AcknowledgedEvent {
+                stream_id,
+                memo_hash,
+                withdrawn_amount: acknowledgement.withdrawn_amount,
+                timestamp: acknowledgement.timestamp,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the recipient's acknowledgements on `stream_id`, oldest first.
+    pub fn get_acknowledgements(env: Env, stream_id: u64) -> Vec<Acknowledgement> {
+        load_acknowledgements(&env, stream_id)
+    }
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{contractimpl, Env, Vec};
+
+use crate::storage::{
+    load_activity_count, save_activity_count, save_activity_slot, try_load_activity_slot,
+};
+use crate::types::{ActivityKind, ActivityRecord};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Number of activity records kept; older ones are overwritten.
+pub(crate) const RECENT_EVENTS_CAPACITY: u32 = 32;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Recent Events ────────────────────────────────────────────────────────
+
+    /// Returns up to `limit` of the most recent stream lifecycle events
+    /// (creations, top-ups, withdrawals, cancellations and keeper
+    /// settlements), newest first.
+    ///
+    /// Only the last `RECENT_EVENTS_CAPACITY` are kept, so this lets clients
+    /// without event indexing catch up through simulation alone; a gap in
+    /// `seq` since the last poll means records were missed. Records also
+    /// expire after `ACTIVITY_TTL` ledgers, so a quiet protocol may return
+    /// fewer than it has written.
+    pub fn get_recent_events(env: Env, limit: u32) -> Vec<ActivityRecord> {
+        let count = load_activity_count(&env);
+        let take = (limit.min(RECENT_EVENTS_CAPACITY) as u64).min(count);
+        let mut records = Vec::new(&env);
+        for seq in (count - take..count).rev() {
+            let slot = (seq % RECENT_EVENTS_CAPACITY as u64) as u32;
+            if let Some(record) = try_load_activity_slot(&env, slot) {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+}
+
+impl StreamContract {
+    /// Appends a lifecycle event to the recent-events ring buffer and
+    /// forwards it to the stream's subscribers.
+    pub(crate) fn record_activity(env: &Env, kind: ActivityKind, stream_id: u64, amount: i128) {
+        let seq = load_activity_count(env);
+        let slot = (seq % RECENT_EVENTS_CAPACITY as u64) as u32;
+        let record = ActivityRecord {
+            seq,
+            kind,
+            stream_id,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        save_activity_slot(env, slot, &record);
+        save_activity_count(env, seq + 1);
+        Self::notify_subscribers(env, &record);
+    }
+}
@@ -0,0 +1,59 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::storage::{
+    load_admin_log_count, save_admin_log_count, save_admin_log_slot, try_load_admin_log_slot,
+};
+use crate::types::AdminRecord;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Number of admin records kept; older ones are overwritten.
+pub(crate) const ADMIN_LOG_CAPACITY: u32 = 256;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Admin Log ────────────────────────────────────────────────────────────
+
+    /// Returns up to `limit` admin actions from position `start` of the
+    /// admin log, oldest first.
+    ///
+    /// Every successful admin-only call (fee, pause, freeze, allowlist,
+    /// oracle and migration changes among them) is recorded, giving an audit
+    /// trail that does not depend on event retention. Only the last
+    /// `ADMIN_LOG_CAPACITY` are kept: a `start` older than that begins at
+    /// the oldest kept record, which a gap in `seq` reveals.
+    pub fn get_admin_log(env: Env, start: u64, limit: u32) -> Vec<AdminRecord> {
+        let count = load_admin_log_count(&env);
+        let oldest = count.saturating_sub(ADMIN_LOG_CAPACITY as u64);
+        let from = start.max(oldest);
+        let to = from
+            .saturating_add(limit.min(ADMIN_LOG_CAPACITY) as u64)
+            .min(count);
+        let mut records = Vec::new(&env);
+        for seq in from..to {
+            let slot = (seq % ADMIN_LOG_CAPACITY as u64) as u32;
+            if let Some(record) = try_load_admin_log_slot(&env, slot) {
+                records.push_back(record);
+            }
+        }
+        records
+    }
+}
+
+impl StreamContract {
+    /// Appends an admin action to the admin log.
+    pub(crate) fn record_admin_action(env: &Env, admin: &Address, action: &str) {
+        let seq = load_admin_log_count(env);
+        let slot = (seq % ADMIN_LOG_CAPACITY as u64) as u32;
+        save_admin_log_slot(
+            env,
+            slot,
+            &AdminRecord {
+                seq,
+                action: Symbol::new(env, action),
+                admin: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        save_admin_log_count(env, seq + 1);
+    }
+}
@@ -0,0 +1,138 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{
+    PayeeAllowlistToggledEvent, PayeeAllowlistUpdatedEvent, SenderAllowlistToggledEvent,
+    SenderAllowlistUpdatedEvent,
+};
+use crate::storage::{
+    is_allowed_payee, is_allowed_sender, is_payee_allowlist_enabled, save_config,
+    set_allowed_payee, set_allowed_sender, set_payee_allowlist_enabled, try_load_config,
+};
+use crate::types::ProtocolConfig;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Permissioned Mode ────────────────────────────────────────────────────
+
+    /// Turn permissioned mode on or off. Admin-only.
+    ///
+    /// While on, only addresses added with `set_sender_allowed` may create
+    /// streams. Existing streams are unaffected either way.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_sender_allowlist_enabled(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_sender_allowlist_enabled")?;
+        save_config(
+            &env,
+            &ProtocolConfig {
+                sender_allowlist_enabled: enabled,
+                ..config
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "allowlist_toggled"),),
+            SenderAllowlistToggledEvent { enabled },
+        );
+        Ok(())
+    }
+
+    /// Add `sender` to (or remove it from) the permissioned-mode allowlist.
+    /// Admin-only.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_sender_allowed(
+        env: Env,
+        admin: Address,
+        sender: Address,
+        allowed: bool,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_sender_allowed")?;
+        set_allowed_sender(&env, &sender, allowed);
+
+        env.events().publish(
+            (Symbol::new(&env, "allowlist_updated"), sender.clone()),
+            SenderAllowlistUpdatedEvent { sender, allowed },
+        );
+        Ok(())
+    }
+
+    /// Returns whether `sender` may currently create streams: always `true`
+    /// outside permissioned mode, otherwise only for allowlisted addresses.
+    pub fn is_sender_allowed(env: Env, sender: Address) -> bool {
+        Self::require_sender_allowed(&env, &sender).is_ok()
+    }
+
+    // ─── Payee Allowlists ─────────────────────────────────────────────────────
+
+    /// Turn `org`'s payee allowlist on or off. Requires `org`'s authorization.
+    ///
+    /// While on, streams sent by `org` may only pay recipients added with
+    /// `set_payee_allowed`, catching unvetted or mistyped addresses before
+    /// any funds move. Existing streams are unaffected either way.
+    pub fn set_payee_allowlist_enabled(env: Env, org: Address, enabled: bool) {
+        org.require_auth();
+        set_payee_allowlist_enabled(&env, &org, enabled);
+
+        env.events().publish(
+            (Symbol::new(&env, "payee_allowlist_toggled"), org.clone()),
+            PayeeAllowlistToggledEvent { org, enabled },
+        );
+    }
+
+    /// Add `payee` to (or remove it from) `org`'s payee allowlist.
+    /// Requires `org`'s authorization.
+    pub fn set_payee_allowed(env: Env, org: Address, payee: Address, allowed: bool) {
+        org.require_auth();
+        set_allowed_payee(&env, &org, &payee, allowed);
+
+        env.events().publish(
+            (Symbol::new(&env, "payee_allowlist_updated"), org.clone()),
+            PayeeAllowlistUpdatedEvent {
+                org,
+                payee,
+                allowed,
+            },
+        );
+    }
+
+    /// Returns whether `org` may currently stream to `payee`: always `true`
+    /// while `org`'s payee allowlist is off.
+    pub fn is_payee_allowed(env: Env, org: Address, payee: Address) -> bool {
+        Self::require_payee_allowed(&env, &org, &payee).is_ok()
+    }
+}
+
+impl StreamContract {
+    /// Rejects `recipient` if `sender` has a payee allowlist that omits it.
+    pub(crate) fn require_payee_allowed(
+        env: &Env,
+        sender: &Address,
+        recipient: &Address,
+    ) -> Result<(), StreamError> {
+        if is_payee_allowlist_enabled(env, sender) && !is_allowed_payee(env, sender, recipient) {
+            return Err(StreamError::PayeeNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Rejects `sender` if permissioned mode is on and it is not allowlisted.
+    pub(crate) fn require_sender_allowed(env: &Env, sender: &Address) -> Result<(), StreamError> {
+        match try_load_config(env) {
+            Some(cfg) if cfg.sender_allowlist_enabled && !is_allowed_sender(env, sender) => {
+                Err(StreamError::SenderNotAllowed)
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{
+    ApprovalPolicySetEvent, ProposalApprovedEvent, ProposalExecutedEvent, StreamProposedEvent,
+};
+use crate::storage::{
+    load_proposal, next_proposal_id, save_approval_policy, save_proposal, try_load_approval_policy,
+    try_load_proposal,
+};
+use crate::types::{ApprovalPolicy, StreamOptions, StreamProposal};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Multi-Approver Creation ──────────────────────────────────────────────
+
+    /// Register, replace or (with no tiers) remove `org`'s approval policy.
+    ///
+    /// While a policy is in place, `org` cannot create a stream whose amount
+    /// reaches one of its tiers directly; it must go through
+    /// `propose_stream` / `approve_proposal` / `execute_proposal` and gather
+    /// the tier's number of approvals first.
+    ///
+    /// Requires `org`'s authorization. Changing an existing policy also needs
+    /// `signers`: current approvers (each must authorize) at least as many as
+    /// the policy's strictest tier, so a single compromised key cannot lift it.
+    ///
+    /// # Errors
+    /// - `InvalidApprovalPolicy` — duplicate approvers, a tier with a
+    ///   non-positive `min_amount`, or a tier needing 0 or more approvals
+    ///   than there are approvers.
+    /// - `NotApprover`     — a signer is not a current approver.
+    /// - `ApprovalRequired` — too few signers to change the existing policy.
+    pub fn set_approval_policy(
+        env: Env,
+        org: Address,
+        policy: ApprovalPolicy,
+        signers: Vec<Address>,
+    ) -> Result<(), StreamError> {
+        org.require_auth();
+
+        if let Some(current) = try_load_approval_policy(&env, &org) {
+            let mut counted: Vec<Address> = Vec::new(&env);
+            for signer in signers.iter() {
+                if !current.approvers.contains(&signer) {
+                    return Err(StreamError::NotApprover);
+                }
+                if !counted.contains(&signer) {
+                    signer.require_auth();
+                    counted.push_back(signer);
+                }
+            }
+            let strictest = current.tiers.iter().map(|t| t.approvals).max().unwrap_or(0);
+            if counted.len() < strictest {
+                return Err(StreamError::ApprovalRequired);
+            }
+        }
+
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for approver in policy.approvers.iter() {
+            if seen.contains(&approver) {
+                return Err(StreamError::InvalidApprovalPolicy);
+            }
+            seen.push_back(approver);
+        }
+        for tier in policy.tiers.iter() {
+            if tier.min_amount <= 0 || tier.approvals == 0 || tier.approvals > seen.len() {
+                return Err(StreamError::InvalidApprovalPolicy);
+            }
+        }
+
+        let enabled = !policy.tiers.is_empty();
+        save_approval_policy(&env, &org, enabled.then_some(&policy));
+
+        env.events().publish(
+            (Symbol::new(&env, "approval_policy_set"), org.clone()),
+            ApprovalPolicySetEvent {
+                org,
+                approvers: policy.approvers,
+                tiers: policy.tiers,
+            },
+        );
+        Ok(())
+    }
+
+    /// Propose a stream funded by `org`. The proposer must be one of `org`'s
+    /// approvers, and its proposal counts as the first approval.
+    ///
+    /// No funds move until `execute_proposal`. Returns the proposal ID.
+    ///
+    /// # Errors
+    /// - `InvalidApprovalPolicy` — `org` has no approval policy.
+    /// - `NotApprover`     — `proposer` is not one of `org`'s approvers.
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `InvalidDuration` — `duration` is 0.
+    /// - `RateTooLow`      — at the current fee rate the net deposit is
+    ///   smaller than `duration`, so the stream could never be executed.
+    pub fn propose_stream(
+        env: Env,
+        proposer: Address,
+        org: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<u64, StreamError> {
+        proposer.require_auth();
+
+        let policy =
+            try_load_approval_policy(&env, &org).ok_or(StreamError::InvalidApprovalPolicy)?;
+        if !policy.approvers.contains(&proposer) {
+            return Err(StreamError::NotApprover);
+        }
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if duration == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+        let fee_rate_bps = Self::creation_fee_rate(&env, &StreamOptions::new(&env))?;
+        Self::require_streamable(amount, duration, fee_rate_bps)?;
+
+        let proposal_id = next_proposal_id(&env);
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(proposer.clone());
+        save_proposal(
+            &env,
+            proposal_id,
+            &StreamProposal {
+                org: org.clone(),
+                proposer: proposer.clone(),
+                recipient: recipient.clone(),
+                token_address,
+                amount,
+                duration,
+                approvals,
+                required_approvals: Self::required_approvals(&policy, amount),
+                stream_id: 0,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "stream_proposed"), proposal_id),
+            StreamProposedEvent {
+                proposal_id,
+                org,
+                proposer,
+                recipient,
+                amount,
+            },
+        );
+        Ok(proposal_id)
+    }
+
+    /// Approve a pending proposal as one of its org's approvers.
+    ///
+    /// # Errors
+    /// - `ProposalNotFound` — no proposal exists with `proposal_id`.
+    /// - `ProposalExecuted` — the proposal has already been executed.
+    /// - `NotApprover`      — `approver` is not one of the org's current approvers.
+    /// - `AlreadyApproved`  — `approver` has already approved this proposal.
+    pub fn approve_proposal(
+        env: Env,
+        approver: Address,
+        proposal_id: u64,
+    ) -> Result<(), StreamError> {
+        approver.require_auth();
+
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal.stream_id != 0 {
+            return Err(StreamError::ProposalExecuted);
+        }
+        let is_approver = try_load_approval_policy(&env, &proposal.org)
+            .is_some_and(|policy| policy.approvers.contains(&approver));
+        if !is_approver {
+            return Err(StreamError::NotApprover);
+        }
+        if proposal.approvals.contains(&approver) {
+            return Err(StreamError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        save_proposal(&env, proposal_id, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "proposal_approved"), proposal_id),
+            ProposalApprovedEvent {
+                proposal_id,
+                approver,
+                approvals: proposal.approvals.len(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Create the proposed stream once it has enough approvals. Requires the
+    /// org's authorization, as funds move from the org as in `create_stream`.
+    ///
+    /// Approvals are re-checked against the org's current approver set.
+    /// Returns the new stream ID.
+    ///
+    /// # Errors
+    /// - `ProposalNotFound` — no proposal exists with `proposal_id`.
+    /// - `ProposalExecuted` — the proposal has already been executed.
+    /// - `ApprovalRequired` — not enough current approvers have approved.
+    /// - Same as `create_stream` otherwise.
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<u64, StreamError> {
+        let mut proposal = load_proposal(&env, proposal_id)?;
+        if proposal.stream_id != 0 {
+            return Err(StreamError::ProposalExecuted);
+        }
+        let valid_approvals = match try_load_approval_policy(&env, &proposal.org) {
+            Some(policy) => proposal
+                .approvals
+                .iter()
+                .filter(|a| policy.approvers.contains(a))
+                .count() as u32,
+            None => 0,
+        };
+        if valid_approvals < proposal.required_approvals {
+            return Err(StreamError::ApprovalRequired);
+        }
+
+        let options = StreamOptions::new(&env);
+        let stream_id = Self::open_stream(
+            env.clone(),
+            None,
+            proposal.org.clone(),
+            proposal.recipient.clone(),
+            proposal.token_address.clone(),
+            proposal.amount,
+            proposal.duration,
+            options,
+            Funding::Direct,
+        )?;
+        proposal.stream_id = stream_id;
+        save_proposal(&env, proposal_id, &proposal);
+
+        env.events().publish(
+            (Symbol::new(&env, "proposal_executed"), proposal_id),
+            ProposalExecutedEvent {
+                proposal_id,
+                stream_id,
+            },
+        );
+        Ok(stream_id)
+    }
+
+    /// Returns `org`'s approval policy, or `None` if it has none.
+    pub fn get_approval_policy(env: Env, org: Address) -> Option<ApprovalPolicy> {
+        try_load_approval_policy(&env, &org)
+    }
+
+    /// Returns the proposal with `proposal_id`, or `None`.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<StreamProposal> {
+        try_load_proposal(&env, proposal_id)
+    }
+}
+
+impl StreamContract {
+    /// Rejects direct creation of `amount` by `sender` when the sender's
+    /// approval policy requires approvals for it.
+    pub(crate) fn require_no_approval_needed(
+        env: &Env,
+        sender: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        match try_load_approval_policy(env, sender) {
+            Some(policy) if Self::required_approvals(&policy, amount) > 0 => {
+                Err(StreamError::ApprovalRequired)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Approvals the strictest tier reached by `amount` demands (0 if none).
+    fn required_approvals(policy: &ApprovalPolicy, amount: i128) -> u32 {
+        policy
+            .tiers
+            .iter()
+            .filter(|tier| amount >= tier.min_amount)
+            .map(|tier| tier.approvals)
+            .max()
+            .unwrap_or(0)
+    }
+}
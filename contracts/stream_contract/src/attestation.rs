@@ -0,0 +1,76 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::AttestationRegistryUpdatedEvent;
+use crate::storage::{save_config, try_load_config};
+use crate::types::ProtocolConfig;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Attestation Gating ───────────────────────────────────────────────────
+
+    /// Point the protocol at an attestation registry, or pass `None` to turn
+    /// gating off. Admin-only.
+    ///
+    /// The registry must expose `is_attested(account) -> bool`. While set,
+    /// both parties of every new stream must hold a valid attestation.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_attestation_registry(
+        env: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_attestation_registry")?;
+        save_config(
+            &env,
+            &ProtocolConfig {
+                attestation_registry: registry.clone(),
+                ..config
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "attestation_registry"),),
+            AttestationRegistryUpdatedEvent { registry },
+        );
+        Ok(())
+    }
+}
+
+impl StreamContract {
+    /// Requires both `sender` and `recipient` to be attested when an
+    /// attestation registry is configured.
+    pub(crate) fn require_attested(
+        env: &Env,
+        sender: &Address,
+        recipient: &Address,
+    ) -> Result<(), StreamError> {
+        let registry = match try_load_config(env).and_then(|cfg| cfg.attestation_registry) {
+            Some(registry) => registry,
+            None => return Ok(()),
+        };
+        if !Self::is_attested(env, &registry, sender)
+            || !Self::is_attested(env, &registry, recipient)
+        {
+            return Err(StreamError::AttestationMissing);
+        }
+        Ok(())
+    }
+
+    /// Asks `registry` whether `account` holds a valid attestation. A failed
+    /// call counts as not attested.
+    fn is_attested(env: &Env, registry: &Address, account: &Address) -> bool {
+        matches!(
+            env.try_invoke_contract::<bool, InvokeError>(
+                registry,
+                &Symbol::new(env, "is_attested"),
+                vec![env, account.into_val(env)],
+            ),
+            Ok(Ok(true))
+        )
+    }
+}
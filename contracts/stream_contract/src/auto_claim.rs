@@ -0,0 +1,108 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, AutoClaimSetEvent};
+use crate::storage::{load_stream, save_auto_claim, try_load_auto_claim};
+use crate::types::AutoClaimSchedule;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Auto-Claim ───────────────────────────────────────────────────────────
+
+    /// Register how often, and to where, keepers may claim on the
+    /// recipient's behalf with `execute_auto_claim`. Recipient-only.
+    ///
+    /// The first claim falls due `interval` seconds from now. An `interval`
+    /// of 0 clears the schedule. `destination` defaults to the stream's
+    /// payout address.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn set_auto_claim(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        interval: u64,
+        destination: Option<Address>,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+
+        let schedule = AutoClaimSchedule {
+            interval,
+            destination: destination.unwrap_or_else(|| Self::payout_destination(&stream)),
+            next_claim_time: env.ledger().timestamp().saturating_add(interval),
+        };
+        save_auto_claim(
+            &env,
+            stream_id,
+            if interval > 0 { Some(&schedule) } else { None },
+        );
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "auto_claim_set",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            AutoClaimSetEvent {
+                stream_id,
+                interval,
+                destination: schedule.destination,
+                next_claim_time: schedule.next_claim_time,
+            },
+        );
+        Ok(())
+    }
+
+    /// Claim a stream's accrued balance on the recipient's schedule.
+    /// Callable by anyone, but only once per scheduled slot and only to the
+    /// recipient's chosen destination.
+    ///
+    /// Slots missed by late keepers are skipped rather than caught up: the
+    /// next claim falls due at the first slot after now. Returns the gross
+    /// amount withdrawn.
+    ///
+    /// # Errors
+    /// - `RecordNotFound` — the recipient has not set a schedule.
+    /// - `ClaimNotDue`     — the next scheduled claim time has not been reached.
+    /// - Same as `withdraw` otherwise.
+    pub fn execute_auto_claim(env: Env, stream_id: u64) -> Result<i128, StreamError> {
+        let mut schedule =
+            try_load_auto_claim(&env, stream_id).ok_or(StreamError::RecordNotFound)?;
+        let now = env.ledger().timestamp();
+        if now < schedule.next_claim_time {
+            return Err(StreamError::ClaimNotDue);
+        }
+
+        let recipient = load_stream(&env, stream_id)?.recipient;
+        let amount = Self::execute_withdrawal(
+            env.clone(),
+            recipient,
+            stream_id,
+            None,
+            Some(schedule.destination.clone()),
+            None,
+        )?;
+
+        let missed = (now - schedule.next_claim_time) / schedule.interval;
+        schedule.next_claim_time += (missed + 1) * schedule.interval;
+        save_auto_claim(&env, stream_id, Some(&schedule));
+        Ok(amount)
+    }
+
+    /// Returns the stream's auto-claim schedule, or `None` if it has none.
+    pub fn get_auto_claim(env: Env, stream_id: u64) -> Option<AutoClaimSchedule> {
+        try_load_auto_claim(&env, stream_id)
+    }
+}
@@ -0,0 +1,309 @@
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{
+    stream_topics, BasketCancelledEvent, BasketCreatedEvent, BasketWithdrawnEvent,
+};
+use crate::storage::{
+    append_to_token_index, load_basket, next_stream_id, save_basket, try_load_basket,
+};
+use crate::types::{BasketComponent, BasketStream};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Maximum number of distinct tokens in a single basket stream.
+pub(crate) const MAX_BASKET_TOKENS: u32 = 5;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Basket Streams ───────────────────────────────────────────────────────
+
+    /// Create a stream that escrows several tokens at once.
+    ///
+    /// Every `(token, amount)` leg is transferred from `sender`, charged the
+    /// protocol fee, and released linearly over the same `duration`, so the
+    /// recipient always holds the same proportion of each leg.
+    ///
+    /// Returns the new stream ID, drawn from the same counter as plain streams.
+    ///
+    /// # Errors
+    /// - `InvalidBasket`   — no legs, more than `MAX_BASKET_TOKENS`, or a repeated token.
+    /// - `InvalidAmount`   — a leg amount ≤ 0.
+    /// - `InvalidDuration` — `duration` is 0.
+    /// - `SenderNotAllowed` — permissioned mode is on and `sender` is not allowlisted.
+    /// - `PayeeNotAllowed` — `sender`'s payee allowlist is on and omits `recipient`.
+    /// - `AttestationMissing` — a registry is configured and either party lacks an attestation.
+    /// - `InvalidTokenAddress` — a leg token is not a token contract.
+    /// - `ApprovalRequired` — a leg amount needs approvals under `sender`'s policy.
+    /// - `TokenFrozen`     — a leg token is frozen.
+    /// - `DepositCapExceeded` — a leg would exceed its token's deposit cap.
+    /// - `BudgetExceeded`  — a leg exceeds `sender`'s spending limit for its token.
+    pub fn create_basket_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        deposits: Vec<(Address, i128)>,
+        duration: u64,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+
+        if deposits.is_empty() || deposits.len() > MAX_BASKET_TOKENS {
+            return Err(StreamError::InvalidBasket);
+        }
+        if duration == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+        Self::require_sender_allowed(&env, &sender)?;
+        Self::require_payee_allowed(&env, &sender, &recipient)?;
+        Self::require_attested(&env, &sender, &recipient)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+        let mut tokens: Vec<Address> = Vec::new(&env);
+        for (token_address, amount) in deposits.iter() {
+            if amount <= 0 {
+                return Err(StreamError::InvalidAmount);
+            }
+            Self::require_no_approval_needed(&env, &sender, amount)?;
+            if tokens.contains(&token_address) {
+                return Err(StreamError::InvalidBasket);
+            }
+            Self::validate_token_contract(&env, &token_address)?;
+            Self::require_token_not_frozen(&env, &token_address)?;
+            Self::require_within_deposit_cap(&env, &token_address, amount)?;
+            tokens.push_back(token_address);
+        }
+
+        let stream_id = next_stream_id(&env);
+        let start_time = env.ledger().timestamp();
+        let fee_rate_bps = Self::current_fee_rate(&env);
+        for (token_address, amount) in deposits.iter() {
+            Self::record_spend(&env, &sender, &token_address, amount)?;
+        }
+
+        let mut components = Vec::new(&env);
+        let mut deposited_amounts = Vec::new(&env);
+        for (token_address, amount) in deposits.iter() {
+            append_to_token_index(&env, &token_address, stream_id);
+            Self::receive_tokens(&env, &token_address, &sender, amount);
+            let net_amount = Self::collect_fee(
+                &env,
+                &sender,
+                &token_address,
+                amount,
+                fee_rate_bps,
+                stream_id,
+                None,
+            );
+            components.push_back(BasketComponent {
+                token_address,
+                deposited_amount: net_amount,
+                withdrawn_amount: 0,
+            });
+            deposited_amounts.push_back(net_amount);
+        }
+
+        save_basket(
+            &env,
+            stream_id,
+            &BasketStream {
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                components,
+                start_time,
+                duration,
+                is_active: true,
+                fee_rate_bps,
+            },
+        );
+
+        env.events().publish(
+            stream_topics(&env, "basket_created", stream_id, &sender, &recipient),
+            BasketCreatedEvent {
+                stream_id,
+                sender,
+                recipient,
+                tokens,
+                deposited_amounts,
+                start_time,
+                duration,
+            },
+        );
+
+        Ok(stream_id)
+    }
+
+    /// Withdraw every leg's claimable amount from a basket stream in one call.
+    ///
+    /// Returns the amount paid per token, in component order. The stream is
+    /// marked inactive once every leg is fully withdrawn.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no basket stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream is already inactive.
+    /// - `InvalidAmount`   — nothing is claimable on any leg.
+    /// - `TokenFrozen`     — one of the basket's tokens is frozen.
+    pub fn withdraw_basket(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<Vec<i128>, StreamError> {
+        recipient.require_auth();
+
+        let mut basket = load_basket(&env, stream_id)?;
+        if basket.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        if !basket.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+        for component in basket.components.iter() {
+            Self::require_token_not_frozen(&env, &component.token_address)?;
+        }
+
+        let now = env.ledger().timestamp();
+        let amounts = Self::basket_claimable(&env, &basket, now);
+        if amounts.iter().all(|amount| amount <= 0) {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let mut components = Vec::new(&env);
+        for (mut component, amount) in basket.components.iter().zip(amounts.iter()) {
+            if amount > 0 {
+                Self::send_tokens(&env, &component.token_address, &recipient, amount);
+                component.withdrawn_amount += amount;
+                Self::record_volume(&env, &basket.sender, &component.token_address, amount);
+            }
+            components.push_back(component);
+        }
+        basket.components = components;
+        basket.is_active = basket
+            .components
+            .iter()
+            .any(|c| c.withdrawn_amount < c.deposited_amount);
+
+        save_basket(&env, stream_id, &basket);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "basket_withdrawn",
+                stream_id,
+                &basket.sender,
+                &basket.recipient,
+            ),
+            BasketWithdrawnEvent {
+                stream_id,
+                recipient,
+                amounts: amounts.clone(),
+                timestamp: now,
+            },
+        );
+
+        Ok(amounts)
+    }
+
+    /// Cancel an active basket stream.
+    ///
+    /// The recipient receives every leg's accrued amount and the sender is
+    /// refunded the unaccrued remainder of each leg.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no basket stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream is already inactive.
+    pub fn cancel_basket_stream(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut basket = load_basket(&env, stream_id)?;
+        if basket.sender != sender {
+            return Err(StreamError::NotSender);
+        }
+        if !basket.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+
+        let now = env.ledger().timestamp();
+        let paid_amounts = Self::basket_claimable(&env, &basket, now);
+
+        let mut components = Vec::new(&env);
+        let mut refunded_amounts = Vec::new(&env);
+        for (mut component, paid) in basket.components.iter().zip(paid_amounts.iter()) {
+            if paid > 0 {
+                Self::send_tokens(&env, &component.token_address, &basket.recipient, paid);
+                component.withdrawn_amount += paid;
+                Self::record_volume(&env, &sender, &component.token_address, paid);
+            }
+            let refund = component.deposited_amount - component.withdrawn_amount;
+            if refund > 0 {
+                Self::send_tokens(&env, &component.token_address, &sender, refund);
+            }
+            refunded_amounts.push_back(refund);
+            components.push_back(component);
+        }
+        basket.components = components;
+        basket.is_active = false;
+
+        let recipient = basket.recipient.clone();
+        save_basket(&env, stream_id, &basket);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "basket_cancelled",
+                stream_id,
+                &basket.sender,
+                &basket.recipient,
+            ),
+            BasketCancelledEvent {
+                stream_id,
+                sender,
+                recipient,
+                paid_amounts,
+                refunded_amounts,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the basket stream record for `stream_id`, or `None` if it does not exist.
+    pub fn get_basket_stream(env: Env, stream_id: u64) -> Option<BasketStream> {
+        try_load_basket(&env, stream_id)
+    }
+
+    /// Returns the currently claimable amount per leg, in component order, or
+    /// `None` if the basket stream does not exist.
+    pub fn get_basket_claimable(env: Env, stream_id: u64) -> Option<Vec<i128>> {
+        try_load_basket(&env, stream_id).map(|basket| {
+            let mut amounts = Self::basket_claimable(&env, &basket, env.ledger().timestamp());
+            if !basket.is_active {
+                for i in 0..amounts.len() {
+                    amounts.set(i, 0);
+                }
+            }
+            amounts
+        })
+    }
+}
+
+impl StreamContract {
+    /// Claimable amount per leg at `now`: each leg's linear accrual since
+    /// `start_time`, minus what has already been withdrawn.
+    fn basket_claimable(env: &Env, basket: &BasketStream, now: u64) -> Vec<i128> {
+        let elapsed = now.saturating_sub(basket.start_time).min(basket.duration);
+        let mut amounts = Vec::new(env);
+        for component in basket.components.iter() {
+            let streamed = component
+                .deposited_amount
+                .checked_mul(elapsed as i128)
+                .map_or(component.deposited_amount, |v| v / basket.duration as i128);
+            amounts.push_back(streamed - component.withdrawn_amount);
+        }
+        amounts
+    }
+}
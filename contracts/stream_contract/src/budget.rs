@@ -0,0 +1,127 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::SpendingLimitSetEvent;
+use crate::storage::{save_spending_limit, try_load_spending_limit};
+use crate::types::SpendingLimit;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Spending Limits ──────────────────────────────────────────────────────
+
+    /// Cap how much `sender` may commit in `token` per epoch of
+    /// `epoch_length` seconds, across stream creations and top-ups.
+    /// Admin-only. A `limit` of 0 removes the cap.
+    ///
+    /// Epochs are aligned to the moment the cap is set; setting a cap again
+    /// starts a fresh epoch with nothing spent.
+    ///
+    /// # Errors
+    /// - `NotInitialized`  — `initialize` has not been called.
+    /// - `NotAdmin`        — caller is not the current admin.
+    /// - `InvalidAmount`   — `limit` is negative.
+    /// - `InvalidDuration` — a cap is set with a zero `epoch_length`.
+    pub fn set_spending_limit(
+        env: Env,
+        admin: Address,
+        sender: Address,
+        token_address: Address,
+        limit: i128,
+        epoch_length: u64,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_spending_limit")?;
+        if limit < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if limit > 0 && epoch_length == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+
+        let spending_limit = (limit > 0).then(|| SpendingLimit {
+            limit,
+            epoch_length,
+            epoch_start: env.ledger().timestamp(),
+            spent: 0,
+        });
+        save_spending_limit(&env, &sender, &token_address, spending_limit.as_ref());
+
+        env.events().publish(
+            (Symbol::new(&env, "spending_limit_set"), sender.clone()),
+            SpendingLimitSetEvent {
+                sender,
+                token_address,
+                limit,
+                epoch_length,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns `sender`'s spending limit for `token`, rolled over to the
+    /// current epoch, or `None` if uncapped.
+    pub fn get_spending_limit(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+    ) -> Option<SpendingLimit> {
+        try_load_spending_limit(&env, &sender, &token_address)
+            .map(|limit| Self::roll_epoch(limit, env.ledger().timestamp()))
+    }
+}
+
+impl StreamContract {
+    /// Counts `amount` against `sender`'s cap for `token`, if any.
+    ///
+    /// # Errors
+    /// - `BudgetExceeded` — `amount` does not fit in the current epoch's allowance.
+    pub(crate) fn record_spend(
+        env: &Env,
+        sender: &Address,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        let Some(limit) = try_load_spending_limit(env, sender, token_address) else {
+            return Ok(());
+        };
+        let mut limit = Self::roll_epoch(limit, env.ledger().timestamp());
+        let spent = limit.spent.saturating_add(amount);
+        if spent > limit.limit {
+            return Err(StreamError::BudgetExceeded);
+        }
+        limit.spent = spent;
+        save_spending_limit(env, sender, token_address, Some(&limit));
+        Ok(())
+    }
+
+    /// Read-only counterpart of `record_spend`: checks `amount` fits the
+    /// remaining limit without recording it.
+    pub(crate) fn check_spend(
+        env: &Env,
+        sender: &Address,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        match try_load_spending_limit(env, sender, token_address) {
+            Some(limit) => {
+                let limit = Self::roll_epoch(limit, env.ledger().timestamp());
+                if limit.spent.saturating_add(amount) > limit.limit {
+                    return Err(StreamError::BudgetExceeded);
+                }
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Advances `limit` to the epoch containing `now`, resetting `spent`
+    /// if a new epoch has begun.
+    pub(crate) fn roll_epoch(mut limit: SpendingLimit, now: u64) -> SpendingLimit {
+        let elapsed = now.saturating_sub(limit.epoch_start);
+        if elapsed >= limit.epoch_length {
+            limit.epoch_start = now - elapsed % limit.epoch_length;
+            limit.spent = 0;
+        }
+        limit
+    }
+}
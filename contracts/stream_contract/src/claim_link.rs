@@ -0,0 +1,108 @@
+use soroban_sdk::{contractimpl, Address, Bytes, BytesN, Env};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{stream_topics, LinkClaimedEvent};
+use crate::storage::{load_stream, save_claim_link, save_stream, try_load_claim_link};
+use crate::types::{Stream, StreamOptions};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Claim Links ──────────────────────────────────────────────────────────
+
+    /// Stream `amount` over `duration` seconds to whoever first presents the
+    /// preimage of `hash`, a SHA-256 digest.
+    ///
+    /// For "payment link" flows where the payee's address is not known
+    /// upfront: the sender shares the preimage off-chain and the payee calls
+    /// `claim_link` with it. Until then the contract itself stands in as the
+    /// recipient and the stream accrues as usual; the claimant receives
+    /// everything accrued so far. Cancelling an unclaimed link refunds the
+    /// sender in full, and keepers, acceleration, restarts and merges leave
+    /// it alone.
+    ///
+    /// Returns the new stream ID.
+    ///
+    /// # Errors
+    /// Same as `create_stream`. A sender with a payee allowlist, or under an
+    /// attestation registry, cannot create links, as the placeholder
+    /// recipient never passes those checks.
+    pub fn create_link_stream(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        hash: BytesN<32>,
+    ) -> Result<u64, StreamError> {
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        let stream_id = Self::open_stream(
+            env.clone(),
+            None,
+            sender,
+            env.current_contract_address(),
+            token_address,
+            amount,
+            duration,
+            StreamOptions::new(&env),
+            Funding::Direct,
+        )?;
+        save_claim_link(&env, stream_id, Some(&hash));
+        Ok(stream_id)
+    }
+
+    /// Claim the recipient role of link stream `stream_id` by presenting the
+    /// `preimage` of its hash. One-time: the link is spent on success.
+    ///
+    /// The first valid claim wins, so the preimage must only reach the
+    /// intended payee. The claimant is held to the sender's payee allowlist
+    /// and the attestation registry like any recipient.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `InvalidTransition` — the stream is not an unclaimed link.
+    /// - `StreamInactive`    — the stream was cancelled or has completed.
+    /// - `Unauthorized`      — `preimage` does not hash to the stored hash.
+    /// - `PayeeNotAllowed`, `AttestationMissing` — the claimant may not
+    ///   receive from the sender.
+    pub fn claim_link(
+        env: Env,
+        claimant: Address,
+        stream_id: u64,
+        preimage: Bytes,
+    ) -> Result<(), StreamError> {
+        claimant.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        let hash = try_load_claim_link(&env, stream_id).ok_or(StreamError::InvalidTransition)?;
+        Self::validate_stream_active(&stream)?;
+        if env.crypto().sha256(&preimage).to_bytes() != hash {
+            return Err(StreamError::Unauthorized);
+        }
+        Self::require_payee_allowed(&env, &stream.sender, &claimant)?;
+        Self::require_attested(&env, &stream.sender, &claimant)?;
+
+        stream.recipient = claimant.clone();
+        save_stream(&env, stream_id, &stream);
+        save_claim_link(&env, stream_id, None);
+
+        env.events().publish(
+            stream_topics(&env, "link_claimed", stream_id, &stream.sender, &claimant),
+            LinkClaimedEvent {
+                stream_id,
+                recipient: claimant,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+}
+
+impl StreamContract {
+    /// Whether `stream` is a link stream nobody has claimed yet, so the
+    /// contract itself still stands in as its recipient.
+    pub(crate) fn is_unclaimed_link(env: &Env, stream: &Stream) -> bool {
+        stream.recipient == env.current_contract_address()
+    }
+}
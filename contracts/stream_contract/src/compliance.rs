@@ -0,0 +1,140 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{ComplianceHoldEvent, ComplianceOracleUpdatedEvent};
+use crate::storage::{
+    is_wound_down, load_compliance_hold, save_compliance_hold, save_config, try_load_config,
+};
+use crate::types::ProtocolConfig;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Compliance Screening ─────────────────────────────────────────────────
+
+    /// Point the protocol at a compliance oracle, or pass `None` to turn
+    /// screening off. Admin-only.
+    ///
+    /// The oracle must expose `check_transfer(token, to, amount) -> bool`.
+    /// While set, it is consulted before each payout `withdraw` and
+    /// `cancel_stream` make on plain streams (recipient payouts, split
+    /// shares and sender refunds). A rejected payout, or a failed oracle
+    /// call, is withheld in the contract under the payee's compliance hold
+    /// instead of being transferred. Once the protocol winds down the oracle
+    /// is no longer consulted, so payouts never wait on the operator.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_compliance_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Option<Address>,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_compliance_oracle")?;
+        save_config(
+            &env,
+            &ProtocolConfig {
+                compliance_oracle: oracle.clone(),
+                ..config
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "compliance_oracle"),),
+            ComplianceOracleUpdatedEvent { oracle },
+        );
+        Ok(())
+    }
+
+    /// Returns the amount of `token_address` withheld from `account` by the
+    /// compliance oracle.
+    pub fn get_compliance_hold(env: Env, token_address: Address, account: Address) -> i128 {
+        load_compliance_hold(&env, &token_address, &account)
+    }
+
+    /// Pay `account` everything withheld from it in `token_address` once the
+    /// oracle clears the transfer, once screening is turned off, or once the
+    /// protocol winds down. Callable by anyone.
+    ///
+    /// Returns the amount released.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` — nothing is withheld from `account` in the token.
+    /// - `Unauthorized`  — the oracle still rejects the transfer.
+    pub fn release_compliance_hold(
+        env: Env,
+        token_address: Address,
+        account: Address,
+    ) -> Result<i128, StreamError> {
+        let amount = load_compliance_hold(&env, &token_address, &account);
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if !Self::is_cleared(&env, &token_address, &account, amount) {
+            return Err(StreamError::Unauthorized);
+        }
+        save_compliance_hold(&env, &token_address, &account, 0);
+        Self::send_tokens(&env, &token_address, &account, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "compliance_release"), account.clone()),
+            ComplianceHoldEvent {
+                account,
+                token_address,
+                amount,
+                held_total: 0,
+            },
+        );
+        Ok(amount)
+    }
+}
+
+impl StreamContract {
+    /// Sends `amount` of `token` to `to` if the compliance oracle clears it,
+    /// otherwise adds it to `to`'s compliance hold. The withheld amount
+    /// stays in the accounted balance.
+    pub(crate) fn send_screened(env: &Env, token_address: &Address, to: &Address, amount: i128) {
+        if Self::is_cleared(env, token_address, to, amount) {
+            Self::send_tokens(env, token_address, to, amount);
+            return;
+        }
+        let held_total = load_compliance_hold(env, token_address, to) + amount;
+        save_compliance_hold(env, token_address, to, held_total);
+
+        env.events().publish(
+            (Symbol::new(env, "compliance_hold"), to.clone()),
+            ComplianceHoldEvent {
+                account: to.clone(),
+                token_address: token_address.clone(),
+                amount,
+                held_total,
+            },
+        );
+    }
+
+    /// Whether a transfer of `amount` of `token` to `to` may go ahead: true
+    /// without an oracle or after wind-down, otherwise the oracle's answer.
+    /// A failed call counts as a rejection.
+    fn is_cleared(env: &Env, token_address: &Address, to: &Address, amount: i128) -> bool {
+        if is_wound_down(env) {
+            return true;
+        }
+        let Some(oracle) = try_load_config(env).and_then(|cfg| cfg.compliance_oracle) else {
+            return true;
+        };
+        matches!(
+            env.try_invoke_contract::<bool, InvokeError>(
+                &oracle,
+                &Symbol::new(env, "check_transfer"),
+                vec![
+                    env,
+                    token_address.into_val(env),
+                    to.into_val(env),
+                    amount.into_val(env),
+                ],
+            ),
+            Ok(Ok(true))
+        )
+    }
+}
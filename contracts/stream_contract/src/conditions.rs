@@ -0,0 +1,172 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{
+    stream_topics, ConditionOracleUpdatedEvent, StreamConditionMetEvent, StreamConditionSetEvent,
+};
+use crate::storage::{is_condition_oracle, load_stream, save_stream, set_condition_oracle};
+use crate::types::StreamCondition;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Condition Gating ─────────────────────────────────────────────────────
+
+    /// Register (or deregister) `oracle` as a contract streams may be gated
+    /// on. Admin-only.
+    ///
+    /// The oracle must expose `is_condition_met(condition) -> bool`.
+    /// Deregistering only blocks new gates; streams already waiting on the
+    /// oracle still ask it.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_condition_oracle(
+        env: Env,
+        admin: Address,
+        oracle: Address,
+        registered: bool,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_condition_oracle")?;
+        set_condition_oracle(&env, &oracle, registered);
+
+        env.events().publish(
+            (Symbol::new(&env, "condition_oracle"), oracle.clone()),
+            ConditionOracleUpdatedEvent { oracle, registered },
+        );
+        Ok(())
+    }
+
+    /// Returns whether `oracle` is a registered condition oracle.
+    pub fn is_condition_oracle(env: Env, oracle: Address) -> bool {
+        is_condition_oracle(&env, &oracle)
+    }
+
+    /// Pause a running stream until `oracle` reports `condition` true.
+    /// Sender-only.
+    ///
+    /// Everything accrued so far stays claimable; from now on the stream
+    /// accrues nothing, and its `end_time` reads `u64::MAX`, until
+    /// `check_condition` finds the condition met. To gate a stream from the
+    /// start, set `StreamOptions::condition` at creation instead.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `NotSender`         — caller is not the stream's sender.
+    /// - `Unauthorized`      — the oracle is not registered, or the stream
+    ///   is irrevocable.
+    /// - `StreamInactive`    — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition` — the stream is already waiting on a condition,
+    ///   or its cliff has yet to pass.
+    pub fn set_stream_condition(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        oracle: Address,
+        condition: Symbol,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        Self::require_condition_oracle(&env, &oracle)?;
+        Self::require_revocable(&env, stream_id)?;
+        let now = env.ledger().timestamp();
+        if stream.is_gated() || stream.is_locked(now) {
+            return Err(StreamError::InvalidTransition);
+        }
+
+        Self::settle_accrued(&mut stream, now);
+        stream.condition = StreamCondition::Oracle(oracle.clone(), condition.clone());
+        stream.end_time = u64::MAX;
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "condition_set",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamConditionSetEvent {
+                stream_id,
+                oracle,
+                condition,
+            },
+        );
+        Ok(())
+    }
+
+    /// Ask a gated stream's oracle whether its condition holds, and if so
+    /// start (or resume) the stream. Callable by anyone.
+    ///
+    /// Once met, the condition is cleared and the remaining balance streams
+    /// from now at the stream's rate. A failed oracle call counts as not met.
+    ///
+    /// Returns whether the condition was met.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `StreamInactive`    — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition` — the stream is not waiting on a condition.
+    pub fn check_condition(env: Env, stream_id: u64) -> Result<bool, StreamError> {
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+        let StreamCondition::Oracle(oracle, condition) = stream.condition.clone() else {
+            return Err(StreamError::InvalidTransition);
+        };
+        if !Self::is_condition_met(&env, &oracle, &condition) {
+            return Ok(false);
+        }
+
+        let now = env.ledger().timestamp();
+        stream.condition = StreamCondition::Unconditional;
+        stream.last_update_time = now;
+        stream.rate.anchor = now;
+        let unstreamed = stream.deposited_amount - stream.withdrawn_amount - stream.settled_balance;
+        stream.end_time = now.saturating_add(Self::seconds_to_stream(&stream, unstreamed));
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "condition_met",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamConditionMetEvent {
+                stream_id,
+                oracle,
+                condition,
+                end_time: stream.end_time,
+            },
+        );
+        Ok(true)
+    }
+}
+
+impl StreamContract {
+    /// Rejects gating on a contract the admin has not registered.
+    pub(crate) fn require_condition_oracle(env: &Env, oracle: &Address) -> Result<(), StreamError> {
+        if !is_condition_oracle(env, oracle) {
+            return Err(StreamError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Asks `oracle` whether `condition` holds.
+    fn is_condition_met(env: &Env, oracle: &Address, condition: &Symbol) -> bool {
+        matches!(
+            env.try_invoke_contract::<bool, InvokeError>(
+                oracle,
+                &Symbol::new(env, "is_condition_met"),
+                vec![env, condition.into_val(env)],
+            ),
+            Ok(Ok(true))
+        )
+    }
+}
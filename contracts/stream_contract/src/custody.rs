@@ -0,0 +1,293 @@
+use soroban_sdk::{contractimpl, token, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{DepositCapUpdatedEvent, SurplusSkimmedEvent, TokenFreezeUpdatedEvent};
+use crate::storage::{
+    is_token_frozen, load_accounted_balance, load_vault_balance, save_accounted_balance,
+    save_deposit_cap, save_vault_balance, set_token_frozen, try_load_deposit_cap, try_load_stream,
+    try_load_stream_deposit_cap,
+};
+use crate::types::Stream;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// How a deposit is pulled from its funder.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Funding {
+    /// The funder authorizes the call and the transfer directly.
+    Direct,
+    /// As `Direct`, but the entrypoint has already authorized the funder,
+    /// as when one call opens several streams.
+    Preauthorized,
+    /// The funder has approved this contract as spender; an org manager
+    /// acts on its behalf without the funder's signature.
+    Allowance,
+    /// Drawn from the funder's vault balance, already held and accounted
+    /// by the contract; no tokens move.
+    Vault,
+    /// Escrowed earlier by a scheduled top-up and already accounted; no
+    /// tokens move.
+    Escrow,
+}
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Custody ──────────────────────────────────────────────────────────────
+
+    /// Send any `token` balance above what the contract owes its streams to
+    /// `to`, e.g. tokens transferred to the contract by mistake. Admin-only.
+    ///
+    /// Only the surplus over the accounted principal can leave, so a rescue
+    /// never touches user funds. Returns the amount skimmed.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidAmount`  — there is no surplus to skim.
+    pub fn skim(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        to: Address,
+    ) -> Result<i128, StreamError> {
+        Self::require_admin(&env, &admin, "skim")?;
+
+        let surplus = Self::token_surplus(&env, &token_address);
+        if surplus <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &to,
+            &surplus,
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "surplus_skimmed"), token_address.clone()),
+            SurplusSkimmedEvent {
+                token: token_address,
+                to,
+                amount: surplus,
+            },
+        );
+        Ok(surplus)
+    }
+
+    /// Returns the amount of `token` the contract holds for its streams.
+    pub fn get_accounted_balance(env: Env, token_address: Address) -> i128 {
+        load_accounted_balance(&env, &token_address)
+    }
+
+    /// Returns the amount of `token` held above the accounted balance, which
+    /// `skim` can recover.
+    pub fn get_token_surplus(env: Env, token_address: Address) -> i128 {
+        Self::token_surplus(&env, &token_address).max(0)
+    }
+
+    /// Freeze `token` after an incident with its contract. Admin-only.
+    ///
+    /// While frozen, no stream in that token can be created, topped up or
+    /// withdrawn from; cancellations still settle. Other tokens are unaffected.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn freeze_token(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), StreamError> {
+        Self::set_token_freeze(&env, &admin, token_address, true)
+    }
+
+    /// Lift a freeze placed by `freeze_token`. Admin-only.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn unfreeze_token(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), StreamError> {
+        Self::set_token_freeze(&env, &admin, token_address, false)
+    }
+
+    /// Returns whether `token` is frozen.
+    pub fn is_token_frozen(env: Env, token_address: Address) -> bool {
+        is_token_frozen(&env, &token_address)
+    }
+
+    /// Cap the accounted balance of `token`, or pass `None` to lift the cap.
+    /// Admin-only.
+    ///
+    /// Deposits that would push the balance over the cap are rejected, which
+    /// limits exposure to newly listed assets. Lowering the cap below the
+    /// current balance only blocks new deposits.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidAmount`  — `cap` is negative.
+    pub fn set_token_deposit_cap(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        cap: Option<i128>,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_token_deposit_cap")?;
+        if cap.is_some_and(|cap| cap < 0) {
+            return Err(StreamError::InvalidAmount);
+        }
+        save_deposit_cap(&env, &token_address, cap);
+
+        env.events().publish(
+            (
+                Symbol::new(&env, "deposit_cap_updated"),
+                token_address.clone(),
+            ),
+            DepositCapUpdatedEvent {
+                token: token_address,
+                cap,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the deposit cap of `token`, or `None` if uncapped.
+    pub fn get_token_deposit_cap(env: Env, token_address: Address) -> Option<i128> {
+        try_load_deposit_cap(&env, &token_address)
+    }
+
+    /// Returns how much more net deposit `stream_id` accepts before reaching
+    /// the cap set at creation, or `None` if the stream does not exist or
+    /// is uncapped.
+    pub fn get_remaining_capacity(env: Env, stream_id: u64) -> Option<i128> {
+        let cap = try_load_stream_deposit_cap(&env, stream_id)?;
+        let stream = try_load_stream(&env, stream_id)?;
+        Some((cap - stream.deposited_amount).max(0))
+    }
+}
+
+impl StreamContract {
+    /// Rejects operations on a frozen token.
+    pub(crate) fn require_token_not_frozen(
+        env: &Env,
+        token_address: &Address,
+    ) -> Result<(), StreamError> {
+        if is_token_frozen(env, token_address) {
+            return Err(StreamError::TokenFrozen);
+        }
+        Ok(())
+    }
+
+    /// Rejects a deposit of `amount` that would push `token`'s accounted
+    /// balance over its cap.
+    pub(crate) fn require_within_deposit_cap(
+        env: &Env,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        if let Some(cap) = try_load_deposit_cap(env, token_address) {
+            let balance = load_accounted_balance(env, token_address);
+            if balance.saturating_add(amount) > cap {
+                return Err(StreamError::DepositCapExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects a deposit that has pushed `stream`'s net deposit over the cap
+    /// set at its creation.
+    pub(crate) fn require_within_stream_cap(
+        env: &Env,
+        stream_id: u64,
+        stream: &Stream,
+    ) -> Result<(), StreamError> {
+        match try_load_stream_deposit_cap(env, stream_id) {
+            Some(cap) if stream.deposited_amount > cap => Err(StreamError::DepositCapExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    /// Shared body of `freeze_token` / `unfreeze_token`.
+    fn set_token_freeze(
+        env: &Env,
+        admin: &Address,
+        token_address: Address,
+        frozen: bool,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(
+            env,
+            admin,
+            if frozen {
+                "freeze_token"
+            } else {
+                "unfreeze_token"
+            },
+        )?;
+        set_token_frozen(env, &token_address, frozen);
+
+        env.events().publish(
+            (
+                Symbol::new(env, "token_freeze_updated"),
+                token_address.clone(),
+            ),
+            TokenFreezeUpdatedEvent {
+                token: token_address,
+                frozen,
+            },
+        );
+        Ok(())
+    }
+
+    /// Pulls `amount` of `token` from `from` into the contract and adds it to
+    /// the accounted balance. Every inbound transfer goes through here.
+    pub(crate) fn receive_tokens(env: &Env, token_address: &Address, from: &Address, amount: i128) {
+        Self::receive_funding(env, token_address, from, amount, Funding::Direct);
+    }
+
+    /// `receive_tokens`, pulling through the token allowance `from` granted
+    /// this contract when `funding` is `Allowance`, or debiting `from`'s
+    /// vault when it is `Vault` (the caller has checked the vault covers it).
+    /// `Escrow` funds are already held, so nothing happens.
+    pub(crate) fn receive_funding(
+        env: &Env,
+        token_address: &Address,
+        from: &Address,
+        amount: i128,
+        funding: Funding,
+    ) {
+        let contract = env.current_contract_address();
+        let client = token::Client::new(env, token_address);
+        match funding {
+            Funding::Direct | Funding::Preauthorized => client.transfer(from, &contract, &amount),
+            Funding::Allowance => client.transfer_from(&contract, from, &contract, &amount),
+            Funding::Vault => {
+                let vault = load_vault_balance(env, from, token_address);
+                save_vault_balance(env, from, token_address, vault - amount);
+                return;
+            }
+            Funding::Escrow => return,
+        }
+        let balance = load_accounted_balance(env, token_address);
+        save_accounted_balance(env, token_address, balance + amount);
+    }
+
+    /// Sends `amount` of `token` from the contract to `to` and removes it
+    /// from the accounted balance. Every outbound transfer goes through here.
+    pub(crate) fn send_tokens(env: &Env, token_address: &Address, to: &Address, amount: i128) {
+        token::Client::new(env, token_address).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount,
+        );
+        let balance = load_accounted_balance(env, token_address);
+        save_accounted_balance(env, token_address, balance - amount);
+    }
+
+    /// Contract balance of `token` minus the accounted balance.
+    fn token_surplus(env: &Env, token_address: &Address) -> i128 {
+        let held = token::Client::new(env, token_address).balance(&env.current_contract_address());
+        held - load_accounted_balance(env, token_address)
+    }
+}
@@ -0,0 +1,157 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::DelegationUpdatedEvent;
+use crate::storage::{load_stream, save_delegation, try_load_delegation};
+use crate::types::{Delegation, Stream};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Vote Delegation ──────────────────────────────────────────────────────
+
+    /// Delegate the voting power of a stream's unvested balance to its
+    /// recipient while the tokens stay locked in escrow.
+    ///
+    /// Calls `set_delegation(delegator, stream_id, delegatee, amount)` on
+    /// `governance`, with this contract as delegator. The delegated amount
+    /// shrinks as the stream vests; anyone may refresh it with
+    /// `sync_delegation`.
+    ///
+    /// Returns the amount delegated.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`   — no stream exists with `stream_id`.
+    /// - `NotRecipient`     — caller is not the stream's recipient.
+    /// - `StreamInactive`   — stream has been cancelled or fully withdrawn.
+    /// - `DelegationFailed` — `governance` rejected the call.
+    pub fn delegate_unvested(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        governance: Address,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+
+        // Revoke any delegation through a previous governance contract.
+        if let Some(previous) = try_load_delegation(&env, stream_id) {
+            if previous.governance != governance {
+                Self::apply_delegation(&env, stream_id, previous, 0)?;
+            }
+        }
+
+        let delegation = Delegation {
+            governance,
+            delegatee: recipient,
+            amount: 0,
+        };
+        let amount = Self::unvested_amount(&env, &stream);
+        Self::apply_delegation(&env, stream_id, delegation, amount)?;
+        Ok(amount)
+    }
+
+    /// Resync a stream's delegation to its current unvested balance.
+    /// Callable by anyone. Once the stream is inactive the delegation is
+    /// revoked and removed.
+    ///
+    /// Returns the amount now delegated.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`     — no stream exists with `stream_id`.
+    /// - `RecordNotFound`     — the stream has no delegation.
+    /// - `DelegationFailed`   — the governance contract rejected the call.
+    pub fn sync_delegation(env: Env, stream_id: u64) -> Result<i128, StreamError> {
+        let stream = load_stream(&env, stream_id)?;
+        let delegation = try_load_delegation(&env, stream_id).ok_or(StreamError::RecordNotFound)?;
+        let amount = Self::unvested_amount(&env, &stream);
+        Self::apply_delegation(&env, stream_id, delegation, amount)?;
+        Ok(amount)
+    }
+
+    /// Revoke a stream's delegation. Recipient-only.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`     — no stream exists with `stream_id`.
+    /// - `NotRecipient`       — caller is not the stream's recipient.
+    /// - `RecordNotFound`     — the stream has no delegation.
+    /// - `DelegationFailed`   — the governance contract rejected the call.
+    pub fn undelegate_unvested(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        let delegation = try_load_delegation(&env, stream_id).ok_or(StreamError::RecordNotFound)?;
+        Self::apply_delegation(&env, stream_id, delegation, 0)
+    }
+
+    /// Returns the stream's delegation, or `None` if it has none.
+    pub fn get_delegation(env: Env, stream_id: u64) -> Option<Delegation> {
+        try_load_delegation(&env, stream_id)
+    }
+}
+
+impl StreamContract {
+    /// Balance still locked in the stream: deposited, not yet withdrawn
+    /// and not yet accrued to the recipient.
+    fn unvested_amount(env: &Env, stream: &Stream) -> i128 {
+        if !stream.is_active() {
+            return 0;
+        }
+        let claimable = Self::calculate_claimable(stream, env.ledger().timestamp());
+        (stream.deposited_amount - stream.withdrawn_amount - claimable).max(0)
+    }
+
+    /// Sets the delegated amount on the governance contract and persists the
+    /// record; an `amount` of 0 revokes it and removes the record.
+    fn apply_delegation(
+        env: &Env,
+        stream_id: u64,
+        mut delegation: Delegation,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        let result = env.try_invoke_contract::<(), InvokeError>(
+            &delegation.governance,
+            &Symbol::new(env, "set_delegation"),
+            vec![
+                env,
+                env.current_contract_address().into_val(env),
+                stream_id.into_val(env),
+                delegation.delegatee.into_val(env),
+                amount.into_val(env),
+            ],
+        );
+        if !matches!(result, Ok(Ok(()))) {
+            return Err(StreamError::DelegationFailed);
+        }
+
+        delegation.amount = amount;
+        save_delegation(
+            env,
+            stream_id,
+            if amount > 0 { Some(&delegation) } else { None },
+        );
+
+        env.events().publish(
+            (Symbol::new(env, "delegation_updated"), stream_id),
+            DelegationUpdatedEvent {
+                stream_id,
+                governance: delegation.governance,
+                delegatee: delegation.delegatee,
+                amount,
+            },
+        );
+        Ok(())
+    }
+}
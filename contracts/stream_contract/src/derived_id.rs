@@ -0,0 +1,165 @@
+use soroban_sdk::{contractimpl, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::storage::{bump_derived_nonce, load_derived_nonce, stream_exists};
+use crate::types::StreamOptions;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// High bit set on every derived stream ID, keeping derived IDs out of the
+/// range the monotonic counter can reach.
+const DERIVED_ID_FLAG: u64 = 1 << 63;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Salted Creation ──────────────────────────────────────────────────────
+
+    /// Create a stream whose ID is derived from `(sender, salt)` instead of
+    /// the counter, making creation idempotent: retrying with the same salt
+    /// fails instead of opening a second stream.
+    ///
+    /// Otherwise behaves like `create_stream`. Returns the derived ID, which
+    /// `get_salted_stream_id` predicts ahead of time.
+    ///
+    /// # Errors
+    /// - `DuplicateSalt`   — `sender` already used `salt`.
+    /// - Same as `create_stream` otherwise.
+    pub fn create_stream_with_salt(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        salt: BytesN<32>,
+    ) -> Result<u64, StreamError> {
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        let stream_id = Self::salted_stream_id(&env, &sender, &salt);
+        if stream_exists(&env, stream_id) {
+            return Err(StreamError::DuplicateSalt);
+        }
+        let options = StreamOptions::new(&env);
+        Self::open_stream(
+            env,
+            Some(stream_id),
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+            Funding::Direct,
+        )
+    }
+
+    /// Returns the ID `create_stream_with_salt` would assign for
+    /// `(sender, salt)`, whether or not it has been used yet.
+    pub fn get_salted_stream_id(env: Env, sender: Address, salt: BytesN<32>) -> u64 {
+        Self::salted_stream_id(&env, &sender, &salt)
+    }
+
+    // ─── Parameter-Derived IDs ────────────────────────────────────────────────
+
+    /// Create a stream whose ID is derived by hashing its creation parameters
+    /// and `sender`'s derived-ID nonce, so an integrating contract can learn
+    /// the ID with `predict_stream_id` before creating it.
+    ///
+    /// The nonce advances on every call, so identical parameters still get
+    /// distinct IDs. Otherwise behaves like `create_stream`.
+    ///
+    /// # Errors
+    /// Same as `create_stream`.
+    pub fn create_stream_derived(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<u64, StreamError> {
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        let nonce = load_derived_nonce(&env, &sender);
+        let stream_id = Self::derived_stream_id(
+            &env,
+            &sender,
+            &recipient,
+            &token_address,
+            amount,
+            duration,
+            nonce,
+        );
+        bump_derived_nonce(&env, &sender, nonce + 1);
+        let options = StreamOptions::new(&env);
+        Self::open_stream(
+            env,
+            Some(stream_id),
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+            Funding::Direct,
+        )
+    }
+
+    /// Returns the ID the next `create_stream_derived` call with these
+    /// parameters would assign.
+    pub fn predict_stream_id(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> u64 {
+        let nonce = load_derived_nonce(&env, &sender);
+        Self::derived_stream_id(
+            &env,
+            &sender,
+            &recipient,
+            &token_address,
+            amount,
+            duration,
+            nonce,
+        )
+    }
+}
+
+impl StreamContract {
+    /// Derived ID for `create_stream_with_salt`: hashes `xdr(sender) ‖ salt`.
+    pub(crate) fn salted_stream_id(env: &Env, sender: &Address, salt: &BytesN<32>) -> u64 {
+        let mut preimage = sender.clone().to_xdr(env);
+        preimage.append(&salt.clone().into());
+        Self::id_from_preimage(env, &preimage)
+    }
+
+    /// Derived ID for `create_stream_derived`, domain-separated from salted
+    /// IDs by a leading `"params"` tag.
+    fn derived_stream_id(
+        env: &Env,
+        sender: &Address,
+        recipient: &Address,
+        token_address: &Address,
+        amount: i128,
+        duration: u64,
+        nonce: u64,
+    ) -> u64 {
+        let mut preimage = Symbol::new(env, "params").to_xdr(env);
+        preimage.append(&sender.clone().to_xdr(env));
+        preimage.append(&recipient.clone().to_xdr(env));
+        preimage.append(&token_address.clone().to_xdr(env));
+        preimage.append(&amount.to_xdr(env));
+        preimage.append(&duration.to_xdr(env));
+        preimage.append(&nonce.to_xdr(env));
+        Self::id_from_preimage(env, &preimage)
+    }
+
+    /// First 8 bytes of `sha256(preimage)`, with `DERIVED_ID_FLAG` set.
+    fn id_from_preimage(env: &Env, preimage: &Bytes) -> u64 {
+        let hash = env.crypto().sha256(preimage).to_array();
+        let mut id = [0u8; 8];
+        id.copy_from_slice(&hash[..8]);
+        u64::from_be_bytes(id) | DERIVED_ID_FLAG
+    }
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::events::DistributorUpdatedEvent;
+use crate::storage::{save_distributor, try_load_distributor, try_load_stream};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Distributors ─────────────────────────────────────────────────────────
+
+    /// Let `operator` claim every stream paying `recipient` through
+    /// `distribute`, or revoke the approval with `None`. Recipient-only.
+    ///
+    /// The operator only triggers claims: the tokens always go to the
+    /// recipient's payout destination, never to the operator.
+    pub fn set_distributor(env: Env, recipient: Address, operator: Option<Address>) {
+        recipient.require_auth();
+
+        save_distributor(&env, &recipient, operator.as_ref());
+        env.events().publish(
+            (Symbol::new(&env, "distributor_updated"), recipient.clone()),
+            DistributorUpdatedEvent {
+                recipient,
+                operator,
+            },
+        );
+    }
+
+    /// Returns the operator `recipient` approved to distribute its streams.
+    pub fn get_distributor(env: Env, recipient: Address) -> Option<Address> {
+        try_load_distributor(&env, &recipient)
+    }
+
+    /// Claim each of `stream_ids` for its recipient and deliver the accrued
+    /// balance to the recipient's payout destination, e.g. to push a whole
+    /// payroll on payday in one transaction.
+    ///
+    /// Each claim is an ordinary withdrawal, with the stream's fees, split,
+    /// cap and cooldown. IDs whose recipient has not approved `operator`,
+    /// or that could not be withdrawn right now, are skipped rather than
+    /// failing the batch.
+    ///
+    /// Returns the IDs that were paid.
+    pub fn distribute(env: Env, operator: Address, stream_ids: Vec<u64>) -> Vec<u64> {
+        operator.require_auth();
+
+        let mut paid = Vec::new(&env);
+        for stream_id in stream_ids.iter() {
+            let Some(stream) = try_load_stream(&env, stream_id) else {
+                continue;
+            };
+            if try_load_distributor(&env, &stream.recipient).as_ref() != Some(&operator) {
+                continue;
+            }
+            if Self::execute_withdrawal(env.clone(), stream.recipient, stream_id, None, None, None)
+                .is_ok()
+            {
+                paid.push_back(stream_id);
+            }
+        }
+        paid
+    }
+}
@@ -0,0 +1,197 @@
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, DonationReceivedEvent, StreamCreatedEvent};
+use crate::storage::{
+    append_to_token_index, extend_stream_ttl, load_stream, next_stream_id, save_stream,
+    save_stream_terms,
+};
+use crate::types::{ExactRate, Stream, StreamCategory, StreamCondition, StreamStatus, YieldPolicy};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Donation Streams ─────────────────────────────────────────────────────
+
+    /// Open an empty donation stream paying `creator` at `rate_per_second`.
+    ///
+    /// The creator is both sender and recipient: anyone may fund the stream
+    /// with `donate`, and donations are released to the creator at the fixed
+    /// rate. The fee is the `Donation` category rate, which the admin can set
+    /// (or waive) independently with `set_category_fee_rate`.
+    ///
+    /// Donation streams stay active when drained so later donations resume
+    /// streaming; the creator may close one with `cancel_stream`.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `rate_per_second` ≤ 0.
+    /// - `AttestationMissing` — a registry is configured and `creator` lacks an attestation.
+    /// - `InvalidTokenAddress` — `token_address` is not a token contract.
+    /// - `TokenFrozen`     — `token_address` is frozen.
+    pub fn create_donation_stream(
+        env: Env,
+        creator: Address,
+        token_address: Address,
+        rate_per_second: i128,
+    ) -> Result<u64, StreamError> {
+        creator.require_auth();
+
+        if rate_per_second <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::require_attested(&env, &creator, &creator)?;
+        let token_decimals = Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+
+        let fee_rate_bps = Self::category_fee_rate(&env, &StreamCategory::Donation);
+        let stream_id = next_stream_id(&env);
+        let start_time = env.ledger().timestamp();
+
+        let mut stream = Stream {
+            sender: creator.clone(),
+            recipient: creator.clone(),
+            token_address: token_address.clone(),
+            rate: ExactRate {
+                numerator: rate_per_second,
+                denominator: 1,
+                anchor: start_time,
+                cliff: 0,
+            },
+            deposited_amount: 0,
+            withdrawn_amount: 0,
+            start_time,
+            last_update_time: start_time,
+            status: StreamStatus::Active,
+            fee_rate_bps,
+            withdrawal_cooldown: 0,
+            last_withdrawal_time: start_time,
+            settled_balance: 0,
+            withdrawal_cap: 0,
+            withdrawal_cap_period: 0,
+            cap_window_start: start_time,
+            cap_window_withdrawn: 0,
+            tags: Vec::new(&env),
+            end_time: start_time,
+            bonus_amount: 0,
+            bonus_claimed: false,
+            clawback_window: 0,
+            held_amount: 0,
+            held_until: 0,
+            hold_contested: false,
+            split_address: None,
+            split_bps: 0,
+            payout_address: None,
+            beneficiary: None,
+            beneficiary_inactivity_period: 0,
+            cancel_at: 0,
+            category: StreamCategory::Donation,
+            partner: None,
+            live_until_ledger: 0,
+            withdrawal_fee_bps: Self::current_withdrawal_fee_rate(&env),
+            relayer_fee: 0,
+            token_decimals,
+            yield_policy: YieldPolicy::Sender,
+            pending_yield: 0,
+            condition: StreamCondition::Unconditional,
+        };
+        append_to_token_index(&env, &token_address, stream_id);
+        save_stream_terms(&env, stream_id, &stream);
+        extend_stream_ttl(&env, stream_id, &mut stream);
+        Self::mint_receipt(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_created",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamCreatedEvent {
+                stream_id,
+                sender: creator.clone(),
+                recipient: creator,
+                rate_per_second,
+                token_address,
+                deposited_amount: 0,
+                start_time,
+            },
+        );
+
+        Ok(stream_id)
+    }
+
+    /// Donate `amount` to a donation stream. Callable by anyone.
+    ///
+    /// The stream's locked-in fee is deducted and the net amount is queued
+    /// behind any unstreamed balance, extending `end_time` at the stream's
+    /// rate. Returns the net amount credited.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `Unauthorized`    — the stream is not a donation stream.
+    /// - `StreamInactive`  — the creator has closed the stream.
+    /// - `TokenFrozen`     — the stream's token is frozen.
+    /// - `DepositCapExceeded` — `amount` would exceed the token's deposit cap.
+    pub fn donate(
+        env: Env,
+        donor: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        donor.require_auth();
+
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.category != StreamCategory::Donation {
+            return Err(StreamError::Unauthorized);
+        }
+        Self::validate_stream_active(&stream)?;
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::require_within_deposit_cap(&env, &stream.token_address, amount)?;
+
+        Self::receive_tokens(&env, &stream.token_address, &donor, amount);
+        let net_amount = Self::collect_fee(
+            &env,
+            &donor,
+            &stream.token_address,
+            amount,
+            stream.fee_rate_bps,
+            stream_id,
+            None,
+        );
+
+        // Accrual never runs ahead of the balance, so a drained stream
+        // restarts from now rather than paying out the idle gap.
+        Self::settle_accrued(&mut stream, env.ledger().timestamp());
+        stream.deposited_amount += net_amount;
+        let unstreamed = stream.deposited_amount - stream.withdrawn_amount - stream.settled_balance;
+        let seconds = Self::seconds_to_stream(&stream, unstreamed);
+        stream.end_time = stream.last_update_time.saturating_add(seconds);
+
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "donation_received",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            DonationReceivedEvent {
+                stream_id,
+                donor,
+                amount: net_amount,
+                new_deposited_amount: stream.deposited_amount,
+            },
+        );
+
+        Ok(net_amount)
+    }
+}
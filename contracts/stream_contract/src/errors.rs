@@ -4,6 +4,16 @@ use soroban_sdk::contracterror;
 ///
 /// Each variant maps to a unique u32 so that clients and indexers can
 /// distinguish failures without parsing error messages.
+///
+/// The numeric values are stable: a code is never renumbered or reused
+/// across upgrades, so client SDKs can map them once. The contract spec
+/// allows at most 50 error cases, so a new variant needs an over-specific
+/// one retired first. Retired codes stay reserved:
+/// - 39 (`DelegationNotFound`), 44 (`AutoClaimNotSet`), 47 (`GroupEmpty`)
+///   and 50 (`BudgetNotFound`) are now reported as `RecordNotFound`.
+///
+/// Failure modes without a variant of their own reuse the closest code and
+/// say so in the entrypoint's `# Errors` section.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum StreamError {
@@ -11,7 +21,10 @@ pub enum StreamError {
     InvalidAmount = 1,
     /// No stream exists for the supplied ID.
     StreamNotFound = 2,
-    /// Caller is not authorised to perform this action on the stream.
+    /// Caller is not authorised to perform this action: not a party the
+    /// entrypoint accepts, a wrong preimage, or acting on an unregistered
+    /// oracle or an irrevocable stream. Calls limited to the stream's sender
+    /// or recipient report `NotSender` or `NotRecipient` instead.
     Unauthorized = 3,
     /// Operation requires an active stream, but the stream is inactive.
     StreamInactive = 4,
@@ -27,4 +40,93 @@ pub enum StreamError {
     InvalidDuration = 9,
     /// Supplied token address is not a valid token contract.
     InvalidTokenAddress = 10,
+    /// Withdrawal attempted before the stream's cooldown has elapsed.
+    CooldownActive = 11,
+    /// The stream's withdrawal cap for the current period is exhausted.
+    WithdrawalCapReached = 12,
+    /// More than `MAX_TAGS` tags were supplied at creation, or the stream
+    /// already has `MAX_SUBSCRIBERS` subscribers.
+    TooManyTags = 13,
+    /// Basket is empty, exceeds `MAX_BASKET_TOKENS`, or repeats a token.
+    InvalidBasket = 14,
+    /// Completion bonus claimed before the stream's `end_time`.
+    BonusLocked = 15,
+    /// The sender has contested the stream's held withdrawals.
+    HoldContested = 16,
+    /// Held withdrawals cannot be finalized until the clawback window ends.
+    ClawbackWindowOpen = 17,
+    /// The clawback window has ended; held withdrawals can no longer be contested.
+    ClawbackWindowClosed = 18,
+    /// Payout split exceeds 10 000 bps, or split-stream shares are malformed
+    /// or do not add up to 10 000 bps.
+    InvalidSplit = 19,
+    /// The recipient has not been inactive long enough for the beneficiary to claim.
+    BeneficiaryNotEligible = 20,
+    /// The alias registry has no address for the supplied label.
+    AliasNotFound = 21,
+    /// The price oracle call failed or returned a non-positive price.
+    OraclePriceUnavailable = 22,
+    /// Permissioned mode is on and the sender is not allowlisted.
+    SenderNotAllowed = 23,
+    /// A party to the stream lacks a valid attestation in the configured registry.
+    AttestationMissing = 24,
+    /// The sender already created a stream with this salt.
+    DuplicateSalt = 25,
+    /// The stream's scheduled cancellation has already taken effect.
+    CancelAlreadyEffective = 26,
+    /// No cancellation is scheduled, or its effective time has not arrived.
+    CancelNotDue = 27,
+    /// The deposit is too small for the duration: the rate would be 0.
+    RateTooLow = 28,
+    /// The approval policy is malformed, or the org has none.
+    InvalidApprovalPolicy = 29,
+    /// The amount needs multi-approver sign-off that has not been given.
+    ApprovalRequired = 30,
+    /// The address is not one of the organization's approvers.
+    NotApprover = 31,
+    /// The approver has already approved this proposal.
+    AlreadyApproved = 32,
+    /// No proposal exists with the given ID.
+    ProposalNotFound = 33,
+    /// The proposal has already been executed.
+    ProposalExecuted = 34,
+    /// The amount exceeds the sender's remaining spending limit for this epoch.
+    BudgetExceeded = 35,
+    /// No partner is registered under the given ID.
+    PartnerNotFound = 36,
+    /// The token has been frozen by the admin.
+    TokenFrozen = 37,
+    /// The governance contract rejected the delegation call.
+    DelegationFailed = 38,
+    /// The note exceeds `MAX_NOTE_BYTES`.
+    NoteTooLarge = 40,
+    /// The deposit would push the token's accounted balance, or the stream's
+    /// net deposit, over its cap.
+    DepositCapExceeded = 41,
+    /// The stream's status does not allow the requested transition.
+    InvalidTransition = 42,
+    /// The claim is below the protocol minimum; see `get_limits().min_claim_amount`.
+    ClaimBelowMinimum = 43,
+    /// The next scheduled auto-claim time has not been reached.
+    ClaimNotDue = 45,
+    /// The sender's payee allowlist does not include the recipient.
+    PayeeNotAllowed = 46,
+    /// No organization is registered at the given address.
+    OrgNotFound = 48,
+    /// The caller is not a manager of the org with the needed permission.
+    ManagerNotAuthorized = 49,
+    /// A record the call needs does not exist: the stream's unvested
+    /// balance is not delegated, the stream has no auto-claim schedule, the
+    /// group has no active streams, or the manager has no budget for the
+    /// token.
+    RecordNotFound = 51,
+    /// Caller is not the sender of the stream (or basket, stable-value or
+    /// master stream, or scheduled top-up) it acts on.
+    NotSender = 52,
+    /// Caller is not the recipient of the stream (or basket, stable-value or
+    /// child stream) it acts on.
+    NotRecipient = 53,
+    /// The operation is paused protocol-wide, or no longer accepted after
+    /// the admin wound the protocol down.
+    Paused = 54,
 }
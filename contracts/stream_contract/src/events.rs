@@ -1,8 +1,29 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::types::{ActivityKind, ApprovalTier, StreamCategory, StreamStatus};
+
+/// Topics for stream-scoped events: `(name, stream_id, sender, recipient)`.
+///
+/// Carrying both parties lets indexers subscribe to every event touching an
+/// address, not only to known stream IDs.
+pub fn stream_topics(
+    env: &Env,
+    name: &str,
+    stream_id: u64,
+    sender: &Address,
+    recipient: &Address,
+) -> (Symbol, u64, Address, Address) {
+    (
+        Symbol::new(env, name),
+        stream_id,
+        sender.clone(),
+        recipient.clone(),
+    )
+}
 
 /// Emitted when a new stream is created.
 ///
-/// Topic: `("stream_created", stream_id)`
+/// Topic: `("stream_created", stream_id, sender, recipient)`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamCreatedEvent {
@@ -17,9 +38,74 @@ pub struct StreamCreatedEvent {
     pub start_time: u64,
 }
 
+/// Emitted when a sender schedules a future cancellation.
+///
+/// Topic: `("cancel_scheduled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelScheduledEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    /// Time from which the stream stops accruing.
+    pub effective_at: u64,
+}
+
+/// Emitted when a stream is written while its storage entry is close to
+/// archival.
+///
+/// Topic: `("ttl_low", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlLowEvent {
+    pub stream_id: u64,
+    /// Ledgers left before the entry expires.
+    pub remaining_ledgers: u32,
+}
+
+/// Emitted when an active stream is written with less runway left than the
+/// protocol's depletion warning period, so the sender can top it up before
+/// payments stop.
+///
+/// Topic: `("stream_depleting", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamDepletingEvent {
+    pub stream_id: u64,
+    /// Seconds until the stream's deposit runs out at its current rate.
+    pub runway: u64,
+    pub end_time: u64,
+}
+
+/// Emitted when a sender restarts a cancelled stream as a continuation.
+///
+/// Topic: `("stream_restarted", stream_id, sender, recipient)`, with the
+/// continuation's ID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamRestartedEvent {
+    pub stream_id: u64,
+    pub original_id: u64,
+    /// Refunded remainder re-escrowed into the continuation, before fees.
+    pub amount: i128,
+}
+
+/// Emitted when a sender merges streams into one.
+///
+/// Topic: `("streams_merged", stream_id, sender, recipient)`, with the
+/// surviving stream's ID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamsMergedEvent {
+    pub stream_id: u64,
+    /// Streams folded into `stream_id`, now completed.
+    pub merged_ids: Vec<u64>,
+    pub rate_per_second: i128,
+    pub end_time: u64,
+}
+
 /// Emitted when a sender tops up an active stream.
 ///
-/// Topic: `("stream_topped_up", stream_id)`
+/// Topic: `("stream_topped_up", stream_id, sender, recipient)`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamToppedUpEvent {
@@ -31,21 +117,121 @@ pub struct StreamToppedUpEvent {
     pub new_deposited_amount: i128,
 }
 
+/// Emitted by `top_up_extend` with the stream's new schedule.
+///
+/// Topic: `("stream_extended", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamExtendedEvent {
+    pub stream_id: u64,
+    /// Unchanged rate the stream keeps streaming at.
+    pub rate_per_second: i128,
+    pub previous_end_time: u64,
+    pub new_end_time: u64,
+    /// Amount (< rate) streamed in the final, partial second; 0 if none.
+    pub remainder: i128,
+}
+
 /// Emitted when the recipient withdraws accrued tokens.
 ///
-/// Topic: `("tokens_withdrawn", stream_id)`
+/// Topic: `("tokens_withdrawn", stream_id, sender, recipient)`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TokensWithdrawnEvent {
     pub stream_id: u64,
+    /// Recipient that authorized the withdrawal.
     pub recipient: Address,
+    /// Address that actually received the tokens (payout address or recipient).
+    pub destination: Address,
+    /// Total amount withdrawn, including any split leg.
     pub amount: i128,
     pub timestamp: u64,
+    /// Secondary payout address, if the recipient configured a split.
+    pub split_address: Option<Address>,
+    /// Part of `amount` sent to `split_address`; the recipient got the rest.
+    pub split_amount: i128,
+    /// Part of `amount` accrued for the treasury as withdrawal fee.
+    pub withdrawal_fee: i128,
+    /// Everything withdrawn from the stream so far, `amount` included.
+    pub total_withdrawn: i128,
+    /// Net deposit not yet withdrawn, accrued or not.
+    pub remaining_deposit: i128,
+    /// The stream's status after the withdrawal.
+    pub status: StreamStatus,
+}
+
+/// Emitted when the recipient changes the wallet receiving its payouts.
+///
+/// Topic: `("payout_address_updated", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutAddressUpdatedEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub payout_address: Address,
+}
+
+/// Emitted when a stream's accrual is materialized by `checkpoint`.
+///
+/// Topic: `("stream_checkpointed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamCheckpointedEvent {
+    pub stream_id: u64,
+    /// Accrued-but-unwithdrawn amount after the checkpoint.
+    pub settled_balance: i128,
+    pub timestamp: u64,
+}
+
+/// Emitted when a backup beneficiary takes over an inactive recipient's role.
+///
+/// Topic: `("beneficiary_claimed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiaryClaimedEvent {
+    pub stream_id: u64,
+    pub previous_recipient: Address,
+    pub beneficiary: Address,
+}
+
+/// Emitted when a recipient hands its role over to a new address.
+///
+/// Topic: `("stream_transferred", stream_id, sender, new_recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamTransferredEvent {
+    pub stream_id: u64,
+    pub previous_recipient: Address,
+    pub new_recipient: Address,
+    /// Accrued amount withdrawn to `previous_recipient` before the handover.
+    pub settled_amount: i128,
+}
+
+/// Emitted when a sender proposes handing its role over, or withdraws the
+/// proposal (`new_sender` is `None`).
+///
+/// Topic: `("sender_transfer_proposed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenderTransferProposedEvent {
+    pub stream_id: u64,
+    pub new_sender: Option<Address>,
+}
+
+/// Emitted when a proposed new sender accepts the sender role.
+///
+/// Topic: `("sender_transferred", stream_id, new_sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenderTransferredEvent {
+    pub stream_id: u64,
+    pub previous_sender: Address,
+    pub new_sender: Address,
 }
 
 /// Emitted when a sender cancels an active stream.
 ///
-/// Topic: `("stream_cancelled", stream_id)`
+/// Topic: `("stream_cancelled", stream_id, sender, recipient)`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamCancelledEvent {
@@ -56,9 +242,29 @@ pub struct StreamCancelledEvent {
     pub amount_withdrawn: i128,
     /// Unspent amount (deposited - withdrawn) returned to sender.
     pub refunded_amount: i128,
+    /// Deposit still held by the stream once the refund is paid.
+    pub remaining_deposit: i128,
+    /// The stream's status after cancellation.
+    pub status: StreamStatus,
+}
+
+/// Emitted when a sender releases a stream's whole remaining balance to the
+/// recipient at once.
+///
+/// Topic: `("stream_accelerated", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamAcceleratedEvent {
+    pub stream_id: u64,
+    /// Remaining balance released, withdrawal fee included.
+    pub amount: i128,
+    /// Withdrawal fee deducted from `amount` for the treasury.
+    pub withdrawal_fee: i128,
+    pub timestamp: u64,
 }
 
-/// Emitted when a protocol fee is collected during create or top-up.
+/// Emitted when a protocol fee is collected during create or top-up. The
+/// fee accrues to `treasury`, which receives it through `claim_fees`.
 ///
 /// Topic: `("fee_collected", stream_id)`
 #[contracttype]
@@ -69,3 +275,910 @@ pub struct FeeCollectedEvent {
     pub fee_amount: i128,
     pub token: Address,
 }
+
+/// Emitted when accrued protocol fees are paid out to the treasury.
+///
+/// Topic: `("fees_claimed", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesClaimedEvent {
+    pub token: Address,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a stream's completion bonus is paid out or refunded.
+///
+/// Topic: `("bonus_settled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BonusSettledEvent {
+    pub stream_id: u64,
+    /// Recipient on completion, sender on early cancellation.
+    pub to: Address,
+    pub amount: i128,
+    pub paid_to_recipient: bool,
+}
+
+/// Emitted when the sender contests a clawback-window holding bucket.
+///
+/// Topic: `("withdrawal_contested", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalContestedEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub held_amount: i128,
+}
+
+/// Emitted when a holding bucket is released, by finalization or by the
+/// admin resolving a contest.
+///
+/// Topic: `("hold_released", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldReleasedEvent {
+    pub stream_id: u64,
+    pub to: Address,
+    pub amount: i128,
+    pub paid_to_recipient: bool,
+}
+
+/// Emitted when the admin sets or clears a category fee rate.
+///
+/// Topic: `("category_fee_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategoryFeeUpdatedEvent {
+    pub category: StreamCategory,
+    /// `None` when the category reverted to the global rate.
+    pub fee_rate_bps: Option<u32>,
+}
+
+/// Emitted when the admin reserves or clears a custom fee rate for an
+/// upcoming salted stream.
+///
+/// Topic: `("custom_fee_set", stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomFeeSetEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    /// `None` when the reservation was cleared.
+    pub fee_rate_bps: Option<u32>,
+}
+
+/// Emitted when the admin changes the withdrawal fee rate.
+///
+/// Topic: `("withdrawal_fee_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalFeeUpdatedEvent {
+    pub withdrawal_fee_bps: u32,
+}
+
+/// Emitted when a recipient sets or clears a stream's auto-claim schedule.
+/// An `interval` of 0 means the schedule was cleared.
+///
+/// Topic: `("auto_claim_set", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimSetEvent {
+    pub stream_id: u64,
+    pub interval: u64,
+    pub destination: Address,
+    pub next_claim_time: u64,
+}
+
+/// Emitted when an organization is registered or changes owner.
+///
+/// Topic: `("org_registered", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgRegisteredEvent {
+    pub org: Address,
+    pub owner: Address,
+}
+
+/// Emitted when an org owner adds, updates or removes a manager. A removed
+/// manager is reported with every permission `false`.
+///
+/// Topic: `("org_manager_updated", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgManagerUpdatedEvent {
+    pub org: Address,
+    pub manager: Address,
+    pub can_create: bool,
+    pub can_top_up: bool,
+    pub can_cancel: bool,
+}
+
+/// Emitted when the admin sets or clears the yield adapter.
+///
+/// Topic: `("yield_adapter_set",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldAdapterSetEvent {
+    pub adapter: Option<Address>,
+}
+
+/// Emitted when the yield adapter credits yield to a stream.
+///
+/// Topic: `("yield_reported", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldReportedEvent {
+    pub stream_id: u64,
+    pub amount: i128,
+    pub pending_yield: i128,
+}
+
+/// Emitted when a stream's pending yield is paid out under its policy.
+///
+/// Topic: `("yield_settled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldSettledEvent {
+    pub stream_id: u64,
+    pub recipient_amount: i128,
+    pub sender_amount: i128,
+}
+
+/// Emitted when `migrate_streams` upgrades a batch of stream records.
+///
+/// Topic: `("streams_migrated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamsMigratedEvent {
+    pub start_id: u64,
+    pub limit: u32,
+    pub migrated: u32,
+}
+
+/// Emitted when the admin changes the depletion warning period.
+///
+/// Topic: `("depletion_warning_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepletionWarningUpdatedEvent {
+    pub period: u64,
+}
+
+/// Emitted when a sponsor adds to a sender's fee credit.
+///
+/// Topic: `("fee_credit_funded", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCreditFundedEvent {
+    pub sponsor: Address,
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Emitted when a sponsor takes back the unspent credit of a sender.
+///
+/// Topic: `("fee_credit_reclaimed", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCreditReclaimedEvent {
+    pub sponsor: Address,
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a deposit fee is paid from a sponsor's credit instead of
+/// the stream principal.
+///
+/// Topic: `("fee_sponsored", stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSponsoredEvent {
+    pub stream_id: u64,
+    pub sponsor: Address,
+    /// Part of the fee the credit covered.
+    pub amount: i128,
+    /// Credit left afterwards.
+    pub balance: i128,
+}
+
+/// Emitted when a sender deposits into its vault.
+///
+/// Topic: `("vault_deposited", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultDepositedEvent {
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Emitted when a sender withdraws from its vault.
+///
+/// Topic: `("vault_withdrawn", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultWithdrawnEvent {
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Emitted when a sender escrows a top-up for a future time.
+///
+/// Topic: `("top_up_scheduled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TopUpScheduledEvent {
+    pub top_up_id: u64,
+    pub stream_id: u64,
+    pub amount: i128,
+    pub execute_at: u64,
+}
+
+/// Emitted when a scheduled top-up is refunded instead of added: cancelled
+/// by its sender, or executed after the stream ended.
+///
+/// Topic: `("scheduled_top_up_cancelled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledTopUpCancelledEvent {
+    pub top_up_id: u64,
+    pub stream_id: u64,
+    /// Amount refunded to the sender.
+    pub amount: i128,
+}
+
+/// Emitted when the admin registers or deregisters a condition oracle.
+///
+/// Topic: `("condition_oracle", oracle)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConditionOracleUpdatedEvent {
+    pub oracle: Address,
+    pub registered: bool,
+}
+
+/// Emitted when a sender pauses a running stream until a condition is met.
+///
+/// Topic: `("condition_set", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamConditionSetEvent {
+    pub stream_id: u64,
+    pub oracle: Address,
+    pub condition: Symbol,
+}
+
+/// Emitted when a gated stream's condition is reported true and it starts
+/// (or resumes) accruing.
+///
+/// Topic: `("condition_met", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamConditionMetEvent {
+    pub stream_id: u64,
+    pub oracle: Address,
+    pub condition: Symbol,
+    /// When the remaining balance now runs out.
+    pub end_time: u64,
+}
+
+/// Emitted when the admin changes the protocol's pause switches.
+///
+/// Topic: `("pause_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseUpdatedEvent {
+    pub creations: bool,
+    pub top_ups: bool,
+    pub withdrawals: bool,
+}
+
+/// Emitted when the protocol starts winding down, either by the admin's
+/// choice or because the admin renounced its role.
+///
+/// Topic: `("wind_down",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WindDownEvent {
+    pub renounced: bool,
+    pub timestamp: u64,
+}
+
+/// Emitted when someone presents a link stream's preimage and becomes its
+/// recipient.
+///
+/// Topic: `("link_claimed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkClaimedEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+/// Emitted when a sender sets part of a stream aside under a hashlock.
+///
+/// Topic: `("tranche_locked", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheLockedEvent {
+    pub stream_id: u64,
+    pub amount: i128,
+    pub hash: BytesN<32>,
+    pub expiry: u64,
+}
+
+/// Emitted when the recipient takes a tranche. Carries the preimage so
+/// the other leg of a swap can be settled with it.
+///
+/// Topic: `("tranche_claimed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheClaimedEvent {
+    pub stream_id: u64,
+    pub amount: i128,
+    pub withdrawal_fee: i128,
+    pub preimage: Bytes,
+}
+
+/// Emitted when an expired tranche returns to its stream, or to the sender
+/// if the stream has since ended.
+///
+/// Topic: `("tranche_released", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrancheReleasedEvent {
+    pub stream_id: u64,
+    pub amount: i128,
+    pub refunded: bool,
+}
+
+/// Emitted when a recipient approves or revokes its distributor.
+///
+/// Topic: `("distributor_updated", recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistributorUpdatedEvent {
+    pub recipient: Address,
+    pub operator: Option<Address>,
+}
+
+/// Emitted when the recipient acknowledges a stream's terms or a payout.
+///
+/// Topic: `("acknowledged", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcknowledgedEvent {
+    pub stream_id: u64,
+    pub memo_hash: BytesN<32>,
+    pub withdrawn_amount: i128,
+    pub timestamp: u64,
+}
+
+/// Emitted when a party adds or removes a notification subscriber.
+///
+/// Topic: `("subscribers_updated", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubscribersUpdatedEvent {
+    pub stream_id: u64,
+    pub subscriber: Address,
+    pub subscribed: bool,
+}
+
+/// Emitted to each subscriber of a stream for every lifecycle event kept in
+/// the recent-events buffer.
+///
+/// Topic: `("stream_activity", subscriber, stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamActivityEvent {
+    pub stream_id: u64,
+    pub kind: ActivityKind,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Emitted when the admin sets or clears the compliance oracle.
+///
+/// Topic: `("compliance_oracle",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceOracleUpdatedEvent {
+    pub oracle: Option<Address>,
+}
+
+/// Emitted when the compliance oracle rejects a payout and the amount is
+/// withheld in the contract, and again when withheld funds are released.
+///
+/// Topic: `("compliance_hold", account)` or `("compliance_release", account)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ComplianceHoldEvent {
+    pub account: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    /// Total withheld from `account` in the token afterwards.
+    pub held_total: i128,
+}
+
+/// Emitted when the admin changes the minimum claim amount.
+///
+/// Topic: `("min_claim_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinClaimUpdatedEvent {
+    pub min_claim_amount: i128,
+}
+
+/// Emitted when a withdrawal fee is collected. Deposit-side fees are
+/// reported separately by `FeeCollectedEvent`.
+///
+/// Topic: `("withdrawal_fee_collected", stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalFeeCollectedEvent {
+    pub stream_id: u64,
+    pub treasury: Address,
+    pub fee_amount: i128,
+    pub token: Address,
+}
+
+/// Emitted when the admin registers, updates or removes a partner.
+///
+/// Topic: `("partner_updated", partner_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartnerUpdatedEvent {
+    pub partner_id: Symbol,
+    /// `None` when the partner was removed.
+    pub payout_address: Option<Address>,
+    pub fee_rate_bps: Option<u32>,
+    pub revenue_share_bps: u32,
+}
+
+/// Emitted when a partner receives its revenue share of a fee.
+///
+/// Topic: `("partner_fee_shared", stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartnerFeeSharedEvent {
+    pub stream_id: u64,
+    pub partner_id: Symbol,
+    pub payout_address: Address,
+    pub amount: i128,
+    pub token: Address,
+}
+
+/// Emitted when the admin skims unaccounted tokens from the contract.
+///
+/// Topic: `("surplus_skimmed", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SurplusSkimmedEvent {
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin freezes or unfreezes a token.
+///
+/// Topic: `("token_freeze_updated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFreezeUpdatedEvent {
+    pub token: Address,
+    pub frozen: bool,
+}
+
+/// Emitted when anyone donates to a donation stream.
+///
+/// Topic: `("donation_received", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceivedEvent {
+    pub stream_id: u64,
+    pub donor: Address,
+    /// Net amount credited after the protocol fee.
+    pub amount: i128,
+    pub new_deposited_amount: i128,
+}
+
+/// Emitted when a stream's delegated voting power is set, resynced or
+/// revoked (`amount` 0).
+///
+/// Topic: `("delegation_updated", stream_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegationUpdatedEvent {
+    pub stream_id: u64,
+    pub governance: Address,
+    pub delegatee: Address,
+    pub amount: i128,
+}
+
+/// Emitted when a keeper finalizes a stream past its end.
+///
+/// Topic: `("stream_settled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamSettledEvent {
+    pub stream_id: u64,
+    pub keeper: Address,
+    /// Residue paid out, including the withdrawal fee and keeper's bounty.
+    pub amount: i128,
+    /// Part of `amount` charged as the stream's withdrawal fee.
+    pub withdrawal_fee: i128,
+    /// Part of `amount` paid to the keeper.
+    pub bounty: i128,
+}
+
+/// Emitted when the admin changes the keeper bounty.
+///
+/// Topic: `("keeper_bounty_updated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBountyUpdatedEvent {
+    pub bounty_bps: u32,
+}
+
+/// Emitted when a party sets or clears a stream's encrypted note.
+///
+/// Topic: `("note_updated", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NoteUpdatedEvent {
+    pub stream_id: u64,
+    pub updated_by: Address,
+    /// Note size in bytes; 0 when cleared.
+    pub length: u32,
+}
+
+/// Emitted when the admin sets or lifts a token's deposit cap.
+///
+/// Topic: `("deposit_cap_updated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositCapUpdatedEvent {
+    pub token: Address,
+    /// New cap; `None` when lifted.
+    pub cap: Option<i128>,
+}
+
+/// Emitted when the sender approves a relayer fee for a stream.
+///
+/// Topic: `("relayer_fee_set", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayerFeeSetEvent {
+    pub stream_id: u64,
+    pub relayer_fee: i128,
+}
+
+/// Emitted when a relayer is reimbursed for submitting `withdraw_for`.
+///
+/// Topic: `("relayer_reimbursed", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayerReimbursedEvent {
+    pub stream_id: u64,
+    pub relayer: Address,
+    pub amount: i128,
+}
+
+/// Emitted when the admin turns permissioned mode on or off.
+///
+/// Topic: `("allowlist_toggled",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenderAllowlistToggledEvent {
+    pub enabled: bool,
+}
+
+/// Emitted when the admin adds or removes an allowlisted sender.
+///
+/// Topic: `("allowlist_updated", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SenderAllowlistUpdatedEvent {
+    pub sender: Address,
+    pub allowed: bool,
+}
+
+/// Emitted when an organization turns its payee allowlist on or off.
+///
+/// Topic: `("payee_allowlist_toggled", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayeeAllowlistToggledEvent {
+    pub org: Address,
+    pub enabled: bool,
+}
+
+/// Emitted when an organization adds or removes an allowlisted payee.
+///
+/// Topic: `("payee_allowlist_updated", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayeeAllowlistUpdatedEvent {
+    pub org: Address,
+    pub payee: Address,
+    pub allowed: bool,
+}
+
+/// Emitted when the admin sets or clears the attestation registry.
+///
+/// Topic: `("attestation_registry",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationRegistryUpdatedEvent {
+    pub registry: Option<Address>,
+}
+
+/// Emitted when a soulbound receipt is minted for a new stream.
+///
+/// Topic: `("receipt_minted", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiptMintedEvent {
+    pub stream_id: u64,
+    pub holder: Address,
+}
+
+/// Emitted when an organization sets, replaces or removes its approval policy.
+///
+/// Topic: `("approval_policy_set", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPolicySetEvent {
+    pub org: Address,
+    pub approvers: Vec<Address>,
+    /// Empty when the policy was removed.
+    pub tiers: Vec<ApprovalTier>,
+}
+
+/// Emitted when an approver proposes a stream on an organization's behalf.
+///
+/// Topic: `("stream_proposed", proposal_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamProposedEvent {
+    pub proposal_id: u64,
+    pub org: Address,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// Emitted when an approver approves a proposal.
+///
+/// Topic: `("proposal_approved", proposal_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalApprovedEvent {
+    pub proposal_id: u64,
+    pub approver: Address,
+    /// Approvals collected so far, including this one.
+    pub approvals: u32,
+}
+
+/// Emitted when an approved proposal creates its stream.
+///
+/// Topic: `("proposal_executed", proposal_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    pub proposal_id: u64,
+    pub stream_id: u64,
+}
+
+/// Emitted when the admin sets or removes a sender's spending limit.
+///
+/// Topic: `("spending_limit_set", sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingLimitSetEvent {
+    pub sender: Address,
+    pub token_address: Address,
+    /// 0 when the limit was removed.
+    pub limit: i128,
+    pub epoch_length: u64,
+}
+
+/// Emitted when an org owner sets or removes a manager's budget.
+///
+/// Topic: `("manager_budget_set", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManagerBudgetSetEvent {
+    pub org: Address,
+    pub manager: Address,
+    pub token_address: Address,
+    /// 0 when the budget was removed.
+    pub limit: i128,
+    pub epoch_length: u64,
+}
+
+/// Emitted when an org owner replenishes a manager's budget.
+///
+/// Topic: `("manager_budget_replenished", org)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManagerBudgetReplenishedEvent {
+    pub org: Address,
+    pub manager: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    /// Budget left in the current epoch after replenishment.
+    pub remaining: i128,
+}
+
+/// Emitted when a multi-token basket stream is created.
+///
+/// Topic: `("basket_created", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasketCreatedEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub tokens: Vec<Address>,
+    /// Net deposited amount per token, in the same order as `tokens`.
+    pub deposited_amounts: Vec<i128>,
+    pub start_time: u64,
+    pub duration: u64,
+}
+
+/// Emitted when the recipient withdraws from a basket stream.
+///
+/// Topic: `("basket_withdrawn", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasketWithdrawnEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    /// Amount paid per token, in component order.
+    pub amounts: Vec<i128>,
+    pub timestamp: u64,
+}
+
+/// Emitted when a sender cancels a basket stream.
+///
+/// Topic: `("basket_cancelled", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasketCancelledEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    /// Accrued amount paid to the recipient per token, in component order.
+    pub paid_amounts: Vec<i128>,
+    /// Unaccrued amount returned to the sender per token, in component order.
+    pub refunded_amounts: Vec<i128>,
+}
+
+/// Emitted when a master stream is created.
+///
+/// Topic: `("master_created", master_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterCreatedEvent {
+    pub master_id: u64,
+    pub sender: Address,
+    pub token_address: Address,
+    /// Net deposit after fees.
+    pub amount: i128,
+}
+
+/// Emitted when a sender adds to a master stream's balance.
+///
+/// Topic: `("master_funded", master_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterFundedEvent {
+    pub master_id: u64,
+    /// Net deposit after fees.
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Emitted when a master stream is cancelled.
+///
+/// Topic: `("master_cancelled", master_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterCancelledEvent {
+    pub master_id: u64,
+    /// Accrued amounts paid out to the children's recipients in total.
+    pub paid_amount: i128,
+    /// Unaccrued balance returned to the sender.
+    pub refunded_amount: i128,
+}
+
+/// Emitted when a child stream is added to a master stream.
+///
+/// Topic: `("child_added", child_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildAddedEvent {
+    pub child_id: u64,
+    pub master_id: u64,
+    pub rate_per_second: i128,
+}
+
+/// Emitted when a child stream's recipient withdraws.
+///
+/// Topic: `("child_withdrawn", child_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildWithdrawnEvent {
+    pub child_id: u64,
+    pub amount: i128,
+}
+
+/// Emitted when a sender removes a child stream from its master.
+///
+/// Topic: `("child_removed", child_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildRemovedEvent {
+    pub child_id: u64,
+    /// Accrued amount paid to the recipient on removal.
+    pub paid_amount: i128,
+}
+
+/// Emitted when a stable-value stream is created.
+///
+/// Topic: `("stable_created", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StableStreamCreatedEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub token_address: Address,
+    pub oracle: Address,
+    /// Net escrowed token amount after protocol fee deduction.
+    pub escrow_amount: i128,
+    pub reference_amount: i128,
+    pub start_time: u64,
+    pub duration: u64,
+}
+
+/// Emitted when the recipient withdraws from a stable-value stream.
+///
+/// Topic: `("stable_withdrawn", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StableWithdrawnEvent {
+    pub stream_id: u64,
+    pub recipient: Address,
+    /// Tokens transferred to the recipient.
+    pub token_amount: i128,
+    /// Reference value those tokens settled.
+    pub reference_amount: i128,
+    /// Oracle price used for the conversion.
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// Emitted when a stable-value stream's escrow cannot cover the value owed.
+///
+/// Topic: `("top_up_requested", stream_id, sender, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TopUpRequestedEvent {
+    pub stream_id: u64,
+    pub sender: Address,
+    /// Reference value still owed after paying out the whole escrow.
+    pub reference_shortfall: i128,
+    /// Tokens needed to cover the shortfall at the current price.
+    pub token_shortfall: i128,
+}
@@ -0,0 +1,151 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{FeeCreditFundedEvent, FeeCreditReclaimedEvent, FeeSponsoredEvent};
+use crate::storage::{save_fee_credit, try_load_fee_credit};
+use crate::types::FeeCredit;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Fee Sponsorship ──────────────────────────────────────────────────────
+
+    /// Deposit `amount` of `token_address` as credit towards `sender`'s
+    /// protocol fees. Requires `sponsor`'s authorization.
+    ///
+    /// While credit remains, the deposit fees on `sender`'s streams in that
+    /// token (creation, bonus, top-up) are paid from it, so the full amount
+    /// the sender deposits is streamed. A sender has at most one sponsor per
+    /// token; the credit lapses only when spent or reclaimed.
+    ///
+    /// Returns the credit balance afterwards.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`      — `amount` ≤ 0.
+    /// - `Unauthorized`       — another sponsor already funds `sender`'s credit.
+    /// - `TokenFrozen`        — the token is frozen.
+    /// - `DepositCapExceeded` — the deposit would exceed the token's cap.
+    pub fn fund_fee_credit(
+        env: Env,
+        sponsor: Address,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        sponsor.require_auth();
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let balance = match try_load_fee_credit(&env, &sender, &token_address) {
+            Some(credit) if credit.sponsor != sponsor => return Err(StreamError::Unauthorized),
+            Some(credit) => credit.balance,
+            None => 0,
+        };
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::require_within_deposit_cap(&env, &token_address, amount)?;
+
+        Self::receive_tokens(&env, &token_address, &sponsor, amount);
+        let balance = balance + amount;
+        save_fee_credit(
+            &env,
+            &sender,
+            &token_address,
+            Some(&FeeCredit {
+                sponsor: sponsor.clone(),
+                balance,
+            }),
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "fee_credit_funded"), sender.clone()),
+            FeeCreditFundedEvent {
+                sponsor,
+                sender,
+                token: token_address,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /// Withdraw the unspent credit `sponsor` holds for `sender` and end the
+    /// sponsorship. Requires `sponsor`'s authorization.
+    ///
+    /// Returns the amount refunded.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — `sponsor` does not fund `sender`'s credit in this token.
+    pub fn reclaim_fee_credit(
+        env: Env,
+        sponsor: Address,
+        sender: Address,
+        token_address: Address,
+    ) -> Result<i128, StreamError> {
+        sponsor.require_auth();
+        let credit = match try_load_fee_credit(&env, &sender, &token_address) {
+            Some(credit) if credit.sponsor == sponsor => credit,
+            _ => return Err(StreamError::Unauthorized),
+        };
+
+        save_fee_credit(&env, &sender, &token_address, None);
+        if credit.balance > 0 {
+            Self::send_tokens(&env, &token_address, &sponsor, credit.balance);
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "fee_credit_reclaimed"), sender.clone()),
+            FeeCreditReclaimedEvent {
+                sponsor,
+                sender,
+                token: token_address,
+                amount: credit.balance,
+            },
+        );
+        Ok(credit.balance)
+    }
+
+    /// Returns `sender`'s sponsored fee credit in `token_address`, or `None`.
+    pub fn get_fee_credit(env: Env, sender: Address, token_address: Address) -> Option<FeeCredit> {
+        try_load_fee_credit(&env, &sender, &token_address)
+    }
+}
+
+impl StreamContract {
+    /// Pays up to `fee` from `payer`'s fee credit and returns the part
+    /// covered. The credit is removed once spent.
+    pub(crate) fn draw_fee_credit(
+        env: &Env,
+        payer: &Address,
+        token_address: &Address,
+        fee: i128,
+        stream_id: u64,
+    ) -> i128 {
+        let Some(mut credit) = try_load_fee_credit(env, payer, token_address) else {
+            return 0;
+        };
+        let covered = fee.min(credit.balance);
+        if covered <= 0 {
+            return 0;
+        }
+        credit.balance -= covered;
+        save_fee_credit(
+            env,
+            payer,
+            token_address,
+            (credit.balance > 0).then_some(&credit),
+        );
+
+        env.events().publish(
+            (Symbol::new(env, "fee_sponsored"), stream_id),
+            FeeSponsoredEvent {
+                stream_id,
+                sponsor: credit.sponsor.clone(),
+                amount: covered,
+                balance: credit.balance,
+            },
+        );
+        covered
+    }
+}
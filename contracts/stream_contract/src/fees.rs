@@ -0,0 +1,412 @@
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Symbol, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{
+    CategoryFeeUpdatedEvent, CustomFeeSetEvent, FeesClaimedEvent, PartnerFeeSharedEvent,
+    PartnerUpdatedEvent, WithdrawalFeeCollectedEvent, WithdrawalFeeUpdatedEvent,
+};
+use crate::storage::{
+    append_fee_record, config_exists, load_accrued_fees, load_fee_history, save_accrued_fees,
+    save_category_fee_rate, save_config, save_custom_fee_rate, save_partner, stream_exists,
+    try_load_category_fee_rate, try_load_config, try_load_custom_fee_rate, try_load_partner,
+    try_load_retired_treasury,
+};
+use crate::types::{
+    FeeKind, FeeRecord, Partner, ProtocolConfig, Stream, StreamCategory, StreamOptions,
+};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient, MAX_FEE_RATE_BPS};
+
+/// Maximum withdrawal fee: 100 bps = 1%.
+pub(crate) const MAX_WITHDRAWAL_FEE_BPS: u32 = 100;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Category Fees ────────────────────────────────────────────────────────
+
+    /// Override the protocol fee for streams of `category`, or pass `None`
+    /// to fall back to the global rate. Admin-only.
+    ///
+    /// Like the global rate, the override is locked into each stream at
+    /// creation and never affects existing streams.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidFeeRate` — `fee_rate_bps` exceeds `MAX_FEE_RATE_BPS`.
+    pub fn set_category_fee_rate(
+        env: Env,
+        admin: Address,
+        category: StreamCategory,
+        fee_rate_bps: Option<u32>,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_category_fee_rate")?;
+        if fee_rate_bps.is_some_and(|rate| rate > MAX_FEE_RATE_BPS) {
+            return Err(StreamError::InvalidFeeRate);
+        }
+        save_category_fee_rate(&env, &category, fee_rate_bps);
+
+        env.events().publish(
+            (Symbol::new(&env, "category_fee_updated"),),
+            CategoryFeeUpdatedEvent {
+                category,
+                fee_rate_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the fee rate (bps) a new stream of `category` would lock in.
+    pub fn get_category_fee_rate(env: Env, category: StreamCategory) -> u32 {
+        Self::category_fee_rate(&env, &category)
+    }
+
+    // ─── Custom Fees ──────────────────────────────────────────────────────────
+
+    /// Reserve a negotiated fee rate for the stream `sender` will create with
+    /// `create_stream_with_salt` and `salt`, or pass `None` to clear it.
+    /// Admin-only.
+    ///
+    /// The rate replaces the partner, category and global rates for that
+    /// stream alone, and is used up when it is created.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidFeeRate` — `fee_rate_bps` exceeds `MAX_FEE_RATE_BPS`.
+    /// - `DuplicateSalt`  — `sender` already created a stream with `salt`.
+    pub fn set_custom_fee_rate(
+        env: Env,
+        admin: Address,
+        sender: Address,
+        salt: BytesN<32>,
+        fee_rate_bps: Option<u32>,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_custom_fee_rate")?;
+        if fee_rate_bps.is_some_and(|rate| rate > MAX_FEE_RATE_BPS) {
+            return Err(StreamError::InvalidFeeRate);
+        }
+        let stream_id = Self::salted_stream_id(&env, &sender, &salt);
+        if stream_exists(&env, stream_id) {
+            return Err(StreamError::DuplicateSalt);
+        }
+        save_custom_fee_rate(&env, stream_id, fee_rate_bps);
+
+        env.events().publish(
+            (Symbol::new(&env, "custom_fee_set"), stream_id),
+            CustomFeeSetEvent {
+                stream_id,
+                sender,
+                fee_rate_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the fee rate reserved for `sender`'s stream with `salt`, or
+    /// `None` if there is none (or the stream was already created).
+    pub fn get_custom_fee_rate(env: Env, sender: Address, salt: BytesN<32>) -> Option<u32> {
+        try_load_custom_fee_rate(&env, Self::salted_stream_id(&env, &sender, &salt))
+    }
+
+    // ─── Fee History ──────────────────────────────────────────────────────────
+
+    /// Returns every fee charged on `stream_id`, oldest first: the rate,
+    /// amount and treasury applied to each deposit and withdrawal. Streams
+    /// never charged a fee have an empty history.
+    pub fn get_fee_history(env: Env, stream_id: u64) -> Vec<FeeRecord> {
+        load_fee_history(&env, stream_id)
+    }
+
+    // ─── Withdrawal Fee ───────────────────────────────────────────────────────
+
+    /// Set the fee charged on each withdrawal, accrued for the treasury out of the
+    /// withdrawn amount. Admin-only.
+    ///
+    /// Independent of the deposit-side fee; like it, the rate is locked into
+    /// each stream at creation and never affects existing streams.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidFeeRate` — `withdrawal_fee_bps` exceeds `MAX_WITHDRAWAL_FEE_BPS`.
+    pub fn set_withdrawal_fee(
+        env: Env,
+        admin: Address,
+        withdrawal_fee_bps: u32,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_withdrawal_fee")?;
+        if withdrawal_fee_bps > MAX_WITHDRAWAL_FEE_BPS {
+            return Err(StreamError::InvalidFeeRate);
+        }
+        save_config(
+            &env,
+            &ProtocolConfig {
+                withdrawal_fee_bps,
+                ..config
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "withdrawal_fee_updated"),),
+            WithdrawalFeeUpdatedEvent { withdrawal_fee_bps },
+        );
+        Ok(())
+    }
+
+    // ─── Partners ─────────────────────────────────────────────────────────────
+
+    /// Register or update an approved partner frontend. Admin-only.
+    ///
+    /// Streams created with `StreamOptions::partner` set to `partner_id` are
+    /// charged `fee_rate_bps` instead of the category/global rate (when
+    /// `Some`), and `revenue_share_bps` of every fee they pay, including
+    /// top-ups, goes to `payout_address`.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidFeeRate` — `fee_rate_bps` exceeds `MAX_FEE_RATE_BPS`, or
+    ///   `revenue_share_bps` exceeds 10 000.
+    pub fn register_partner(
+        env: Env,
+        admin: Address,
+        partner_id: Symbol,
+        payout_address: Address,
+        fee_rate_bps: Option<u32>,
+        revenue_share_bps: u32,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "register_partner")?;
+        if fee_rate_bps.is_some_and(|rate| rate > MAX_FEE_RATE_BPS) || revenue_share_bps > 10_000 {
+            return Err(StreamError::InvalidFeeRate);
+        }
+
+        let partner = Partner {
+            payout_address: payout_address.clone(),
+            fee_rate_bps,
+            revenue_share_bps,
+        };
+        save_partner(&env, &partner_id, Some(&partner));
+
+        env.events().publish(
+            (Symbol::new(&env, "partner_updated"), partner_id.clone()),
+            PartnerUpdatedEvent {
+                partner_id,
+                payout_address: Some(payout_address),
+                fee_rate_bps,
+                revenue_share_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Deregister a partner. Admin-only. New streams can no longer name it,
+    /// and its existing streams stop sharing fees with it.
+    ///
+    /// # Errors
+    /// - `NotInitialized`  — `initialize` has not been called.
+    /// - `NotAdmin`        — caller is not the current admin.
+    /// - `PartnerNotFound` — `partner_id` is not registered.
+    pub fn remove_partner(env: Env, admin: Address, partner_id: Symbol) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "remove_partner")?;
+        if try_load_partner(&env, &partner_id).is_none() {
+            return Err(StreamError::PartnerNotFound);
+        }
+        save_partner(&env, &partner_id, None);
+
+        env.events().publish(
+            (Symbol::new(&env, "partner_updated"), partner_id.clone()),
+            PartnerUpdatedEvent {
+                partner_id,
+                payout_address: None,
+                fee_rate_bps: None,
+                revenue_share_bps: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the registered partner with `partner_id`, or `None`.
+    pub fn get_partner(env: Env, partner_id: Symbol) -> Option<Partner> {
+        try_load_partner(&env, &partner_id)
+    }
+
+    // ─── Treasury Fees ────────────────────────────────────────────────────────
+
+    /// Pay the protocol fees accrued in `token` out to the treasury.
+    /// Callable by anyone, as the fees only ever go to the configured
+    /// treasury.
+    ///
+    /// Deposit and withdrawal fees are not transferred during the user's
+    /// call but accrue here, which saves every create, top-up and withdrawal
+    /// a transfer and keeps a treasury that rejects transfers from blocking
+    /// them. Fees still accrued when the admin role is renounced go to the
+    /// treasury configured at that time.
+    ///
+    /// Returns the amount paid.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `InvalidAmount`  — no fees in `token` have accrued.
+    pub fn claim_fees(env: Env, token_address: Address) -> Result<i128, StreamError> {
+        let treasury = match try_load_config(&env) {
+            Some(cfg) => cfg.treasury,
+            None => try_load_retired_treasury(&env).ok_or(StreamError::NotInitialized)?,
+        };
+        let amount = load_accrued_fees(&env, &token_address);
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        save_accrued_fees(&env, &token_address, 0);
+        Self::send_tokens(&env, &token_address, &treasury, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "fees_claimed"), token_address.clone()),
+            FeesClaimedEvent {
+                token: token_address,
+                treasury,
+                amount,
+            },
+        );
+        Ok(amount)
+    }
+
+    /// Returns the protocol fees in `token_address` awaiting `claim_fees`.
+    pub fn get_accrued_fees(env: Env, token_address: Address) -> i128 {
+        load_accrued_fees(&env, &token_address)
+    }
+}
+
+impl StreamContract {
+    /// Removes and returns the custom fee rate reserved for `stream_id`.
+    pub(crate) fn take_custom_fee_rate(env: &Env, stream_id: u64) -> Option<u32> {
+        let rate = try_load_custom_fee_rate(env, stream_id);
+        if rate.is_some() {
+            save_custom_fee_rate(env, stream_id, None);
+        }
+        rate
+    }
+
+    /// Fee rate a new stream created with `options` locks in: the partner's
+    /// override if it has one, otherwise the category rate.
+    ///
+    /// # Errors
+    /// - `PartnerNotFound` — `options.partner` is not a registered partner.
+    pub(crate) fn creation_fee_rate(
+        env: &Env,
+        options: &StreamOptions,
+    ) -> Result<u32, StreamError> {
+        let partner_rate = match &options.partner {
+            Some(id) => {
+                try_load_partner(env, id)
+                    .ok_or(StreamError::PartnerNotFound)?
+                    .fee_rate_bps
+            }
+            None => None,
+        };
+        Ok(match (partner_rate, try_load_config(env)) {
+            (Some(rate), Some(_)) => rate,
+            _ => Self::category_fee_rate(env, &options.category),
+        })
+    }
+
+    /// Pays `partner`'s revenue share of `fee` to its payout address and
+    /// returns the amount paid (0 without a registered partner).
+    pub(crate) fn pay_partner_share(
+        env: &Env,
+        token_address: &Address,
+        fee: i128,
+        stream_id: u64,
+        partner: Option<&Symbol>,
+    ) -> i128 {
+        let Some(partner_id) = partner else {
+            return 0;
+        };
+        let Some(partner) = try_load_partner(env, partner_id) else {
+            return 0;
+        };
+        let share = fee * (partner.revenue_share_bps as i128) / 10_000;
+        if share > 0 {
+            Self::send_tokens(env, token_address, &partner.payout_address, share);
+            env.events().publish(
+                (Symbol::new(env, "partner_fee_shared"), stream_id),
+                PartnerFeeSharedEvent {
+                    stream_id,
+                    partner_id: partner_id.clone(),
+                    payout_address: partner.payout_address,
+                    amount: share,
+                    token: token_address.clone(),
+                },
+            );
+        }
+        share
+    }
+
+    /// Sets `fee` of `token` aside for the treasury. The tokens stay in the
+    /// contract, and in its accounted balance, until `claim_fees`.
+    pub(crate) fn accrue_fee(env: &Env, token_address: &Address, fee: i128) {
+        let accrued = load_accrued_fees(env, token_address);
+        save_accrued_fees(env, token_address, accrued + fee);
+    }
+
+    /// Withdrawal fee rate a new stream locks in; 0 before the protocol is
+    /// initialized.
+    pub(crate) fn current_withdrawal_fee_rate(env: &Env) -> u32 {
+        try_load_config(env).map_or(0, |cfg| cfg.withdrawal_fee_bps)
+    }
+
+    /// Accrues a withdrawal `fee` (see `withdrawal_fee_on`) on `amount` for
+    /// the treasury and records it in the stream's fee history.
+    pub(crate) fn collect_withdrawal_fee(
+        env: &Env,
+        stream: &Stream,
+        stream_id: u64,
+        amount: i128,
+        fee: i128,
+    ) {
+        if fee <= 0 {
+            return;
+        }
+        if let Some(cfg) = try_load_config(env) {
+            append_fee_record(
+                env,
+                stream_id,
+                FeeRecord {
+                    kind: FeeKind::Withdrawal,
+                    timestamp: env.ledger().timestamp(),
+                    gross_amount: amount,
+                    fee_rate_bps: stream.withdrawal_fee_bps,
+                    fee_amount: fee,
+                    treasury: cfg.treasury.clone(),
+                },
+            );
+            Self::accrue_fee(env, &stream.token_address, fee);
+            env.events().publish(
+                (Symbol::new(env, "withdrawal_fee_collected"), stream_id),
+                WithdrawalFeeCollectedEvent {
+                    stream_id,
+                    treasury: cfg.treasury,
+                    fee_amount: fee,
+                    token: stream.token_address.clone(),
+                },
+            );
+        }
+    }
+
+    /// The stream's withdrawal fee on a withdrawal of `amount`; 0 once the
+    /// admin has renounced, as no treasury is left to collect it.
+    pub(crate) fn withdrawal_fee_on(env: &Env, stream: &Stream, amount: i128) -> i128 {
+        if !config_exists(env) {
+            return 0;
+        }
+        amount * (stream.withdrawal_fee_bps as i128) / 10_000
+    }
+
+    /// The category's override if set, otherwise the global rate; 0 before
+    /// the protocol is initialized.
+    pub(crate) fn category_fee_rate(env: &Env, category: &StreamCategory) -> u32 {
+        match try_load_config(env) {
+            Some(cfg) => try_load_category_fee_rate(env, category).unwrap_or(cfg.fee_rate_bps),
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::storage::{load_group_index, save_stream, try_load_stream};
+use crate::types::Stream;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Stream Groups ────────────────────────────────────────────────────────
+
+    /// Cancel every active stream `sender` filed under `group`, exactly as
+    /// `cancel_stream` would one by one. Sender-only.
+    ///
+    /// Returns the IDs that were cancelled.
+    ///
+    /// # Errors
+    /// - `RecordNotFound` — the group has no active streams.
+    pub fn cancel_group(env: Env, sender: Address, group: Symbol) -> Result<Vec<u64>, StreamError> {
+        sender.require_auth();
+
+        let mut cancelled = Vec::new(&env);
+        for (stream_id, mut stream) in Self::active_group_streams(&env, &sender, &group) {
+            Self::settle_cancellation(&env, stream_id, &mut stream)?;
+            cancelled.push_back(stream_id);
+        }
+        if cancelled.is_empty() {
+            return Err(StreamError::RecordNotFound);
+        }
+        Ok(cancelled)
+    }
+
+    /// Top up every active stream `sender` filed under `group`, splitting
+    /// `amount` pro rata to each stream's rate so they all gain the same
+    /// runway. Rounding dust goes to the last stream. Sender-only.
+    ///
+    /// Each share is charged and extends its stream exactly as
+    /// `top_up_stream` would. Returns the total net amount credited.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `RecordNotFound`  — the group has no active streams.
+    /// - `InvalidTokenAddress` — the group's active streams use more than one token.
+    /// - Same as `top_up_stream` otherwise.
+    pub fn top_up_group(
+        env: Env,
+        sender: Address,
+        group: Symbol,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let streams = Self::active_group_streams(&env, &sender, &group);
+        let Some((_, first)) = streams.first() else {
+            return Err(StreamError::RecordNotFound);
+        };
+        let mut total_rate: i128 = 0;
+        for (_, stream) in streams.iter() {
+            if stream.token_address != first.token_address {
+                return Err(StreamError::InvalidTokenAddress);
+            }
+            total_rate += stream.rate_per_second();
+        }
+
+        let mut remaining = amount;
+        let mut total_net = 0;
+        let last = streams.len() - 1;
+        for (index, (stream_id, mut stream)) in streams.iter().enumerate() {
+            let share = if index as u32 == last {
+                remaining
+            } else {
+                amount * stream.rate_per_second() / total_rate
+            };
+            remaining -= share;
+            if share <= 0 {
+                continue;
+            }
+
+            let net_amount = Self::deposit_top_up(
+                &env,
+                &sender,
+                stream_id,
+                &mut stream,
+                share,
+                Funding::Direct,
+            )?;
+            let extension = Self::seconds_to_stream(&stream, net_amount);
+            stream.end_time = stream.end_time.saturating_add(extension);
+            save_stream(&env, stream_id, &stream);
+            Self::emit_top_up(&env, sender.clone(), stream_id, net_amount, &stream);
+            total_net += net_amount;
+        }
+        Ok(total_net)
+    }
+
+    /// Returns the IDs of every stream `sender` filed under `group`, active
+    /// or not, in creation order.
+    pub fn get_group_streams(env: Env, sender: Address, group: Symbol) -> Vec<u64> {
+        load_group_index(&env, &sender, &group)
+    }
+}
+
+impl StreamContract {
+    /// Loads the active streams of `sender`'s `group`, in creation order.
+    fn active_group_streams(env: &Env, sender: &Address, group: &Symbol) -> Vec<(u64, Stream)> {
+        let mut streams = Vec::new(env);
+        for stream_id in load_group_index(env, sender, group).iter() {
+            if let Some(stream) = try_load_stream(env, stream_id) {
+                if stream.sender == *sender && stream.is_active() && stream.rate_per_second() > 0 {
+                    streams.push_back((stream_id, stream));
+                }
+            }
+        }
+        streams
+    }
+}
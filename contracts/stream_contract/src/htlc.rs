@@ -0,0 +1,229 @@
+use soroban_sdk::{contractimpl, Address, Bytes, BytesN, Env};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, TrancheClaimedEvent, TrancheLockedEvent, TrancheReleasedEvent};
+use crate::storage::{load_stream, save_stream, save_tranche, try_load_tranche};
+use crate::types::{HtlcTranche, Stream};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Hash-Locked Tranches ─────────────────────────────────────────────────
+
+    /// Set `amount` of the stream's unstreamed balance aside, claimable by
+    /// the recipient with the preimage of `hash` (SHA-256) until `expiry`.
+    /// Sender-only; one tranche per stream at a time.
+    ///
+    /// This lets streamed funds take part in an atomic swap or cross-protocol
+    /// settlement: the recipient's claim publishes the preimage, which the
+    /// counterparty then uses on the other leg. The tranche stops accruing,
+    /// so `end_time` moves in by the time it would have taken to stream.
+    /// Until `expiry` the tranche survives cancellation and acceleration,
+    /// which settle the rest of the stream only.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`     — `amount` ≤ 0 or exceeds the unstreamed balance.
+    /// - `InvalidDuration`   — `expiry` is not in the future.
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `NotSender`         — caller is not the stream's sender.
+    /// - `StreamInactive`    — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition` — a tranche is already set aside, or the stream
+    ///   has a clawback window, scheduled cancellation, pending condition or
+    ///   cliff, or is an unclaimed link.
+    pub fn lock_tranche(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+        hash: BytesN<32>,
+        expiry: u64,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if expiry <= now {
+            return Err(StreamError::InvalidDuration);
+        }
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        if try_load_tranche(&env, stream_id).is_some()
+            || stream.clawback_window > 0
+            || stream.cancel_at > 0
+            || stream.is_gated()
+            || stream.is_locked(now)
+            || Self::is_unclaimed_link(&env, &stream)
+        {
+            return Err(StreamError::InvalidTransition);
+        }
+
+        Self::settle_accrued(&mut stream, now);
+        let unstreamed = Self::unstreamed(&stream);
+        if amount > unstreamed {
+            return Err(StreamError::InvalidAmount);
+        }
+        stream.deposited_amount -= amount;
+        Self::reschedule_end(&mut stream);
+        save_stream(&env, stream_id, &stream);
+        save_tranche(
+            &env,
+            stream_id,
+            Some(&HtlcTranche {
+                amount,
+                hash: hash.clone(),
+                expiry,
+            }),
+        );
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "tranche_locked",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            TrancheLockedEvent {
+                stream_id,
+                amount,
+                hash,
+                expiry,
+            },
+        );
+        Ok(())
+    }
+
+    /// Take the stream's tranche by revealing the `preimage` of its hash
+    /// before it expires. Recipient-only.
+    ///
+    /// The tranche is paid at once like an accelerated payout: the stream's
+    /// withdrawal fee, payout split and payout address apply, and it counts
+    /// as deposited and withdrawn from then on.
+    ///
+    /// Returns the amount paid, withdrawal fee included.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `NotRecipient`      — caller is not the stream's recipient.
+    /// - `Unauthorized`      — `preimage` does not hash to the tranche's hash.
+    /// - `InvalidTransition` — no tranche is set aside, or it has expired.
+    /// - `TokenFrozen`       — the stream's token is frozen.
+    /// - `Paused`            — withdrawals are paused.
+    pub fn claim_tranche(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        preimage: Bytes,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        let tranche = try_load_tranche(&env, stream_id).ok_or(StreamError::InvalidTransition)?;
+        if env.ledger().timestamp() >= tranche.expiry {
+            return Err(StreamError::InvalidTransition);
+        }
+        if env.crypto().sha256(&preimage).to_bytes() != tranche.hash {
+            return Err(StreamError::Unauthorized);
+        }
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let amount = tranche.amount;
+        let withdrawal_fee = Self::withdrawal_fee_on(&env, &stream, amount);
+        Self::collect_withdrawal_fee(&env, &stream, stream_id, amount, withdrawal_fee);
+        if amount > withdrawal_fee {
+            let destination = Self::payout_destination(&stream);
+            Self::pay_with_split(&env, &stream, &destination, amount - withdrawal_fee);
+        }
+        stream.deposited_amount += amount;
+        stream.withdrawn_amount += amount;
+        Self::record_volume(&env, &stream.sender, &stream.token_address, amount);
+        save_stream(&env, stream_id, &stream);
+        save_tranche(&env, stream_id, None);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "tranche_claimed",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            TrancheClaimedEvent {
+                stream_id,
+                amount,
+                withdrawal_fee,
+                preimage,
+            },
+        );
+        Ok(amount)
+    }
+
+    /// Return an expired tranche to its stream, where it accrues again as
+    /// if topped up, pushing `end_time` back out. If the stream has ended
+    /// in the meantime the tranche is refunded to the sender instead.
+    /// Callable by anyone.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `InvalidTransition` — no tranche is set aside.
+    /// - `ClaimNotDue`       — the tranche has not expired yet.
+    pub fn release_tranche(env: Env, stream_id: u64) -> Result<(), StreamError> {
+        let mut stream = load_stream(&env, stream_id)?;
+        let tranche = try_load_tranche(&env, stream_id).ok_or(StreamError::InvalidTransition)?;
+        let now = env.ledger().timestamp();
+        if now < tranche.expiry {
+            return Err(StreamError::ClaimNotDue);
+        }
+
+        let refunded = !stream.is_active();
+        if refunded {
+            Self::send_tokens(&env, &stream.token_address, &stream.sender, tranche.amount);
+        } else {
+            Self::settle_accrued(&mut stream, now);
+            stream.deposited_amount += tranche.amount;
+            if !stream.is_gated() {
+                Self::reschedule_end(&mut stream);
+            }
+            save_stream(&env, stream_id, &stream);
+        }
+        save_tranche(&env, stream_id, None);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "tranche_released",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            TrancheReleasedEvent {
+                stream_id,
+                amount: tranche.amount,
+                refunded,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the stream's hash-locked tranche, or `None`.
+    pub fn get_tranche(env: Env, stream_id: u64) -> Option<HtlcTranche> {
+        try_load_tranche(&env, stream_id)
+    }
+}
+
+impl StreamContract {
+    /// Ends a settled stream when its unstreamed balance runs out at the
+    /// unchanged rate.
+    fn reschedule_end(stream: &mut Stream) {
+        let seconds = Self::seconds_to_stream(stream, Self::unstreamed(stream));
+        stream.end_time = stream.last_update_time.saturating_add(seconds);
+    }
+}
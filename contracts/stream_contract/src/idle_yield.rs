@@ -0,0 +1,139 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, YieldAdapterSetEvent, YieldReportedEvent, YieldSettledEvent};
+use crate::storage::{load_stream, save_stream, save_yield_adapter, try_load_yield_adapter};
+use crate::types::{Stream, YieldPolicy};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Idle Yield ───────────────────────────────────────────────────────────
+
+    /// Set (or with `None` clear) the contract allowed to report yield
+    /// earned on streams' idle principal. Admin-only.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_yield_adapter(
+        env: Env,
+        admin: Address,
+        adapter: Option<Address>,
+    ) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_yield_adapter")?;
+        save_yield_adapter(&env, adapter.as_ref());
+
+        env.events().publish(
+            (Symbol::new(&env, "yield_adapter_set"),),
+            YieldAdapterSetEvent { adapter },
+        );
+        Ok(())
+    }
+
+    /// Returns the configured yield adapter, or `None` if there is none.
+    pub fn get_yield_adapter(env: Env) -> Option<Address> {
+        try_load_yield_adapter(&env)
+    }
+
+    /// Credit `amount` of yield, in the stream's token, to `stream_id`.
+    /// Adapter-only; the adapter transfers the tokens in with the call.
+    ///
+    /// The yield is held as `pending_yield` and paid out under the stream's
+    /// `yield_policy` at its next withdrawal, cancellation or settlement.
+    ///
+    /// # Errors
+    /// - `Unauthorized`    — caller is not the configured yield adapter.
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn report_yield(
+        env: Env,
+        adapter: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        adapter.require_auth();
+
+        if try_load_yield_adapter(&env) != Some(adapter.clone()) {
+            return Err(StreamError::Unauthorized);
+        }
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+
+        Self::receive_tokens(&env, &stream.token_address, &adapter, amount);
+        stream.pending_yield += amount;
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "yield_reported",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            YieldReportedEvent {
+                stream_id,
+                amount,
+                pending_yield: stream.pending_yield,
+            },
+        );
+        Ok(())
+    }
+}
+
+impl StreamContract {
+    /// Rejects a `Split` policy giving the recipient more than 100%.
+    ///
+    /// # Errors
+    /// - `InvalidFeeRate` — the split exceeds 10 000 bps.
+    pub(crate) fn validate_yield_policy(policy: &YieldPolicy) -> Result<(), StreamError> {
+        match policy {
+            YieldPolicy::Split(bps) if *bps > 10_000 => Err(StreamError::InvalidFeeRate),
+            _ => Ok(()),
+        }
+    }
+
+    /// Pays out the stream's pending yield under its policy: the recipient's
+    /// share to its payout destination, the rest to the sender. The caller
+    /// persists the stream.
+    pub(crate) fn settle_yield(env: &Env, stream: &mut Stream, stream_id: u64) {
+        if stream.pending_yield <= 0 {
+            return;
+        }
+        let recipient_bps = match stream.yield_policy {
+            YieldPolicy::Sender => 0,
+            YieldPolicy::Recipient => 10_000,
+            YieldPolicy::Split(bps) => bps as i128,
+        };
+        let recipient_amount = stream.pending_yield * recipient_bps / 10_000;
+        let sender_amount = stream.pending_yield - recipient_amount;
+        if recipient_amount > 0 {
+            let destination = Self::payout_destination(stream);
+            Self::send_tokens(env, &stream.token_address, &destination, recipient_amount);
+        }
+        if sender_amount > 0 {
+            Self::send_tokens(env, &stream.token_address, &stream.sender, sender_amount);
+        }
+        stream.pending_yield = 0;
+
+        env.events().publish(
+            stream_topics(
+                env,
+                "yield_settled",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            YieldSettledEvent {
+                stream_id,
+                recipient_amount,
+                sender_amount,
+            },
+        );
+    }
+}
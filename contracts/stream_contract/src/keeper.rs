@@ -0,0 +1,140 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, KeeperBountyUpdatedEvent, StreamSettledEvent};
+use crate::storage::{
+    is_token_frozen, load_keeper_bounty_bps, load_pause_flags, save_keeper_bounty_bps, save_stream,
+    try_load_auto_claim, try_load_stream,
+};
+use crate::types::{ActivityKind, Stream, StreamCategory, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Maximum keeper bounty: 100 bps = 1% of the settled residue.
+pub(crate) const MAX_KEEPER_BOUNTY_BPS: u32 = 100;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Keepers ──────────────────────────────────────────────────────────────
+
+    /// Finalize streams that have run past their end. Callable by anyone.
+    ///
+    /// Each eligible stream pays its remaining balance to the recipient
+    /// (minus the stream's withdrawal fee and the keeper bounty, sent to
+    /// `keeper` subject to compliance screening), settles any outstanding
+    /// bonus to the recipient and idle yield under its policy, and is marked
+    /// inactive. IDs that are missing, inactive, not yet ended, or need
+    /// recipient action (a clawback window, a scheduled cancellation, a
+    /// pending condition, a frozen token, donation streams) are skipped
+    /// rather than failing the batch. So are streams whose cooldown or
+    /// withdrawal cap would stop the recipient taking the whole balance, and
+    /// streams the recipient claims on an auto-claim schedule.
+    ///
+    /// Returns the IDs that were settled.
+    pub fn settle_expired(env: Env, keeper: Address, stream_ids: Vec<u64>) -> Vec<u64> {
+        let now = env.ledger().timestamp();
+        let bounty_bps = load_keeper_bounty_bps(&env);
+        let mut settled = Vec::new(&env);
+        for stream_id in stream_ids.iter() {
+            let Some(mut stream) = try_load_stream(&env, stream_id) else {
+                continue;
+            };
+            if !Self::is_settleable(&env, stream_id, &stream, now)
+                || Self::transition(&mut stream, StreamStatus::Settled).is_err()
+            {
+                continue;
+            }
+
+            let amount = stream.deposited_amount - stream.withdrawn_amount;
+            let withdrawal_fee = Self::withdrawal_fee_on(&env, &stream, amount);
+            let bounty = amount * (bounty_bps as i128) / 10_000;
+            Self::collect_withdrawal_fee(&env, &stream, stream_id, amount, withdrawal_fee);
+            if bounty > 0 {
+                Self::send_screened(&env, &stream.token_address, &keeper, bounty);
+            }
+            if amount > withdrawal_fee + bounty {
+                let destination = Self::payout_destination(&stream);
+                Self::pay_with_split(
+                    &env,
+                    &stream,
+                    &destination,
+                    amount - withdrawal_fee - bounty,
+                );
+            }
+            Self::record_volume(&env, &stream.sender, &stream.token_address, amount);
+            if Self::bonus_outstanding(&stream) {
+                let recipient = stream.recipient.clone();
+                Self::settle_bonus(&env, &mut stream, stream_id, &recipient);
+            }
+
+            Self::settle_yield(&env, &mut stream, stream_id);
+            stream.withdrawn_amount = stream.deposited_amount;
+            stream.settled_balance = 0;
+            stream.last_update_time = now;
+            save_stream(&env, stream_id, &stream);
+
+            env.events().publish(
+                stream_topics(
+                    &env,
+                    "stream_settled",
+                    stream_id,
+                    &stream.sender,
+                    &stream.recipient,
+                ),
+                StreamSettledEvent {
+                    stream_id,
+                    keeper: keeper.clone(),
+                    amount,
+                    withdrawal_fee,
+                    bounty,
+                },
+            );
+            Self::record_activity(&env, ActivityKind::Settled, stream_id, amount);
+            settled.push_back(stream_id);
+        }
+        settled
+    }
+
+    /// Set the share of settled residue paid to keepers by `settle_expired`.
+    /// Admin-only.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidFeeRate` — `bounty_bps` exceeds `MAX_KEEPER_BOUNTY_BPS`.
+    pub fn set_keeper_bounty(env: Env, admin: Address, bounty_bps: u32) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_keeper_bounty")?;
+        if bounty_bps > MAX_KEEPER_BOUNTY_BPS {
+            return Err(StreamError::InvalidFeeRate);
+        }
+        save_keeper_bounty_bps(&env, bounty_bps);
+
+        env.events().publish(
+            (Symbol::new(&env, "keeper_bounty_updated"),),
+            KeeperBountyUpdatedEvent { bounty_bps },
+        );
+        Ok(())
+    }
+
+    /// Returns the keeper bounty rate in bps.
+    pub fn get_keeper_bounty(env: Env) -> u32 {
+        load_keeper_bounty_bps(&env)
+    }
+}
+
+impl StreamContract {
+    /// Whether `settle_expired` may finalize `stream` at `now`, status aside.
+    fn is_settleable(env: &Env, stream_id: u64, stream: &Stream, now: u64) -> bool {
+        now >= stream.end_time
+            && now >= Self::next_withdrawal_time(stream)
+            && Self::withdrawal_allowance(stream, now)
+                >= stream.deposited_amount - stream.withdrawn_amount
+            && try_load_auto_claim(env, stream_id).is_none()
+            && !Self::is_unclaimed_link(env, stream)
+            && stream.clawback_window == 0
+            && stream.cancel_at == 0
+            && !stream.is_gated()
+            && stream.category != StreamCategory::Donation
+            && !is_token_frozen(env, &stream.token_address)
+            && !load_pause_flags(env).withdrawals
+    }
+}
@@ -1,28 +1,108 @@
 #![no_std]
-
+#![allow(clippy::too_many_arguments)]
+
+mod accelerate;
+mod acknowledgement;
+mod activity;
+mod admin_log;
+mod allowlist;
+mod approvals;
+mod attestation;
+mod auto_claim;
+mod basket;
+mod budget;
+mod claim_link;
+mod compliance;
+mod conditions;
+mod custody;
+mod delegation;
+mod derived_id;
+mod distributor;
+mod donation;
 mod errors;
 mod events;
+mod fee_sponsorship;
+mod fees;
+mod groups;
+mod htlc;
+mod idle_yield;
+mod keeper;
+mod lifecycle;
+mod master;
+mod merge;
+mod migration;
+mod note;
+mod orgs;
+mod pause;
+mod rate;
+mod receipt;
+mod relayer;
+mod restart;
+mod scheduled_top_up;
+mod simulate;
+mod split_stream;
+mod stable;
+mod stats;
 mod storage;
+mod subscription;
+mod sunset;
+mod timelock;
+mod transfer;
 mod types;
+mod vault;
+mod vesting;
 
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_adversarial;
+#[cfg(test)]
+mod test_scenario;
 
-use soroban_sdk::{contract, contractimpl, token, vec, Address, Env, InvokeError, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Env, IntoVal, InvokeError, String, Symbol, Vec,
+};
 
+use custody::Funding;
 use errors::StreamError;
 use events::{
-    FeeCollectedEvent, StreamCancelledEvent, StreamCreatedEvent, StreamToppedUpEvent,
-    TokensWithdrawnEvent,
+    stream_topics, BeneficiaryClaimedEvent, BonusSettledEvent, CancelScheduledEvent,
+    DepletionWarningUpdatedEvent, FeeCollectedEvent, HoldReleasedEvent, MinClaimUpdatedEvent,
+    PayoutAddressUpdatedEvent, StreamCancelledEvent, StreamCheckpointedEvent, StreamCreatedEvent,
+    StreamExtendedEvent, StreamToppedUpEvent, TokensWithdrawnEvent, WithdrawalContestedEvent,
 };
+use rate::Accrual;
 use storage::{
-    config_exists, load_config, load_stream, next_stream_id, save_config, save_stream,
-    try_load_config, try_load_stream,
+    append_fee_record, append_to_group_index, append_to_tag_index, append_to_token_index,
+    config_exists, extend_stream_ttl, is_wound_down, load_config, load_keeper_bounty_bps,
+    load_stream, load_stream_state_only, load_tag_index, load_token_index, next_stream_id,
+    save_config, save_stream, save_stream_deposit_cap, save_stream_terms, save_token_info,
+    try_load_config, try_load_stream, try_load_stream_state, try_load_token_info,
+};
+use types::{
+    ActivityKind, ExactRate, FeeKind, FeeRecord, ProtocolConfig, ProtocolLimits, Stream,
+    StreamCategory, StreamCondition, StreamHealth, StreamOptions, StreamProgress, StreamRate,
+    StreamStatus, TokenInfo,
 };
-use types::{ProtocolConfig, Stream};
 
 /// Maximum allowed protocol fee: 1 000 bps = 10%.
-const MAX_FEE_RATE_BPS: u32 = 1_000;
+pub(crate) const MAX_FEE_RATE_BPS: u32 = 1_000;
+
+/// Maximum number of tags a stream may carry.
+pub(crate) const MAX_TAGS: u32 = 5;
+
+/// Smallest gross deposit, in token base units.
+pub(crate) const MIN_AMOUNT: i128 = 1;
+
+/// Rates are whole token base units per second.
+const RATE_GRANULARITY: i128 = 1;
+
+/// Seconds in a day, for per-day rate conversions.
+const SECONDS_PER_DAY: i128 = 86_400;
+
+/// Runway below which stream writes emit `stream_depleting` until the admin
+/// changes it: 7 days.
+const DEFAULT_DEPLETION_WARNING_PERIOD: u64 = 7 * 86_400;
 
 #[contract]
 pub struct StreamContract;
@@ -34,7 +114,8 @@ impl StreamContract {
     /// One-time initialization of the protocol fee configuration.
     ///
     /// # Errors
-    /// - `AlreadyInitialized` — called more than once.
+    /// - `AlreadyInitialized` — called more than once, or after the admin
+    ///   renounced its role.
     /// - `InvalidFeeRate`     — `fee_rate_bps` exceeds `MAX_FEE_RATE_BPS`.
     pub fn initialize(
         env: Env,
@@ -44,7 +125,7 @@ impl StreamContract {
     ) -> Result<(), StreamError> {
         admin.require_auth();
 
-        if config_exists(&env) {
+        if config_exists(&env) || is_wound_down(&env) {
             return Err(StreamError::AlreadyInitialized);
         }
         if fee_rate_bps > MAX_FEE_RATE_BPS {
@@ -57,6 +138,12 @@ impl StreamContract {
                 admin,
                 treasury,
                 fee_rate_bps,
+                sender_allowlist_enabled: false,
+                attestation_registry: None,
+                withdrawal_fee_bps: 0,
+                min_claim_amount: 0,
+                depletion_warning_period: DEFAULT_DEPLETION_WARNING_PERIOD,
+                compliance_oracle: None,
             },
         );
         Ok(())
@@ -74,12 +161,7 @@ impl StreamContract {
         treasury: Address,
         fee_rate_bps: u32,
     ) -> Result<(), StreamError> {
-        admin.require_auth();
-
-        let config = load_config(&env)?;
-        if config.admin != admin {
-            return Err(StreamError::NotAdmin);
-        }
+        let config = Self::require_admin(&env, &admin, "update_fee_config")?;
         if fee_rate_bps > MAX_FEE_RATE_BPS {
             return Err(StreamError::InvalidFeeRate);
         }
@@ -87,11 +169,73 @@ impl StreamContract {
         save_config(
             &env,
             &ProtocolConfig {
-                admin: config.admin,
                 treasury,
                 fee_rate_bps,
+                ..config
+            },
+        );
+        Ok(())
+    }
+
+    /// Set the smallest amount a withdrawal may claim, so recipients and
+    /// relayers can't spam dust withdrawals. Admin-only; 0 disables it.
+    ///
+    /// A withdrawal that empties the stream is always allowed, so balances
+    /// below the minimum can still be drained.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    /// - `InvalidAmount`  — `min_claim_amount` < 0.
+    pub fn set_min_claim_amount(
+        env: Env,
+        admin: Address,
+        min_claim_amount: i128,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_min_claim_amount")?;
+        if min_claim_amount < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        save_config(
+            &env,
+            &ProtocolConfig {
+                min_claim_amount,
+                ..config
             },
         );
+
+        env.events().publish(
+            (Symbol::new(&env, "min_claim_updated"),),
+            MinClaimUpdatedEvent { min_claim_amount },
+        );
+        Ok(())
+    }
+
+    /// Set how close to running dry a stream must be for writes to it to
+    /// emit `stream_depleting`, in seconds of runway. Admin-only; 0
+    /// disables the warning.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_depletion_warning_period(
+        env: Env,
+        admin: Address,
+        period: u64,
+    ) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "set_depletion_warning_period")?;
+        save_config(
+            &env,
+            &ProtocolConfig {
+                depletion_warning_period: period,
+                ..config
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "depletion_warning_updated"),),
+            DepletionWarningUpdatedEvent { period },
+        );
         Ok(())
     }
 
@@ -100,19 +244,57 @@ impl StreamContract {
         try_load_config(&env)
     }
 
+    /// Returns the deployed contract's compiled-in limits and configured
+    /// rates, so clients can validate inputs before submitting.
+    pub fn get_limits(env: Env) -> ProtocolLimits {
+        ProtocolLimits {
+            max_fee_rate_bps: MAX_FEE_RATE_BPS,
+            fee_rate_bps: Self::current_fee_rate(&env),
+            min_amount: MIN_AMOUNT,
+            max_duration: u64::MAX,
+            rate_granularity: RATE_GRANULARITY,
+            max_tags: MAX_TAGS,
+            max_basket_tokens: basket::MAX_BASKET_TOKENS,
+            max_keeper_bounty_bps: keeper::MAX_KEEPER_BOUNTY_BPS,
+            keeper_bounty_bps: load_keeper_bounty_bps(&env),
+            ttl_low_threshold: storage::TTL_LOW_THRESHOLD,
+            price_scale: stable::PRICE_SCALE,
+            max_note_bytes: note::MAX_NOTE_BYTES,
+            max_withdrawal_fee_bps: fees::MAX_WITHDRAWAL_FEE_BPS,
+            withdrawal_fee_bps: Self::current_withdrawal_fee_rate(&env),
+            min_claim_amount: try_load_config(&env).map_or(0, |cfg| cfg.min_claim_amount),
+            depletion_warning_period: try_load_config(&env)
+                .map_or(0, |cfg| cfg.depletion_warning_period),
+        }
+    }
+
     // ─── Stream Operations ────────────────────────────────────────────────────
 
     /// Create a new payment stream.
     ///
     /// Transfers `amount` tokens from `sender` to the contract, deducts the
-    /// protocol fee (if configured), and records the stream with a calculated
-    /// `rate_per_second = net_amount / duration`.
+    /// protocol fee (if configured), and records the stream with the exact
+    /// rate `net_amount / duration`: after `t` seconds the recipient has
+    /// accrued `net_amount * t / duration`, rounded down, and the whole
+    /// deposit by `end_time`.
     ///
     /// Returns the new stream ID (starts at 1, increments monotonically).
     ///
+    /// Equivalent to `create_stream_with_options` with default `StreamOptions`.
+    ///
+    /// `sender` may be a contract (DAO treasury, multisig). Calling this
+    /// directly authorizes it as the invoker, but the token pull is a nested
+    /// call: the sender must first pre-authorize
+    /// `token.transfer(sender, this_contract, amount)` with
+    /// `authorize_as_current_contract`, and likewise for top-ups and any
+    /// completion bonus. Cancellation moves no tokens out of the sender and
+    /// needs no extra authorization.
+    ///
     /// # Errors
     /// - `InvalidAmount`   — `amount` ≤ 0.
     /// - `InvalidDuration` — `duration` is 0.
+    /// - `RateTooLow`      — the net deposit is smaller than `duration`, so
+    ///   `rate_per_second` would be 0.
     /// - `InvalidTokenAddress` — `token_address` is not a token contract.
     pub fn create_stream(
         env: Env,
@@ -122,46 +304,321 @@ impl StreamContract {
         amount: i128,
         duration: u64,
     ) -> Result<u64, StreamError> {
-        sender.require_auth();
+        let options = StreamOptions::new(&env);
+        Self::create_stream_with_options(
+            env,
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+        )
+    }
 
-        if amount <= 0 {
+    /// Create a new payment stream and return its ID together with the
+    /// persisted record.
+    ///
+    /// Behaves exactly like `create_stream`; the returned `Stream` shows the
+    /// net deposit after fees, the computed rate and the timestamps, so
+    /// callers need no follow-up `get_stream` call.
+    ///
+    /// # Errors
+    /// Same as `create_stream`.
+    pub fn create_stream_detailed(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(u64, Stream), StreamError> {
+        let stream_id = Self::create_stream(
+            env.clone(),
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+        )?;
+        Ok((stream_id, load_stream(&env, stream_id)?))
+    }
+
+    /// Create a new payment stream that runs from `start_time` to `end_time`
+    /// (ledger timestamps) rather than for a duration from now.
+    ///
+    /// Equivalent to `create_stream_with_options` with a duration of
+    /// `end_time - start_time` and `StreamOptions::start_time` set, so a
+    /// `start_time` in the future defers accrual until then.
+    ///
+    /// # Errors
+    /// Same as `create_stream`, plus:
+    /// - `InvalidDuration` — `end_time` is not after `start_time`, or
+    ///   `start_time` is in the past.
+    pub fn create_stream_with_times(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<u64, StreamError> {
+        if start_time == 0 || end_time <= start_time {
+            return Err(StreamError::InvalidDuration);
+        }
+        let options = StreamOptions {
+            start_time,
+            ..StreamOptions::new(&env)
+        };
+        Self::create_stream_with_options(
+            env,
+            sender,
+            recipient,
+            token_address,
+            amount,
+            end_time - start_time,
+            options,
+        )
+    }
+
+    /// Create a new payment stream with optional creation-time settings.
+    ///
+    /// Behaves like `create_stream`, additionally applying `options`
+    /// (see `StreamOptions` for the available settings).
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `InvalidDuration` — `duration` is 0.
+    /// - `RateTooLow`      — the net deposit is smaller than `duration`.
+    /// - `PartnerNotFound` — `options.partner` is not a registered partner.
+    /// - `TokenFrozen`     — `token_address` is frozen.
+    /// - `DepositCapExceeded` — the deposit and bonus would exceed the token's cap.
+    /// - `InvalidTokenAddress` — `token_address` is not a token contract.
+    /// - `InvalidAmount`   — `options.withdrawal_cap` is negative.
+    /// - `InvalidDuration` — a withdrawal cap is set with a zero period.
+    /// - `InvalidDuration` — `options.cliff_duration` exceeds `duration`.
+    /// - `InvalidDuration` — `options.start_time` is in the past, or set on a
+    ///   stream with a start condition.
+    /// - `TooManyTags`     — more than `MAX_TAGS` tags supplied.
+    /// - `InvalidFeeRate`  — a `YieldPolicy::Split` share exceeds 10 000 bps.
+    /// - `InvalidAmount`   — `options.bonus_amount` is negative.
+    /// - `InvalidAmount`   — `options.deposit_cap` is negative.
+    /// - `DepositCapExceeded` — the net deposit already exceeds `options.deposit_cap`.
+    /// - `SenderNotAllowed` — permissioned mode is on and `sender` is not allowlisted.
+    /// - `PayeeNotAllowed` — `sender`'s payee allowlist is on and omits `recipient`.
+    /// - `AttestationMissing` — a registry is configured and either party lacks an attestation.
+    /// - `Unauthorized`    — `options.condition` names an unregistered oracle.
+    /// - `BudgetExceeded`  — `amount` exceeds `sender`'s remaining spending limit.
+    /// - `ApprovalRequired` — `sender`'s approval policy requires approvals for
+    ///   `amount`; use `propose_stream` instead.
+    pub fn create_stream_with_options(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        options: StreamOptions,
+    ) -> Result<u64, StreamError> {
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        Self::open_stream(
+            env,
+            None,
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+            Funding::Direct,
+        )
+    }
+
+    /// Shared creation path for plain streams: uses `stream_id` when given (the caller has
+    /// checked it is free), otherwise draws the next counter ID.
+    fn open_stream(
+        env: Env,
+        stream_id: Option<u64>,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        options: StreamOptions,
+        funding: Funding,
+    ) -> Result<u64, StreamError> {
+        if !matches!(funding, Funding::Allowance | Funding::Preauthorized) {
+            sender.require_auth();
+        }
+
+        if amount < MIN_AMOUNT {
             return Err(StreamError::InvalidAmount);
         }
-        if duration == 0 {
+        if duration == 0 || options.cliff_duration > duration {
             return Err(StreamError::InvalidDuration);
         }
-        Self::validate_token_contract(&env, &token_address)?;
-
-        let stream_id = next_stream_id(&env);
-        let start_time = env.ledger().timestamp();
+        let start_time = Self::scheduled_start(&env, &options)?;
+        if options.withdrawal_cap < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if options.withdrawal_cap > 0 && options.withdrawal_cap_period == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+        if options.tags.len() > MAX_TAGS {
+            return Err(StreamError::TooManyTags);
+        }
+        if options.bonus_amount < 0 || options.deposit_cap < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::validate_yield_policy(&options.yield_policy)?;
+        if let StreamCondition::Oracle(oracle, _) = &options.condition {
+            Self::require_condition_oracle(&env, oracle)?;
+        }
+        Self::require_sender_allowed(&env, &sender)?;
+        Self::require_payee_allowed(&env, &sender, &recipient)?;
+        Self::require_attested(&env, &sender, &recipient)?;
+        let token_decimals = Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+
+        let stream_id = stream_id.unwrap_or_else(|| next_stream_id(&env));
+
+        // Snapshot the fee rate so later config changes never affect this stream.
+        let fee_rate_bps = match Self::take_custom_fee_rate(&env, stream_id) {
+            Some(rate) => rate,
+            None => Self::creation_fee_rate(&env, &options)?,
+        };
+
+        Self::require_streamable(amount, duration, fee_rate_bps)?;
+
+        if funding != Funding::Vault {
+            Self::require_within_deposit_cap(
+                &env,
+                &token_address,
+                amount.saturating_add(options.bonus_amount),
+            )?;
+        }
+        Self::record_spend(&env, &sender, &token_address, amount)?;
 
         // Transfer gross amount from sender to this contract.
-        let token_client = token::Client::new(&env, &token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&sender, &contract_address, &amount);
+        Self::receive_funding(&env, &token_address, &sender, amount, funding);
 
         // Deduct protocol fee; returns net amount (== amount when no fee config).
-        let net_amount = Self::collect_fee(&env, &token_address, amount, stream_id);
-        let rate_per_second = net_amount / (duration as i128);
-
-        save_stream(
+        let partner = options.partner.as_ref();
+        let net_amount = Self::collect_fee(
             &env,
+            &sender,
+            &token_address,
+            amount,
+            fee_rate_bps,
             stream_id,
-            &Stream {
-                sender: sender.clone(),
-                recipient: recipient.clone(),
-                token_address: token_address.clone(),
-                rate_per_second,
-                deposited_amount: net_amount,
-                withdrawn_amount: 0,
-                start_time,
-                last_update_time: start_time,
-                is_active: true,
-            },
+            partner,
         );
+        let rate_per_second = net_amount / (duration as i128);
+
+        // Escrow the completion bonus, charged at the same fee rate.
+        let bonus_amount = if options.bonus_amount > 0 {
+            Self::receive_funding(&env, &token_address, &sender, options.bonus_amount, funding);
+            Self::collect_fee(
+                &env,
+                &sender,
+                &token_address,
+                options.bonus_amount,
+                fee_rate_bps,
+                stream_id,
+                partner,
+            )
+        } else {
+            0
+        };
+
+        append_to_token_index(&env, &token_address, stream_id);
+
+        let mut tags = Vec::new(&env);
+        for tag in options.tags.iter() {
+            if !tags.contains(&tag) {
+                append_to_tag_index(&env, &sender, &tag, stream_id);
+                tags.push_back(tag);
+            }
+        }
+        if let Some(group) = &options.group {
+            append_to_group_index(&env, &sender, group, stream_id);
+        }
+
+        let mut stream = Stream {
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            token_address: token_address.clone(),
+            rate: ExactRate {
+                numerator: net_amount,
+                denominator: duration,
+                anchor: start_time,
+                // A future start locks the stream until then, so settling a
+                // top-up early cannot move `last_update_time` back to now.
+                cliff: if options.cliff_duration > 0 || start_time > env.ledger().timestamp() {
+                    start_time.saturating_add(options.cliff_duration)
+                } else {
+                    0
+                },
+            },
+            deposited_amount: net_amount,
+            withdrawn_amount: 0,
+            start_time,
+            last_update_time: start_time,
+            status: StreamStatus::Active,
+            fee_rate_bps,
+            withdrawal_cooldown: options.withdrawal_cooldown,
+            last_withdrawal_time: start_time,
+            settled_balance: 0,
+            withdrawal_cap: options.withdrawal_cap,
+            withdrawal_cap_period: options.withdrawal_cap_period,
+            cap_window_start: start_time,
+            cap_window_withdrawn: 0,
+            tags,
+            end_time: if options.condition != StreamCondition::Unconditional {
+                u64::MAX
+            } else {
+                start_time.saturating_add(duration)
+            },
+            bonus_amount,
+            bonus_claimed: false,
+            clawback_window: options.clawback_window,
+            held_amount: 0,
+            held_until: 0,
+            hold_contested: false,
+            split_address: None,
+            split_bps: 0,
+            payout_address: None,
+            beneficiary: None,
+            beneficiary_inactivity_period: 0,
+            cancel_at: 0,
+            category: options.category,
+            partner: options.partner.clone(),
+            live_until_ledger: 0,
+            withdrawal_fee_bps: Self::current_withdrawal_fee_rate(&env),
+            relayer_fee: 0,
+            token_decimals,
+            yield_policy: options.yield_policy,
+            pending_yield: 0,
+            condition: options.condition.clone(),
+        };
+        if options.deposit_cap > 0 {
+            save_stream_deposit_cap(&env, stream_id, options.deposit_cap);
+            Self::require_within_stream_cap(&env, stream_id, &stream)?;
+        }
+        save_stream_terms(&env, stream_id, &stream);
+        extend_stream_ttl(&env, stream_id, &mut stream);
+        Self::mint_receipt(&env, stream_id, &stream);
 
         env.events().publish(
-            (Symbol::new(&env, "stream_created"), stream_id),
+            stream_topics(
+                &env,
+                "stream_created",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
             StreamCreatedEvent {
                 stream_id,
                 sender,
@@ -172,20 +629,54 @@ impl StreamContract {
                 start_time,
             },
         );
+        Self::record_activity(&env, ActivityKind::Created, stream_id, net_amount);
 
         Ok(stream_id)
     }
 
+    /// Create a stream whose recipient is looked up by label in an
+    /// `AliasRegistry` contract, reducing the risk of mistyped addresses.
+    ///
+    /// `registry` is the address book contract and `org` the organization
+    /// namespace in it; otherwise behaves like `create_stream`.
+    ///
+    /// # Errors
+    /// - `AliasNotFound`   — `label` is not registered for `org`, or
+    ///   `registry` is not an alias registry.
+    /// - Any error returned by `create_stream`.
+    pub fn create_stream_to_alias(
+        env: Env,
+        sender: Address,
+        registry: Address,
+        org: Address,
+        label: Symbol,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<u64, StreamError> {
+        let recipient = Self::resolve_alias(&env, &registry, org, label)?;
+        Self::create_stream(env, sender, recipient, token_address, amount, duration)
+    }
+
     /// Top up an active stream with additional tokens.
     ///
     /// Only the original sender may top up their own stream. The top-up amount
-    /// is subject to protocol fees (if configured) before being added to the stream.
+    /// is charged at the fee rate locked in when the stream was created, not the
+    /// current global rate.
+    ///
+    /// The stream keeps its rate, so `end_time` moves out by the time the net
+    /// top-up takes to stream (rounded up to a whole second).
     ///
     /// # Errors
     /// - `InvalidAmount`   — `amount` ≤ 0.
     /// - `StreamNotFound`  — no stream exists with `stream_id`.
-    /// - `Unauthorized`    — caller is not the stream's sender.
+    /// - `NotSender`       — caller is not the stream's sender.
     /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition` — the stream is a timelock.
+    /// - `BudgetExceeded`  — `amount` exceeds `sender`'s remaining spending limit.
+    /// - `TokenFrozen`     — the stream's token is frozen.
+    /// - `DepositCapExceeded` — `amount` would exceed the token's deposit cap,
+    ///   or the net top-up the stream's own cap.
     pub fn top_up_stream(
         env: Env,
         sender: Address,
@@ -199,89 +690,448 @@ impl StreamContract {
         }
 
         let mut stream = load_stream(&env, stream_id)?;
+        let net_amount = Self::deposit_top_up(
+            &env,
+            &sender,
+            stream_id,
+            &mut stream,
+            amount,
+            Funding::Direct,
+        )?;
 
-        // Validate ownership and active status using helper functions
-        Self::validate_stream_ownership(&stream, &sender)?;
-        Self::validate_stream_active(&stream)?;
+        let extension = Self::seconds_to_stream(&stream, net_amount);
+        stream.end_time = stream.end_time.saturating_add(extension);
+
+        save_stream(&env, stream_id, &stream);
+        Self::emit_top_up(&env, sender, stream_id, net_amount, &stream);
+        Ok(())
+    }
 
-        // Transfer tokens from sender to contract
-        let token_client = token::Client::new(&env, &stream.token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&sender, &contract_address, &amount);
+    /// Top up an active stream, keeping its rate and pushing `end_time` out.
+    ///
+    /// Unlike `top_up_stream`, the new end is recomputed from the whole
+    /// unstreamed balance rather than added per top-up, so repeated top-ups
+    /// never accumulate rounding drift. The balance is not always a multiple
+    /// of the rate; the leftover `remainder` streams in the final second.
+    ///
+    /// Returns the new `end_time` and emits a `stream_extended` event with the
+    /// resulting schedule.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0, or the stream's rate is 0.
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `BudgetExceeded`  — `amount` exceeds `sender`'s remaining spending limit.
+    /// - `TokenFrozen`     — the stream's token is frozen.
+    /// - `DepositCapExceeded` — `amount` would exceed the token's deposit cap,
+    ///   or the net top-up the stream's own cap.
+    pub fn top_up_extend(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
 
-        // Collect protocol fee and get net amount
-        let net_amount = Self::collect_fee(&env, &stream.token_address, amount, stream_id);
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
 
-        // Update stream state
-        stream.deposited_amount += net_amount;
-        stream.last_update_time = env.ledger().timestamp();
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.rate_per_second() <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let previous_end_time = stream.end_time;
+        let net_amount = Self::deposit_top_up(
+            &env,
+            &sender,
+            stream_id,
+            &mut stream,
+            amount,
+            Funding::Direct,
+        )?;
+
+        // Everything not yet accrued streams from now at the unchanged rate;
+        // a stream waiting on its condition gets its end once it starts.
+        let unstreamed = stream.deposited_amount - stream.withdrawn_amount - stream.settled_balance;
+        let remainder = unstreamed % stream.rate_per_second();
+        if !stream.is_gated() {
+            let seconds = Self::seconds_to_stream(&stream, unstreamed);
+            stream.end_time = stream.last_update_time.saturating_add(seconds);
+        }
 
         save_stream(&env, stream_id, &stream);
+        Self::emit_top_up(&env, sender, stream_id, net_amount, &stream);
 
-        // Emit top-up event
         env.events().publish(
-            (Symbol::new(&env, "stream_topped_up"), stream_id),
-            StreamToppedUpEvent {
+            stream_topics(
+                &env,
+                "stream_extended",
                 stream_id,
-                sender,
-                amount: net_amount,
-                new_deposited_amount: stream.deposited_amount,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamExtendedEvent {
+                stream_id,
+                rate_per_second: stream.rate_per_second(),
+                previous_end_time,
+                new_end_time: stream.end_time,
+                remainder,
             },
         );
 
-        Ok(())
+        Ok(stream.end_time)
     }
 
     // ─── Internal Helpers ─────────────────────────────────────────────────────
 
-    /// Ensures the supplied token address implements the Soroban token interface.
-    fn validate_token_contract(env: &Env, token_address: &Address) -> Result<(), StreamError> {
-        match env.try_invoke_contract::<u32, InvokeError>(
-            token_address,
-            &Symbol::new(env, "decimals"),
-            vec![env],
+    /// Shared top-up path: checks the caller, pulls `amount` from `sender`,
+    /// charges the stream's locked-in fee and credits the net amount after
+    /// settling what has accrued so far. Returns the net amount.
+    ///
+    /// The caller adjusts `end_time` and persists the stream.
+    fn deposit_top_up(
+        env: &Env,
+        sender: &Address,
+        stream_id: u64,
+        stream: &mut Stream,
+        amount: i128,
+        funding: Funding,
+    ) -> Result<i128, StreamError> {
+        Self::validate_stream_ownership(stream, sender)?;
+        Self::validate_stream_active(stream)?;
+        if stream.is_timelock() {
+            return Err(StreamError::InvalidTransition);
+        }
+        Self::require_token_not_frozen(env, &stream.token_address)?;
+        Self::require_unpaused(env, |p| p.top_ups)?;
+        // Escrowed top-ups were checked against the cap and spending limit
+        // when scheduled.
+        if matches!(
+            funding,
+            Funding::Direct | Funding::Preauthorized | Funding::Allowance
         ) {
-            Ok(Ok(_)) => Ok(()),
-            _ => Err(StreamError::InvalidTokenAddress),
+            Self::require_within_deposit_cap(env, &stream.token_address, amount)?;
+        }
+        if funding != Funding::Escrow {
+            Self::record_spend(env, sender, &stream.token_address, amount)?;
         }
+
+        Self::receive_funding(env, &stream.token_address, sender, amount, funding);
+
+        // Collect protocol fee at the stream's locked-in rate and get net amount
+        let net_amount = Self::collect_fee(
+            env,
+            sender,
+            &stream.token_address,
+            amount,
+            stream.fee_rate_bps,
+            stream_id,
+            stream.partner.as_ref(),
+        );
+
+        // Lock in everything accrued so far before the deposit changes
+        Self::settle_accrued(stream, env.ledger().timestamp());
+        stream.deposited_amount += net_amount;
+        Self::require_within_stream_cap(env, stream_id, stream)?;
+        Ok(net_amount)
     }
 
-    /// Calculate the claimable amount for a stream at a given timestamp.
-    ///
-    /// This helper computes how many tokens have been streamed since the last
-    /// update, capped at the remaining balance to prevent over-withdrawal.
-    ///
-    /// # Arguments
-    /// * `stream` - The stream to calculate claimable amount for
-    /// * `now` - Current ledger timestamp
-    ///
-    /// # Returns
-    /// The amount of tokens that can be claimed, never exceeding remaining balance
-    fn calculate_claimable(stream: &Stream, now: u64) -> i128 {
-        let elapsed = now.saturating_sub(stream.last_update_time);
+    /// Cancellation settlement shared by `cancel_stream` and scheduled
+    /// cancellations: pays the recipient everything accrued (up to
+    /// `cancel_at` if set), refunds the rest to the sender, settles any
+    /// bonus, and persists the now-inactive stream. Irrevocable streams are
+    /// refused.
+    fn settle_cancellation(
+        env: &Env,
+        stream_id: u64,
+        stream: &mut Stream,
+    ) -> Result<(), StreamError> {
+        Self::require_revocable(env, stream_id)?;
+        Self::transition(stream, StreamStatus::Cancelled)?;
+        let now = env.ledger().timestamp();
+        // An unclaimed link has no one to pay, so the sender gets it all back.
+        let accrued_amount = if Self::is_unclaimed_link(env, stream) {
+            0
+        } else {
+            Self::calculate_claimable(stream, now)
+        };
+
+        let sender = stream.sender.clone();
 
-        let streamed = (elapsed as i128)
-            .checked_mul(stream.rate_per_second)
-            .unwrap_or(i128::MAX);
+        // Settle recipient with all accrued tokens at cancellation
+        if accrued_amount > 0 {
+            let destination = Self::payout_destination(stream);
+            Self::deliver_to_recipient(env, stream, &destination, accrued_amount, now);
+            stream.withdrawn_amount = stream.withdrawn_amount.saturating_add(accrued_amount);
+            Self::record_volume(env, &sender, &stream.token_address, accrued_amount);
+        }
+        stream.settled_balance = 0;
 
-        let remaining = stream
+        // Calculate and refund remaining balance to sender
+        let refunded_amount = stream
             .deposited_amount
             .saturating_sub(stream.withdrawn_amount);
 
-        streamed.min(remaining)
-    }
+        if refunded_amount > 0 {
+            Self::send_screened(env, &stream.token_address, &sender, refunded_amount);
+        }
 
-    /// Validate that a stream exists and is owned by the caller.
-    ///
-    /// # Errors
-    /// - `StreamNotFound` — no stream exists with `stream_id`.
-    /// - `Unauthorized` — caller is not the stream's sender.
-    fn validate_stream_ownership(
-        stream: &Stream,
-        caller: &Address,
-    ) -> Result<(), StreamError> {
-        if stream.sender != *caller {
-            return Err(StreamError::Unauthorized);
+        if Self::bonus_outstanding(stream) {
+            let bonus_to = if Self::accrual_cutoff(stream, now) >= stream.end_time {
+                stream.recipient.clone()
+            } else {
+                sender.clone()
+            };
+            Self::settle_bonus(env, stream, stream_id, &bonus_to);
+        }
+
+        Self::settle_yield(env, stream, stream_id);
+        stream.last_update_time = now;
+
+        let recipient = stream.recipient.clone();
+        let amount_withdrawn = stream.withdrawn_amount;
+
+        save_stream(env, stream_id, stream);
+
+        // Emit cancellation event
+        env.events().publish(
+            stream_topics(
+                env,
+                "stream_cancelled",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamCancelledEvent {
+                stream_id,
+                sender,
+                recipient,
+                amount_withdrawn,
+                refunded_amount,
+                remaining_deposit: stream.deposited_amount - amount_withdrawn - refunded_amount,
+                status: stream.status,
+            },
+        );
+        Self::record_activity(env, ActivityKind::Cancelled, stream_id, refunded_amount);
+        Ok(())
+    }
+
+    /// Emits the `stream_topped_up` event for a completed top-up.
+    fn emit_top_up(env: &Env, sender: Address, stream_id: u64, net_amount: i128, stream: &Stream) {
+        env.events().publish(
+            stream_topics(
+                env,
+                "stream_topped_up",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamToppedUpEvent {
+                stream_id,
+                sender,
+                amount: net_amount,
+                new_deposited_amount: stream.deposited_amount,
+            },
+        );
+        Self::record_activity(env, ActivityKind::ToppedUp, stream_id, net_amount);
+    }
+
+    /// Requires `admin`'s authorization and checks it is the protocol admin,
+    /// recording `action` in the admin log. Should the action then fail, the
+    /// record is rolled back with the rest of the call.
+    ///
+    /// Returns the loaded config so callers can update it.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — `admin` is not the current admin.
+    fn require_admin(
+        env: &Env,
+        admin: &Address,
+        action: &str,
+    ) -> Result<ProtocolConfig, StreamError> {
+        admin.require_auth();
+
+        let config = load_config(env)?;
+        if config.admin != *admin {
+            return Err(StreamError::NotAdmin);
+        }
+        Self::record_admin_action(env, admin, action);
+        Ok(config)
+    }
+
+    /// Ensures the supplied token address implements the Soroban token interface.
+    ///
+    /// Returns the token's decimals, asking the token only on its first use
+    /// and caching its metadata from then on.
+    fn validate_token_contract(env: &Env, token_address: &Address) -> Result<u32, StreamError> {
+        if let Some(info) = try_load_token_info(env, token_address) {
+            return Ok(info.decimals);
+        }
+        let decimals = match env.try_invoke_contract::<u32, InvokeError>(
+            token_address,
+            &Symbol::new(env, "decimals"),
+            vec![env],
+        ) {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Err(StreamError::InvalidTokenAddress),
+        };
+        let symbol = match env.try_invoke_contract::<String, InvokeError>(
+            token_address,
+            &Symbol::new(env, "symbol"),
+            vec![env],
+        ) {
+            Ok(Ok(symbol)) => Some(symbol),
+            _ => None,
+        };
+        save_token_info(env, token_address, &TokenInfo { decimals, symbol });
+        Ok(decimals)
+    }
+
+    /// Resolves `label` in `org`'s namespace of the alias registry at `registry`.
+    fn resolve_alias(
+        env: &Env,
+        registry: &Address,
+        org: Address,
+        label: Symbol,
+    ) -> Result<Address, StreamError> {
+        match env.try_invoke_contract::<Option<Address>, InvokeError>(
+            registry,
+            &Symbol::new(env, "resolve"),
+            vec![env, org.into_val(env), label.into_val(env)],
+        ) {
+            Ok(Ok(Some(address))) => Ok(address),
+            _ => Err(StreamError::AliasNotFound),
+        }
+    }
+
+    /// Calculate the claimable amount for a stream at a given timestamp.
+    ///
+    /// This helper computes how many tokens have been streamed since the last
+    /// update, plus any balance already settled but not yet withdrawn, capped
+    /// at the remaining balance to prevent over-withdrawal.
+    ///
+    /// # Arguments
+    /// * `stream` - The stream to calculate claimable amount for
+    /// * `now` - Current ledger timestamp
+    ///
+    /// # Returns
+    /// The amount of tokens that can be claimed, never exceeding remaining balance
+    fn calculate_claimable(stream: &impl Accrual, now: u64) -> i128 {
+        let cutoff = Self::accrual_cutoff(stream, now);
+        let last_update_time = stream.last_update_time();
+        let streamed = if cutoff > last_update_time {
+            Self::streamed_between(stream, last_update_time, cutoff)
+        } else {
+            0
+        };
+
+        stream.settled_balance() + streamed.min(stream.unsettled())
+    }
+
+    /// `now`, clamped to a scheduled cancellation time if one is set. A
+    /// stream waiting on its condition, or stopped before its cliff, accrues
+    /// nothing.
+    fn accrual_cutoff(stream: &impl Accrual, now: u64) -> u64 {
+        let cutoff = if stream.cancel_at() > 0 {
+            now.min(stream.cancel_at())
+        } else {
+            now
+        };
+        let gated = *stream.condition() != StreamCondition::Unconditional;
+        if gated || cutoff < stream.rate().cliff {
+            stream.last_update_time()
+        } else {
+            cutoff
+        }
+    }
+
+    /// Materializes everything accrued up to `now` into `settled_balance` and
+    /// advances `last_update_time`, so that a partial withdrawal never forfeits
+    /// the part of the accrual it leaves behind. Before the cliff nothing is
+    /// settled, so the accrual up to it is released in one piece.
+    fn settle_accrued(stream: &mut Stream, now: u64) {
+        stream.settled_balance = Self::calculate_claimable(stream, now);
+        if !stream.is_locked(now) {
+            stream.last_update_time = now;
+        }
+    }
+
+    /// Amount the recipient may still withdraw in the current cap window, or
+    /// `i128::MAX` for streams without a withdrawal cap.
+    ///
+    /// A window opens at creation and is replaced by a fresh one at the first
+    /// withdrawal after `withdrawal_cap_period` seconds have passed.
+    fn withdrawal_allowance(stream: &Stream, now: u64) -> i128 {
+        if stream.withdrawal_cap == 0 {
+            return i128::MAX;
+        }
+        let window_end = stream
+            .cap_window_start
+            .saturating_add(stream.withdrawal_cap_period);
+        if now >= window_end {
+            stream.withdrawal_cap
+        } else {
+            stream
+                .withdrawal_cap
+                .saturating_sub(stream.cap_window_withdrawn)
+        }
+    }
+
+    /// Records `amount` against the stream's current cap window, rolling the
+    /// window forward first if it has expired. No-op for uncapped streams.
+    fn record_capped_withdrawal(stream: &mut Stream, amount: i128, now: u64) {
+        if stream.withdrawal_cap == 0 {
+            return;
+        }
+        let window_end = stream
+            .cap_window_start
+            .saturating_add(stream.withdrawal_cap_period);
+        if now >= window_end {
+            stream.cap_window_start = now;
+            stream.cap_window_withdrawn = 0;
+        }
+        stream.cap_window_withdrawn += amount;
+    }
+
+    /// Validate that a stream exists and is owned by the caller.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `NotSender`    — caller is not the stream's sender.
+    fn validate_stream_ownership(stream: &Stream, caller: &Address) -> Result<(), StreamError> {
+        if stream.sender != *caller {
+            return Err(StreamError::NotSender);
+        }
+        Ok(())
+    }
+
+    /// Earliest ledger timestamp at which the recipient may next withdraw.
+    ///
+    /// Streams without a cooldown are always withdrawable, so this returns the
+    /// time of the last withdrawal (or creation) in that case.
+    fn next_withdrawal_time(stream: &Stream) -> u64 {
+        stream
+            .last_withdrawal_time
+            .saturating_add(stream.withdrawal_cooldown)
+    }
+
+    /// Whether the stream still escrows an unclaimed completion bonus.
+    fn bonus_outstanding(stream: &Stream) -> bool {
+        stream.bonus_amount > 0 && !stream.bonus_claimed
+    }
+
+    /// Rejects a claim of `amount` below the protocol minimum unless it
+    /// empties the stream.
+    ///
+    /// # Errors
+    /// - `ClaimBelowMinimum` — `amount` is below `min_claim_amount`.
+    fn require_min_claim(env: &Env, stream: &Stream, amount: i128) -> Result<(), StreamError> {
+        let min_claim_amount = try_load_config(env).map_or(0, |cfg| cfg.min_claim_amount);
+        if amount < min_claim_amount && amount < stream.deposited_amount - stream.withdrawn_amount {
+            return Err(StreamError::ClaimBelowMinimum);
         }
         Ok(())
     }
@@ -291,34 +1141,129 @@ impl StreamContract {
     /// # Errors
     /// - `StreamInactive` — stream has been cancelled or fully withdrawn.
     fn validate_stream_active(stream: &Stream) -> Result<(), StreamError> {
-        if !stream.is_active {
+        if !stream.is_active() {
             return Err(StreamError::StreamInactive);
         }
         Ok(())
     }
 
-    /// Transfer tokens from contract to recipient and update stream state.
+    /// Transfer tokens from contract to `destination` and update stream state.
     ///
     /// This helper consolidates the token transfer logic and stream state updates
     /// to reduce code duplication across withdrawal operations.
+    ///
+    /// `fee` (already paid out by the caller) is deducted from what reaches
+    /// the recipient but counts towards the withdrawn `amount`.
+    ///
+    /// Returns the part of `amount` diverted to the recipient's split address.
     fn transfer_and_update_stream(
         env: &Env,
         stream: &mut Stream,
-        recipient: &Address,
+        destination: &Address,
         amount: i128,
+        fee: i128,
         now: u64,
-    ) {
-        let token_client = token::Client::new(env, &stream.token_address);
-        let contract_address = env.current_contract_address();
-        token_client.transfer(&contract_address, recipient, &amount);
+    ) -> Result<i128, StreamError> {
+        let split_amount = Self::deliver_to_recipient(env, stream, destination, amount - fee, now);
 
+        Self::settle_accrued(stream, now);
+        Self::record_capped_withdrawal(stream, amount, now);
+        stream.settled_balance -= amount;
         stream.withdrawn_amount += amount;
-        stream.last_update_time = now;
+        Self::record_volume(env, &stream.sender, &stream.token_address, amount);
+        stream.last_withdrawal_time = now;
+
+        // Mark stream as inactive if fully drained and no bonus is outstanding;
+        // donation streams stay open for further donations.
+        if stream.withdrawn_amount >= stream.deposited_amount
+            && !Self::bonus_outstanding(stream)
+            && stream.category != StreamCategory::Donation
+        {
+            Self::transition(stream, StreamStatus::Completed)?;
+        }
 
-        // Mark stream as inactive if fully drained
-        if stream.withdrawn_amount >= stream.deposited_amount {
-            stream.is_active = false;
+        Ok(split_amount)
+    }
+
+    /// Pays `amount` out of the stream to the recipient's `destination`,
+    /// returning the part diverted to the recipient's split address.
+    ///
+    /// Streams with a clawback window move the amount into the holding bucket
+    /// instead, (re)starting the window; it is released by `finalize_withdrawal`.
+    fn deliver_to_recipient(
+        env: &Env,
+        stream: &mut Stream,
+        destination: &Address,
+        amount: i128,
+        now: u64,
+    ) -> i128 {
+        if stream.clawback_window > 0 {
+            stream.held_amount += amount;
+            stream.held_until = now.saturating_add(stream.clawback_window);
+            return 0;
+        }
+        Self::pay_with_split(env, stream, destination, amount)
+    }
+
+    /// Address that receives the recipient's payouts: the payout address if
+    /// one is set, otherwise the recipient itself.
+    fn payout_destination(stream: &Stream) -> Address {
+        stream
+            .payout_address
+            .clone()
+            .unwrap_or_else(|| stream.recipient.clone())
+    }
+
+    /// Transfers `amount` to `to`, sending the recipient's configured split
+    /// share to the split address instead. Returns the diverted amount.
+    ///
+    /// Both transfers are screened by the compliance oracle, if one is set.
+    fn pay_with_split(env: &Env, stream: &Stream, to: &Address, amount: i128) -> i128 {
+        let split_amount = match &stream.split_address {
+            Some(split_address) => {
+                let split_amount = amount * (stream.split_bps as i128) / 10_000;
+                if split_amount > 0 {
+                    Self::send_screened(env, &stream.token_address, split_address, split_amount);
+                }
+                split_amount
+            }
+            None => 0,
+        };
+        if amount > split_amount {
+            Self::send_screened(env, &stream.token_address, to, amount - split_amount);
+        }
+        split_amount
+    }
+
+    /// Transfers the whole holding bucket to `to`, clears it, and emits a
+    /// `hold_released` event. The caller persists the stream.
+    fn release_hold(env: &Env, stream: &mut Stream, stream_id: u64, to: &Address) -> i128 {
+        let amount = stream.held_amount;
+        if *to == stream.recipient {
+            Self::pay_with_split(env, stream, &Self::payout_destination(stream), amount);
+        } else {
+            Self::send_tokens(env, &stream.token_address, to, amount);
         }
+
+        stream.held_amount = 0;
+        stream.hold_contested = false;
+
+        env.events().publish(
+            stream_topics(
+                env,
+                "hold_released",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            HoldReleasedEvent {
+                stream_id,
+                to: to.clone(),
+                amount,
+                paid_to_recipient: *to == stream.recipient,
+            },
+        );
+        amount
     }
 
     /// Withdraw all currently claimable tokens from a stream.
@@ -327,46 +1272,186 @@ impl StreamContract {
     /// based on elapsed time and the stream's rate. The stream is automatically marked
     /// inactive once fully drained.
     ///
+    /// For streams with a withdrawal cap, the amount is limited to what remains of
+    /// the current cap window; the excess stays claimable for later windows.
+    ///
+    /// For streams with a clawback window, the amount is moved into the holding
+    /// bucket rather than transferred; see `finalize_withdrawal`.
+    ///
+    /// If the recipient configured a payout split, that share of the amount is
+    /// sent to the split address in the same call.
+    ///
+    /// The stream's withdrawal fee, if any, accrues to the treasury out of the
+    /// amount. Returns the gross amount withdrawn from the stream.
+    ///
     /// # Errors
     /// - `StreamNotFound`  — no stream exists with `stream_id`.
-    /// - `Unauthorized`    — caller is not the stream's recipient.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
     /// - `StreamInactive`  — stream is already inactive.
+    /// - `CooldownActive`  — the stream's withdrawal cooldown has not elapsed;
+    ///   see `get_next_withdrawal_time`.
+    /// - `WithdrawalCapReached` — the current cap window is exhausted.
+    /// - `ClaimBelowMinimum` — the claim is below `min_claim_amount` and
+    ///   does not empty the stream.
+    /// - `HoldContested`   — the sender has contested the holding bucket.
     /// - `InvalidAmount`   — no claimable balance (fully withdrawn already).
+    /// - `TokenFrozen`     — the stream's token is frozen.
     pub fn withdraw(env: Env, recipient: Address, stream_id: u64) -> Result<i128, StreamError> {
         recipient.require_auth();
+        Self::execute_withdrawal(env, recipient, stream_id, None, None, None)
+    }
 
-        let mut stream = load_stream(&env, stream_id)?;
+    /// Withdraw all currently claimable tokens from a stream to `to` rather
+    /// than to the recipient or its payout address, for this call only.
+    ///
+    /// Behaves like `withdraw` otherwise; a payout split still diverts its
+    /// share to the split address. The `tokens_withdrawn` event records the
+    /// recipient as `recipient` and `to` as `destination`.
+    ///
+    /// Not available on streams with a clawback window, whose withdrawals
+    /// are held and later released to the recipient's usual destination.
+    ///
+    /// # Errors
+    /// Same as `withdraw`, plus:
+    /// - `ClawbackWindowOpen` — the stream has a clawback window.
+    pub fn withdraw_to(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        to: Address,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+        if load_stream(&env, stream_id)?.clawback_window > 0 {
+            return Err(StreamError::ClawbackWindowOpen);
+        }
+        Self::execute_withdrawal(env, recipient, stream_id, None, Some(to), None)
+    }
+
+    /// Withdraw exactly `amount` of the stream's claimable tokens, leaving the
+    /// rest claimable.
+    ///
+    /// Behaves like `withdraw` otherwise: fees, splits, the clawback window
+    /// and the withdrawal cap apply to `amount`. Returns `amount`.
+    ///
+    /// # Errors
+    /// Same as `withdraw`, plus:
+    /// - `InvalidAmount`   — `amount` ≤ 0 or exceeds the claimable balance.
+    /// - `WithdrawalCapReached` — `amount` exceeds what remains of the
+    ///   current cap window.
+    pub fn withdraw_amount(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+        Self::execute_withdrawal(env, recipient, stream_id, None, None, Some(amount))
+    }
+
+    /// Shared withdrawal path for `withdraw` and `withdraw_for`; the caller
+    /// has checked authorization. With a `relayer`, the stream's approved
+    /// relayer fee is paid to it out of the withdrawn amount. With a
+    /// `requested` amount, exactly that much is withdrawn instead of
+    /// everything claimable.
+    fn execute_withdrawal(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        relayer: Option<&Address>,
+        destination: Option<Address>,
+        requested: Option<i128>,
+    ) -> Result<i128, StreamError> {
+        let mut stream = load_stream_state_only(&env, stream_id)?;
 
         // Validate recipient authorization
         if stream.recipient != recipient {
-            return Err(StreamError::Unauthorized);
+            return Err(StreamError::NotRecipient);
         }
-        
+
         // Validate stream is active
         Self::validate_stream_active(&stream)?;
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
 
         let now = env.ledger().timestamp();
-        let claimable = Self::calculate_claimable(&stream, now);
+        if now < Self::next_withdrawal_time(&stream) {
+            return Err(StreamError::CooldownActive);
+        }
+        if stream.hold_contested {
+            return Err(StreamError::HoldContested);
+        }
+
+        let accrued = Self::calculate_claimable(&stream, now);
 
-        if claimable <= 0 {
+        if accrued <= 0 {
             return Err(StreamError::InvalidAmount);
         }
 
+        let allowance = Self::withdrawal_allowance(&stream, now);
+        let claimable = match requested {
+            Some(amount) if amount <= 0 || amount > accrued => {
+                return Err(StreamError::InvalidAmount);
+            }
+            Some(amount) => amount,
+            None => accrued.min(allowance),
+        };
+        if claimable <= 0 || claimable > allowance {
+            return Err(StreamError::WithdrawalCapReached);
+        }
+        Self::require_min_claim(&env, &stream, claimable)?;
+
+        let relayer_fee = if relayer.is_some() {
+            stream.relayer_fee
+        } else {
+            0
+        };
+        let withdrawal_fee = Self::withdrawal_fee_on(&env, &stream, claimable);
+        if withdrawal_fee + relayer_fee >= claimable {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::collect_withdrawal_fee(&env, &stream, stream_id, claimable, withdrawal_fee);
+        if let Some(relayer) = relayer {
+            Self::reimburse_relayer(&env, &stream, stream_id, relayer);
+        }
+
         // Use helper function to transfer tokens and update state
-        Self::transfer_and_update_stream(&env, &mut stream, &recipient, claimable, now);
+        let destination = destination.unwrap_or_else(|| Self::payout_destination(&stream));
+        let split_amount = Self::transfer_and_update_stream(
+            &env,
+            &mut stream,
+            &destination,
+            claimable,
+            withdrawal_fee + relayer_fee,
+            now,
+        )?;
+        Self::settle_yield(&env, &mut stream, stream_id);
 
         save_stream(&env, stream_id, &stream);
 
         // Emit withdrawal event
         env.events().publish(
-            (Symbol::new(&env, "tokens_withdrawn"), stream_id),
+            stream_topics(
+                &env,
+                "tokens_withdrawn",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
             TokensWithdrawnEvent {
                 stream_id,
                 recipient,
+                destination,
                 amount: claimable,
                 timestamp: stream.last_update_time,
+                split_address: stream.split_address.clone(),
+                split_amount,
+                withdrawal_fee,
+                total_withdrawn: stream.withdrawn_amount,
+                remaining_deposit: stream.deposited_amount - stream.withdrawn_amount,
+                status: stream.status,
             },
         );
+        Self::record_activity(&env, ActivityKind::Withdrawn, stream_id, claimable);
 
         Ok(claimable)
     }
@@ -377,9 +1462,13 @@ impl StreamContract {
     /// accrued tokens up to the cancellation moment, and any remaining unspent
     /// balance is refunded to the sender.
     ///
+    /// An unclaimed completion bonus is refunded to the sender when cancelling
+    /// before `end_time`, and paid to the recipient once `end_time` has passed.
+    ///
     /// # Errors
     /// - `StreamNotFound`  — no stream exists with `stream_id`.
-    /// - `Unauthorized`    — caller is not the stream's sender.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `Unauthorized`    — the stream is irrevocable.
     /// - `StreamInactive`  — stream is already inactive.
     pub fn cancel_stream(env: Env, sender: Address, stream_id: u64) -> Result<(), StreamError> {
         sender.require_auth();
@@ -390,51 +1479,480 @@ impl StreamContract {
         Self::validate_stream_ownership(&stream, &sender)?;
         Self::validate_stream_active(&stream)?;
 
+        Self::settle_cancellation(&env, stream_id, &mut stream)
+    }
+
+    /// Cancel an active stream effective at `effective_at`. Sender-only.
+    ///
+    /// A time at or before now cancels immediately, exactly like
+    /// `cancel_stream`. A future time keeps the stream accruing until then;
+    /// from that moment accrual stops, and anyone may call
+    /// `execute_scheduled_cancel` to pay out and refund. Calling again before
+    /// the time arrives reschedules.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `Unauthorized`    — the stream is irrevocable.
+    /// - `StreamInactive`  — stream is already inactive.
+    /// - `CancelAlreadyEffective` — a scheduled cancellation has already taken effect.
+    pub fn cancel_stream_at(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        effective_at: u64,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        Self::require_revocable(&env, stream_id)?;
+
         let now = env.ledger().timestamp();
-        let accrued_amount = Self::calculate_claimable(&stream, now);
+        if stream.cancel_at > 0 && stream.cancel_at <= now {
+            return Err(StreamError::CancelAlreadyEffective);
+        }
+        if effective_at <= now {
+            return Self::settle_cancellation(&env, stream_id, &mut stream);
+        }
 
-        let token_client = token::Client::new(&env, &stream.token_address);
-        let contract_address = env.current_contract_address();
+        stream.cancel_at = effective_at;
+        save_stream(&env, stream_id, &stream);
 
-        // Settle recipient with all accrued tokens at cancellation
-        if accrued_amount > 0 {
-            token_client.transfer(&contract_address, &stream.recipient, &accrued_amount);
-            stream.withdrawn_amount = stream.withdrawn_amount.saturating_add(accrued_amount);
+        env.events().publish(
+            stream_topics(
+                &env,
+                "cancel_scheduled",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            CancelScheduledEvent {
+                stream_id,
+                sender,
+                effective_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Carry out a scheduled cancellation once its time has arrived. Callable
+    /// by anyone; settles the stream as `cancel_stream` would have at that time.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `StreamInactive`  — stream is already inactive.
+    /// - `CancelNotDue`    — no cancellation is scheduled, or its time has not come.
+    pub fn execute_scheduled_cancel(env: Env, stream_id: u64) -> Result<(), StreamError> {
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+        if stream.cancel_at == 0 || env.ledger().timestamp() < stream.cancel_at {
+            return Err(StreamError::CancelNotDue);
         }
 
-        // Calculate and refund remaining balance to sender
-        let refunded_amount = stream
-            .deposited_amount
-            .saturating_sub(stream.withdrawn_amount);
+        Self::settle_cancellation(&env, stream_id, &mut stream)
+    }
 
-        if refunded_amount > 0 {
-            token_client.transfer(&contract_address, &sender, &refunded_amount);
+    /// Configure (or clear) an automatic split of every payout. Recipient-only.
+    ///
+    /// `split_bps` of each withdrawal is sent to `split_address` (e.g. a tax
+    /// withholding or savings wallet), the rest to the recipient. Passing
+    /// `split_bps = 0` removes the split.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `InvalidSplit`    — `split_bps` exceeds 10 000.
+    pub fn set_payout_split(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        split_address: Address,
+        split_bps: u32,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+        if split_bps > 10_000 {
+            return Err(StreamError::InvalidSplit);
         }
 
-        // Mark stream as inactive
-        stream.is_active = false;
-        stream.last_update_time = now;
+        if split_bps == 0 {
+            stream.split_address = None;
+        } else {
+            stream.split_address = Some(split_address);
+        }
+        stream.split_bps = split_bps;
+        save_stream(&env, stream_id, &stream);
+        Ok(())
+    }
 
-        let recipient = stream.recipient.clone();
-        let amount_withdrawn = stream.withdrawn_amount;
+    /// Redirect future payouts of a stream to `new_address`. Recipient-only.
+    ///
+    /// Unlike transferring the stream, the recipient stays the authorizing
+    /// identity; only the wallet receiving tokens changes (e.g. after a key
+    /// rotation). Passing the recipient's own address restores the default.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn update_payout_address(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        new_address: Address,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
 
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+
+        stream.payout_address = if new_address == recipient {
+            None
+        } else {
+            Some(new_address.clone())
+        };
         save_stream(&env, stream_id, &stream);
 
-        // Emit cancellation event
         env.events().publish(
-            (Symbol::new(&env, "stream_cancelled"), stream_id),
-            StreamCancelledEvent {
+            stream_topics(
+                &env,
+                "payout_address_updated",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            PayoutAddressUpdatedEvent {
                 stream_id,
-                sender,
                 recipient,
-                amount_withdrawn,
-                refunded_amount,
+                payout_address: new_address,
+            },
+        );
+        Ok(())
+    }
+
+    /// Designate (or clear) a backup beneficiary for a stream. Recipient-only.
+    ///
+    /// If the recipient makes no withdrawal for `inactivity_period` seconds,
+    /// the beneficiary may take over the recipient role via
+    /// `claim_as_beneficiary`. Passing `inactivity_period = 0` clears it.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `PayeeNotAllowed`, `AttestationMissing` — `beneficiary` may not
+    ///   receive from the sender.
+    pub fn set_beneficiary(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+        beneficiary: Address,
+        inactivity_period: u64,
+    ) -> Result<(), StreamError> {
+        recipient.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+
+        stream.beneficiary = if inactivity_period == 0 {
+            None
+        } else {
+            Self::require_payee_allowed(&env, &stream.sender, &beneficiary)?;
+            Self::require_attested(&env, &stream.sender, &beneficiary)?;
+            Some(beneficiary)
+        };
+        stream.beneficiary_inactivity_period = inactivity_period;
+        save_stream(&env, stream_id, &stream);
+        Ok(())
+    }
+
+    /// Take over the recipient role of a stream whose recipient has made no
+    /// withdrawal for the configured inactivity period. Beneficiary-only.
+    ///
+    /// All recipient settings (payout address, split, beneficiary) are reset;
+    /// already-accrued funds become claimable by the new recipient.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `Unauthorized`    — caller is not the designated beneficiary.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `BeneficiaryNotEligible` — the inactivity period has not elapsed.
+    /// - `PayeeNotAllowed`, `AttestationMissing` — the beneficiary may no
+    ///   longer receive from the sender.
+    pub fn claim_as_beneficiary(
+        env: Env,
+        beneficiary: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        beneficiary.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.beneficiary.as_ref() != Some(&beneficiary) {
+            return Err(StreamError::Unauthorized);
+        }
+        Self::validate_stream_active(&stream)?;
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let eligible_at = stream
+            .last_withdrawal_time
+            .saturating_add(stream.beneficiary_inactivity_period);
+        if env.ledger().timestamp() < eligible_at {
+            return Err(StreamError::BeneficiaryNotEligible);
+        }
+        Self::require_payee_allowed(&env, &stream.sender, &beneficiary)?;
+        Self::require_attested(&env, &stream.sender, &beneficiary)?;
+
+        let previous_recipient = stream.recipient.clone();
+        stream.recipient = beneficiary.clone();
+        stream.payout_address = None;
+        stream.split_address = None;
+        stream.split_bps = 0;
+        stream.beneficiary = None;
+        stream.beneficiary_inactivity_period = 0;
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "beneficiary_claimed",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            BeneficiaryClaimedEvent {
+                stream_id,
+                previous_recipient,
+                beneficiary,
+            },
+        );
+        Ok(())
+    }
+
+    /// Materialize a stream's accrued-but-unwithdrawn amount into
+    /// `settled_balance` without transferring anything. Callable by anyone.
+    ///
+    /// Already-earned funds are then unaffected by later changes to the
+    /// stream's parameters. Returns the settled balance.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn checkpoint(env: Env, stream_id: u64) -> Result<i128, StreamError> {
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+
+        let now = env.ledger().timestamp();
+        Self::settle_accrued(&mut stream, now);
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_checkpointed",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            StreamCheckpointedEvent {
+                stream_id,
+                settled_balance: stream.settled_balance,
+                timestamp: now,
             },
         );
 
+        Ok(stream.settled_balance)
+    }
+
+    /// Extend a stream's storage TTL, and the contract instance's, to the
+    /// network maximum. Callable by anyone; keepers use it in response to
+    /// `ttl_low` events.
+    ///
+    /// Returns the ledger the stream now lives until.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    pub fn bump_stream_ttl(env: Env, stream_id: u64) -> Result<u32, StreamError> {
+        let mut stream = load_stream(&env, stream_id)?;
+        extend_stream_ttl(&env, stream_id, &mut stream);
+        let max_ttl = env.storage().max_ttl();
+        env.storage().instance().extend_ttl(max_ttl, max_ttl);
+        Ok(stream.live_until_ledger)
+    }
+
+    // ─── Clawback Holds ───────────────────────────────────────────────────────
+
+    /// Release the holding bucket of a clawback-window stream to the recipient.
+    ///
+    /// Allowed once the window after the latest withdrawal has passed without
+    /// a contest. Works on cancelled and drained streams too.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `InvalidAmount`   — the holding bucket is empty.
+    /// - `HoldContested`   — the sender has contested the bucket.
+    /// - `ClawbackWindowOpen` — the clawback window has not elapsed yet.
+    pub fn finalize_withdrawal(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        if stream.held_amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if stream.hold_contested {
+            return Err(StreamError::HoldContested);
+        }
+        if env.ledger().timestamp() < stream.held_until {
+            return Err(StreamError::ClawbackWindowOpen);
+        }
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let amount = Self::release_hold(&env, &mut stream, stream_id, &recipient);
+        save_stream(&env, stream_id, &stream);
+        Ok(amount)
+    }
+
+    /// Contest the holding bucket while its clawback window is open, freezing
+    /// its release until the protocol admin resolves the dispute.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `InvalidAmount`   — the holding bucket is empty.
+    /// - `HoldContested`   — the bucket is already contested.
+    /// - `ClawbackWindowClosed` — the clawback window has already elapsed.
+    pub fn contest_withdrawal(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        if stream.held_amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if stream.hold_contested {
+            return Err(StreamError::HoldContested);
+        }
+        if env.ledger().timestamp() >= stream.held_until {
+            return Err(StreamError::ClawbackWindowClosed);
+        }
+
+        stream.hold_contested = true;
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "withdrawal_contested",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            WithdrawalContestedEvent {
+                stream_id,
+                sender,
+                held_amount: stream.held_amount,
+            },
+        );
         Ok(())
     }
 
+    /// Resolve a contested holding bucket. Admin-only.
+    ///
+    /// Pays the bucket back to the sender when `refund_sender` is `true`,
+    /// otherwise releases it to the recipient.
+    ///
+    /// # Errors
+    /// - `NotInitialized`  — `initialize` has not been called.
+    /// - `NotAdmin`        — caller is not the current admin.
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `InvalidAmount`   — the bucket is not contested.
+    pub fn resolve_contest(
+        env: Env,
+        admin: Address,
+        stream_id: u64,
+        refund_sender: bool,
+    ) -> Result<i128, StreamError> {
+        Self::require_admin(&env, &admin, "resolve_contest")?;
+        let mut stream = load_stream(&env, stream_id)?;
+        if !stream.hold_contested {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let to = if refund_sender {
+            stream.sender.clone()
+        } else {
+            stream.recipient.clone()
+        };
+        let amount = Self::release_hold(&env, &mut stream, stream_id, &to);
+        save_stream(&env, stream_id, &stream);
+        Ok(amount)
+    }
+
+    /// Claim the completion bonus of a stream that has reached its `end_time`.
+    ///
+    /// Only the recipient may claim, and only once. If the stream is otherwise
+    /// fully withdrawn it becomes inactive.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled (bonus already settled).
+    /// - `InvalidAmount`   — the stream has no unclaimed bonus.
+    /// - `BonusLocked`     — `end_time` has not been reached yet.
+    pub fn claim_bonus(env: Env, recipient: Address, stream_id: u64) -> Result<i128, StreamError> {
+        recipient.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+        if !Self::bonus_outstanding(&stream) {
+            return Err(StreamError::InvalidAmount);
+        }
+        if env.ledger().timestamp() < stream.end_time {
+            return Err(StreamError::BonusLocked);
+        }
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let amount = stream.bonus_amount;
+        Self::settle_bonus(&env, &mut stream, stream_id, &recipient);
+        if stream.withdrawn_amount >= stream.deposited_amount {
+            Self::transition(&mut stream, StreamStatus::Completed)?;
+        }
+        save_stream(&env, stream_id, &stream);
+
+        Ok(amount)
+    }
+
     // ─── Read-only Queries ────────────────────────────────────────────────────
 
     /// Returns the stream record for `stream_id`, or `None` if it does not exist.
@@ -449,42 +1967,322 @@ impl StreamContract {
     ///
     /// Returns `None` if the stream doesn't exist, otherwise returns the claimable amount.
     pub fn get_claimable_amount(env: Env, stream_id: u64) -> Option<i128> {
+        try_load_stream_state(&env, stream_id).map(|state| {
+            if state.status != StreamStatus::Active {
+                return 0;
+            }
+            let now = env.ledger().timestamp();
+            Self::calculate_claimable(&state, now)
+        })
+    }
+
+    /// Returns the funding health of a stream so dashboards can warn senders
+    /// before it runs dry, or `None` if the stream does not exist.
+    ///
+    /// Inactive streams report zero runway and nothing owed.
+    pub fn get_stream_health(env: Env, stream_id: u64) -> Option<StreamHealth> {
+        try_load_stream(&env, stream_id).map(|stream| {
+            let now = env.ledger().timestamp();
+            if !stream.is_active() {
+                return StreamHealth {
+                    runway_seconds: 0,
+                    depletes_at: now,
+                    unstreamed_balance: 0,
+                    owed_amount: 0,
+                    is_underfunded: false,
+                };
+            }
+
+            let owed_amount = Self::calculate_claimable(&stream, now);
+            let unstreamed_balance =
+                stream.deposited_amount - stream.withdrawn_amount - owed_amount;
+            let runway_seconds = Self::seconds_to_stream(&stream, unstreamed_balance);
+            let depletes_at = now.saturating_add(runway_seconds);
+
+            StreamHealth {
+                runway_seconds,
+                depletes_at,
+                unstreamed_balance,
+                owed_amount,
+                is_underfunded: depletes_at < stream.end_time,
+            }
+        })
+    }
+
+    /// Returns how far a stream has progressed, by amount and by time, or
+    /// `None` if the stream does not exist.
+    ///
+    /// Inactive streams report what they streamed before ending and zero
+    /// runway; a gated stream's `total_duration` runs to `u64::MAX`.
+    pub fn get_progress(env: Env, stream_id: u64) -> Option<StreamProgress> {
+        try_load_stream(&env, stream_id).map(|stream| {
+            let now = env.ledger().timestamp();
+            let owed_amount = if stream.is_active() {
+                Self::calculate_claimable(&stream, now)
+            } else {
+                0
+            };
+            let streamed = stream.withdrawn_amount + owed_amount;
+            let unstreamed_balance = if stream.is_active() {
+                stream.deposited_amount - streamed
+            } else {
+                0
+            };
+            let streamed_bps = if stream.deposited_amount > 0 {
+                (streamed.saturating_mul(10_000) / stream.deposited_amount).clamp(0, 10_000) as u32
+            } else {
+                0
+            };
+            let total_duration = stream.end_time.saturating_sub(stream.start_time);
+
+            StreamProgress {
+                streamed_bps,
+                elapsed_seconds: now.saturating_sub(stream.start_time).min(total_duration),
+                total_duration,
+                withdrawn_amount: stream.withdrawn_amount,
+                deposited_amount: stream.deposited_amount,
+                runway_seconds: Self::seconds_to_stream(&stream, unstreamed_balance),
+            }
+        })
+    }
+
+    /// Returns the stream's rate in raw units per second and per day, and in
+    /// whole tokens per day using the decimals recorded at creation.
+    ///
+    /// Returns `None` if the stream doesn't exist.
+    pub fn get_stream_rate(env: Env, stream_id: u64) -> Option<StreamRate> {
+        try_load_stream(&env, stream_id).map(|stream| {
+            let per_day = stream.rate_per_second().saturating_mul(SECONDS_PER_DAY);
+            let unit = 10_i128.saturating_pow(stream.token_decimals);
+            StreamRate {
+                rate_per_second: stream.rate_per_second(),
+                rate_per_day: per_day,
+                decimals: stream.token_decimals,
+                whole_tokens_per_day: per_day / unit,
+                per_day_remainder: per_day % unit,
+            }
+        })
+    }
+
+    /// Returns the metadata cached for `token_address` when it was first
+    /// used, or `None` if no stream has used it yet.
+    ///
+    /// The decimals and symbol are those the token reported at the time.
+    pub fn get_token_info(env: Env, token_address: Address) -> Option<TokenInfo> {
+        try_load_token_info(&env, &token_address)
+    }
+
+    /// Returns up to `limit` IDs of streams `sender` created under `tag`, starting
+    /// at position `start` in creation order.
+    ///
+    /// Returns an empty vector once `start` is past the end of the index.
+    pub fn get_streams_by_tag(
+        env: Env,
+        sender: Address,
+        tag: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let ids = load_tag_index(&env, &sender, &tag);
+        let end = start.saturating_add(limit).min(ids.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        ids.slice(start..end)
+    }
+
+    /// Returns up to `limit` IDs of streams escrowing `token` (plain, basket
+    /// and stable-value streams alike), starting at position `start` in
+    /// creation order.
+    ///
+    /// Returns an empty vector once `start` is past the end of the index.
+    pub fn get_streams_by_token(env: Env, token: Address, start: u32, limit: u32) -> Vec<u64> {
+        let ids = load_token_index(&env, &token);
+        let end = start.saturating_add(limit).min(ids.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        ids.slice(start..end)
+    }
+
+    /// Returns how much a `withdraw` call would pay out right now: the claimable
+    /// amount limited by the stream's withdrawal cap window, if any.
+    ///
+    /// Returns `None` if the stream doesn't exist. Ignores the withdrawal
+    /// cooldown; see `get_next_withdrawal_time`.
+    pub fn get_withdrawable_amount(env: Env, stream_id: u64) -> Option<i128> {
         try_load_stream(&env, stream_id).map(|stream| {
-            if !stream.is_active {
+            if !stream.is_active() {
                 return 0;
             }
             let now = env.ledger().timestamp();
-            Self::calculate_claimable(&stream, now)
+            Self::calculate_claimable(&stream, now).min(Self::withdrawal_allowance(&stream, now))
         })
     }
 
+    /// Returns the earliest ledger timestamp at which the recipient may next
+    /// call `withdraw`, or `None` if the stream does not exist.
+    ///
+    /// For streams without a withdrawal cooldown this is never in the future.
+    pub fn get_next_withdrawal_time(env: Env, stream_id: u64) -> Option<u64> {
+        try_load_stream(&env, stream_id).map(|stream| Self::next_withdrawal_time(&stream))
+    }
+
+    /// Batch form of `get_claimable_amount` for portfolio views.
+    ///
+    /// Returns one amount per ID, in input order; streams that don't exist
+    /// or are inactive report 0.
+    pub fn get_claimable_amounts(env: Env, stream_ids: Vec<u64>) -> Vec<i128> {
+        let now = env.ledger().timestamp();
+        let mut amounts = Vec::new(&env);
+        for stream_id in stream_ids.iter() {
+            let amount = match try_load_stream_state(&env, stream_id) {
+                Some(state) if state.status == StreamStatus::Active => {
+                    Self::calculate_claimable(&state, now)
+                }
+                _ => 0,
+            };
+            amounts.push_back(amount);
+        }
+        amounts
+    }
+
+    /// Returns the protocol fee rate (bps) locked in for `stream_id` at creation,
+    /// or `None` if the stream does not exist.
+    ///
+    /// This is the rate applied to every top-up of the stream.
+    pub fn get_stream_fee_rate(env: Env, stream_id: u64) -> Option<u32> {
+        try_load_stream(&env, stream_id).map(|stream| stream.fee_rate_bps)
+    }
+
     // ─── Internal Helpers ─────────────────────────────────────────────────────
 
-    /// Deducts the protocol fee from `amount`, transfers it to the treasury,
-    /// emits a `fee_collected` event, and returns the net amount.
+    /// Transfers the escrowed bonus to `to`, marks it claimed, and emits a
+    /// `bonus_settled` event. The caller persists the stream.
+    fn settle_bonus(env: &Env, stream: &mut Stream, stream_id: u64, to: &Address) {
+        Self::send_tokens(env, &stream.token_address, to, stream.bonus_amount);
+        stream.bonus_claimed = true;
+
+        env.events().publish(
+            stream_topics(
+                env,
+                "bonus_settled",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            BonusSettledEvent {
+                stream_id,
+                to: to.clone(),
+                amount: stream.bonus_amount,
+                paid_to_recipient: *to == stream.recipient,
+            },
+        );
+    }
+
+    /// Returns the current global fee rate (bps), or 0 if the protocol config
+    /// has not been initialized.
+    fn current_fee_rate(env: &Env) -> u32 {
+        try_load_config(env).map_or(0, |cfg| cfg.fee_rate_bps)
+    }
+
+    /// `amount` minus the protocol fee at `fee_rate_bps`.
+    fn net_of_fee(amount: i128, fee_rate_bps: u32) -> i128 {
+        amount - amount * (fee_rate_bps as i128) / 10_000
+    }
+
+    /// Rejects a deposit of `amount` that, after the fee at `fee_rate_bps`,
+    /// is smaller than `duration` and so would stream nothing per second.
+    ///
+    /// # Errors
+    /// - `RateTooLow` — the net deposit is smaller than `duration`.
+    pub(crate) fn require_streamable(
+        amount: i128,
+        duration: u64,
+        fee_rate_bps: u32,
+    ) -> Result<(), StreamError> {
+        if Self::net_of_fee(amount, fee_rate_bps) / (duration as i128) == 0 {
+            return Err(StreamError::RateTooLow);
+        }
+        Ok(())
+    }
+
+    /// When a stream created now with `options` starts: `options.start_time`,
+    /// or the current ledger time when that is 0.
+    ///
+    /// # Errors
+    /// - `InvalidDuration` — `options.start_time` is in the past, or is set
+    ///   together with a start condition.
+    pub(crate) fn scheduled_start(env: &Env, options: &StreamOptions) -> Result<u64, StreamError> {
+        let now = env.ledger().timestamp();
+        if options.start_time == 0 {
+            return Ok(now);
+        }
+        if options.start_time < now || options.condition != StreamCondition::Unconditional {
+            return Err(StreamError::InvalidDuration);
+        }
+        Ok(options.start_time)
+    }
+
+    /// Deducts the protocol fee at `fee_rate_bps` from `amount`, accrues it for
+    /// the treasury, emits a `fee_collected` event, records it in the stream's
+    /// fee history, and returns the net amount.
+    ///
+    /// When `partner` names a registered partner, its revenue share of the fee
+    /// goes to the partner's payout address instead (see `register_partner`).
+    ///
+    /// Whatever part of the fee `payer`'s sponsored fee credit covers is not
+    /// deducted from `amount` (see `fund_fee_credit`).
     ///
     /// If no protocol config exists or the fee rate is 0, returns `amount` unchanged.
     /// Time complexity: O(1).
-    fn collect_fee(env: &Env, token_address: &Address, amount: i128, stream_id: u64) -> i128 {
+    fn collect_fee(
+        env: &Env,
+        payer: &Address,
+        token_address: &Address,
+        amount: i128,
+        fee_rate_bps: u32,
+        stream_id: u64,
+        partner: Option<&Symbol>,
+    ) -> i128 {
+        if fee_rate_bps == 0 {
+            return amount;
+        }
         match try_load_config(env) {
-            Some(cfg) if cfg.fee_rate_bps > 0 => {
-                let fee = amount * (cfg.fee_rate_bps as i128) / 10_000;
+            Some(cfg) => {
+                let fee = amount - Self::net_of_fee(amount, fee_rate_bps);
                 if fee > 0 {
-                    let token_client = token::Client::new(env, token_address);
-                    token_client.transfer(&env.current_contract_address(), &cfg.treasury, &fee);
-                    env.events().publish(
-                        (Symbol::new(env, "fee_collected"), stream_id),
-                        FeeCollectedEvent {
-                            stream_id,
-                            treasury: cfg.treasury,
+                    append_fee_record(
+                        env,
+                        stream_id,
+                        FeeRecord {
+                            kind: FeeKind::Deposit,
+                            timestamp: env.ledger().timestamp(),
+                            gross_amount: amount,
+                            fee_rate_bps,
                             fee_amount: fee,
-                            token: token_address.clone(),
+                            treasury: cfg.treasury.clone(),
                         },
                     );
+                    let partner_share =
+                        Self::pay_partner_share(env, token_address, fee, stream_id, partner);
+                    let treasury_fee = fee - partner_share;
+                    if treasury_fee > 0 {
+                        Self::accrue_fee(env, token_address, treasury_fee);
+                        env.events().publish(
+                            (Symbol::new(env, "fee_collected"), stream_id),
+                            FeeCollectedEvent {
+                                stream_id,
+                                treasury: cfg.treasury,
+                                fee_amount: treasury_fee,
+                                token: token_address.clone(),
+                            },
+                        );
+                    }
                 }
-                amount - fee
+                amount - fee + Self::draw_fee_credit(env, payer, token_address, fee, stream_id)
             }
-            _ => amount,
+            None => amount,
         }
     }
 }
@@ -0,0 +1,61 @@
+use crate::errors::StreamError;
+use crate::types::{Stream, StreamCondition, StreamStatus};
+use crate::StreamContract;
+
+impl StreamStatus {
+    /// Whether a stream in this status may move to `next`. Only `Active`
+    /// streams move, and only to one of the terminal statuses.
+    pub fn can_transition_to(self, next: StreamStatus) -> bool {
+        matches!(
+            (self, next),
+            (
+                StreamStatus::Active,
+                StreamStatus::Completed | StreamStatus::Cancelled | StreamStatus::Settled
+            )
+        )
+    }
+}
+
+impl Stream {
+    /// `true` until the stream reaches a terminal status.
+    pub fn is_active(&self) -> bool {
+        self.status == StreamStatus::Active
+    }
+
+    /// `true` while the stream waits on its start condition.
+    pub fn is_gated(&self) -> bool {
+        self.condition != StreamCondition::Unconditional
+    }
+
+    /// `true` while the stream's cliff has yet to pass at `now`.
+    pub fn is_locked(&self, now: u64) -> bool {
+        now < self.rate.cliff
+    }
+
+    /// `true` for a timelock, which releases its whole balance at the cliff.
+    pub fn is_timelock(&self) -> bool {
+        self.rate.cliff > 0 && self.rate.cliff >= self.end_time
+    }
+}
+
+impl StreamContract {
+    // ─── Lifecycle ────────────────────────────────────────────────────────────
+
+    /// Moves `stream` to `next`. Every status change goes through here; the
+    /// caller persists the stream.
+    ///
+    /// # Errors
+    /// - `StreamInactive`    — the stream has already reached a terminal status.
+    /// - `InvalidTransition` — `next` is not reachable from the current status.
+    pub(crate) fn transition(stream: &mut Stream, next: StreamStatus) -> Result<(), StreamError> {
+        if !stream.status.can_transition_to(next) {
+            return Err(if stream.is_active() {
+                StreamError::InvalidTransition
+            } else {
+                StreamError::StreamInactive
+            });
+        }
+        stream.status = next;
+        Ok(())
+    }
+}
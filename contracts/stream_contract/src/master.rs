@@ -0,0 +1,452 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{
+    stream_topics, ChildAddedEvent, ChildRemovedEvent, ChildWithdrawnEvent, MasterCancelledEvent,
+    MasterCreatedEvent, MasterFundedEvent,
+};
+use crate::storage::{
+    append_to_token_index, load_child, load_master, next_stream_id, save_child, save_master,
+    try_load_child, try_load_master,
+};
+use crate::types::{ChildStream, MasterStream};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Maximum number of active children a master stream may back.
+pub(crate) const MAX_MASTER_CHILDREN: u32 = 20;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Master Streams ───────────────────────────────────────────────────────
+
+    /// Create a master stream: one deposit of `amount` (less the protocol
+    /// fee) from which child streams added with `add_child_stream` draw
+    /// their principal.
+    ///
+    /// While the balance covers every child's accrual, each child accrues at
+    /// its own rate. When it runs short, whatever is left is shared pro rata
+    /// by rate, and accrual resumes in full once `fund_master_stream` tops
+    /// the balance up.
+    ///
+    /// Returns the master ID, drawn from the same counter as plain streams.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`    — `amount` ≤ 0.
+    /// - `SenderNotAllowed` — permissioned mode is on and `sender` is not allowlisted.
+    /// - `ApprovalRequired` — `amount` needs approvals under `sender`'s policy.
+    /// - `InvalidTokenAddress` — `token_address` is not a token contract.
+    /// - `TokenFrozen`      — the token is frozen.
+    /// - `DepositCapExceeded` — the deposit would exceed the token's cap.
+    /// - `BudgetExceeded`   — `amount` exceeds `sender`'s spending limit.
+    pub fn create_master_stream(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::require_sender_allowed(&env, &sender)?;
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+        Self::require_within_deposit_cap(&env, &token_address, amount)?;
+        Self::record_spend(&env, &sender, &token_address, amount)?;
+
+        let master_id = next_stream_id(&env);
+        let fee_rate_bps = Self::current_fee_rate(&env);
+        append_to_token_index(&env, &token_address, master_id);
+        Self::receive_tokens(&env, &token_address, &sender, amount);
+        let net_amount = Self::collect_fee(
+            &env,
+            &sender,
+            &token_address,
+            amount,
+            fee_rate_bps,
+            master_id,
+            None,
+        );
+
+        save_master(
+            &env,
+            master_id,
+            &MasterStream {
+                sender: sender.clone(),
+                token_address: token_address.clone(),
+                balance: net_amount,
+                total_rate: 0,
+                children: Vec::new(&env),
+                last_update_time: env.ledger().timestamp(),
+                is_active: true,
+                fee_rate_bps,
+            },
+        );
+
+        env.events().publish(
+            (Symbol::new(&env, "master_created"), master_id),
+            MasterCreatedEvent {
+                master_id,
+                sender,
+                token_address,
+                amount: net_amount,
+            },
+        );
+        Ok(master_id)
+    }
+
+    /// Add `amount` (less the master's locked-in fee) to a master stream's
+    /// balance. Sender-only.
+    ///
+    /// Returns the new balance.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `StreamNotFound`  — no master stream exists with `master_id`.
+    /// - `NotSender`       — caller is not the master's sender.
+    /// - `StreamInactive`  — the master has been cancelled.
+    /// - `TokenFrozen`, `DepositCapExceeded`, `BudgetExceeded` — as for
+    ///   `create_master_stream`.
+    pub fn fund_master_stream(
+        env: Env,
+        sender: Address,
+        master_id: u64,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut master = load_master(&env, master_id)?;
+        Self::validate_master_owner(&master, &sender)?;
+        Self::require_token_not_frozen(&env, &master.token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::require_within_deposit_cap(&env, &master.token_address, amount)?;
+        Self::record_spend(&env, &sender, &master.token_address, amount)?;
+
+        // Accrual up to now was capped by the old balance.
+        Self::settle_master(&env, &mut master);
+        Self::receive_tokens(&env, &master.token_address, &sender, amount);
+        let net_amount = Self::collect_fee(
+            &env,
+            &sender,
+            &master.token_address,
+            amount,
+            master.fee_rate_bps,
+            master_id,
+            None,
+        );
+        master.balance += net_amount;
+        save_master(&env, master_id, &master);
+
+        env.events().publish(
+            (Symbol::new(&env, "master_funded"), master_id),
+            MasterFundedEvent {
+                master_id,
+                amount: net_amount,
+                balance: master.balance,
+            },
+        );
+        Ok(master.balance)
+    }
+
+    /// Start a child stream paying `recipient` `rate_per_second` out of a
+    /// master stream's balance. Sender-only.
+    ///
+    /// Returns the child ID, drawn from the same counter as plain streams.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `rate_per_second` ≤ 0, or the master already
+    ///   backs `MAX_MASTER_CHILDREN` children.
+    /// - `StreamNotFound`  — no master stream exists with `master_id`.
+    /// - `NotSender`       — caller is not the master's sender.
+    /// - `StreamInactive`  — the master has been cancelled.
+    /// - `PayeeNotAllowed` — `sender`'s payee allowlist is on and omits `recipient`.
+    /// - `AttestationMissing` — a registry is configured and either party lacks an attestation.
+    pub fn add_child_stream(
+        env: Env,
+        sender: Address,
+        master_id: u64,
+        recipient: Address,
+        rate_per_second: i128,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+        if rate_per_second <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut master = load_master(&env, master_id)?;
+        Self::validate_master_owner(&master, &sender)?;
+        if master.children.len() >= MAX_MASTER_CHILDREN {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::require_payee_allowed(&env, &sender, &recipient)?;
+        Self::require_attested(&env, &sender, &recipient)?;
+
+        Self::settle_master(&env, &mut master);
+        let child_id = next_stream_id(&env);
+        save_child(
+            &env,
+            child_id,
+            &ChildStream {
+                master_id,
+                recipient: recipient.clone(),
+                rate_per_second,
+                accrued: 0,
+                withdrawn_amount: 0,
+                is_active: true,
+            },
+        );
+        master.children.push_back(child_id);
+        master.total_rate += rate_per_second;
+        save_master(&env, master_id, &master);
+
+        env.events().publish(
+            stream_topics(&env, "child_added", child_id, &sender, &recipient),
+            ChildAddedEvent {
+                child_id,
+                master_id,
+                rate_per_second,
+            },
+        );
+        Ok(child_id)
+    }
+
+    /// Withdraw everything a child stream has accrued from its master.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no child stream exists with `child_id`.
+    /// - `NotRecipient`    — caller is not the child's recipient.
+    /// - `StreamInactive`  — the child was removed or its master cancelled.
+    /// - `TokenFrozen`     — the token is frozen.
+    /// - `InvalidAmount`   — nothing has accrued.
+    pub fn withdraw_child_stream(
+        env: Env,
+        recipient: Address,
+        child_id: u64,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+        let child = load_child(&env, child_id)?;
+        if child.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        if !child.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+        let mut master = load_master(&env, child.master_id)?;
+        Self::require_token_not_frozen(&env, &master.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        Self::settle_master(&env, &mut master);
+        save_master(&env, child.master_id, &master);
+        let mut child = load_child(&env, child_id)?;
+        let amount = child.accrued;
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::pay_child(&env, &master, &mut child, amount);
+        save_child(&env, child_id, &child);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "child_withdrawn",
+                child_id,
+                &master.sender,
+                &recipient,
+            ),
+            ChildWithdrawnEvent { child_id, amount },
+        );
+        Ok(amount)
+    }
+
+    /// Stop a child stream, paying its recipient what it has accrued.
+    /// Sender-only. Its rate no longer draws on the master.
+    ///
+    /// Returns the amount paid.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no child stream exists with `child_id`.
+    /// - `NotSender`       — caller is not the master's sender.
+    /// - `StreamInactive`  — the child was already removed or its master cancelled.
+    pub fn remove_child_stream(
+        env: Env,
+        sender: Address,
+        child_id: u64,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        let child = load_child(&env, child_id)?;
+        if !child.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+        let mut master = load_master(&env, child.master_id)?;
+        Self::validate_master_owner(&master, &sender)?;
+
+        Self::settle_master(&env, &mut master);
+        let mut child = load_child(&env, child_id)?;
+        let paid_amount = child.accrued;
+        if paid_amount > 0 {
+            Self::pay_child(&env, &master, &mut child, paid_amount);
+        }
+        child.is_active = false;
+        save_child(&env, child_id, &child);
+
+        if let Some(index) = master.children.first_index_of(child_id) {
+            master.children.remove(index);
+        }
+        master.total_rate -= child.rate_per_second;
+        save_master(&env, child.master_id, &master);
+
+        env.events().publish(
+            stream_topics(&env, "child_removed", child_id, &sender, &child.recipient),
+            ChildRemovedEvent {
+                child_id,
+                paid_amount,
+            },
+        );
+        Ok(paid_amount)
+    }
+
+    /// Cancel a master stream and all its children. Sender-only.
+    ///
+    /// Each child's recipient is paid what it has accrued and the sender is
+    /// refunded the remaining balance. Returns the refund.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no master stream exists with `master_id`.
+    /// - `NotSender`       — caller is not the master's sender.
+    /// - `StreamInactive`  — the master has already been cancelled.
+    pub fn cancel_master_stream(
+        env: Env,
+        sender: Address,
+        master_id: u64,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        let mut master = load_master(&env, master_id)?;
+        Self::validate_master_owner(&master, &sender)?;
+
+        Self::settle_master(&env, &mut master);
+        let mut paid_amount = 0;
+        for child_id in master.children.iter() {
+            let mut child = load_child(&env, child_id)?;
+            let accrued = child.accrued;
+            if accrued > 0 {
+                Self::pay_child(&env, &master, &mut child, accrued);
+                paid_amount += accrued;
+            }
+            child.is_active = false;
+            save_child(&env, child_id, &child);
+        }
+
+        let refunded_amount = master.balance;
+        if refunded_amount > 0 {
+            Self::send_tokens(&env, &master.token_address, &sender, refunded_amount);
+        }
+        master.balance = 0;
+        master.total_rate = 0;
+        master.children = Vec::new(&env);
+        master.is_active = false;
+        save_master(&env, master_id, &master);
+
+        env.events().publish(
+            (Symbol::new(&env, "master_cancelled"), master_id),
+            MasterCancelledEvent {
+                master_id,
+                paid_amount,
+                refunded_amount,
+            },
+        );
+        Ok(refunded_amount)
+    }
+
+    /// Returns the master stream record for `master_id`, or `None`.
+    pub fn get_master_stream(env: Env, master_id: u64) -> Option<MasterStream> {
+        try_load_master(&env, master_id)
+    }
+
+    /// Returns the child stream record for `child_id`, or `None`. Its
+    /// `accrued` is as of the master's last settlement; see
+    /// `get_child_claimable` for the live figure.
+    pub fn get_child_stream(env: Env, child_id: u64) -> Option<ChildStream> {
+        try_load_child(&env, child_id)
+    }
+
+    /// Returns what the child stream's recipient could withdraw right now,
+    /// or `None` if the child does not exist.
+    pub fn get_child_claimable(env: Env, child_id: u64) -> Option<i128> {
+        let child = try_load_child(&env, child_id)?;
+        if !child.is_active {
+            return Some(0);
+        }
+        let master = try_load_master(&env, child.master_id)?;
+        let shares = Self::master_accrual(&env, &master);
+        let index = master.children.first_index_of(child_id)?;
+        Some(child.accrued + shares.get(index).unwrap_or(0))
+    }
+}
+
+impl StreamContract {
+    /// Checks `sender` owns the active master stream `master`.
+    fn validate_master_owner(master: &MasterStream, sender: &Address) -> Result<(), StreamError> {
+        if master.sender != *sender {
+            return Err(StreamError::NotSender);
+        }
+        if !master.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+        Ok(())
+    }
+
+    /// Each active child's accrual since the master's last settlement, in
+    /// `children` order: its full rate while the balance covers everyone,
+    /// otherwise its pro-rata share of the balance.
+    fn master_accrual(env: &Env, master: &MasterStream) -> Vec<i128> {
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(master.last_update_time);
+        let demand = (elapsed as i128)
+            .checked_mul(master.total_rate)
+            .unwrap_or(i128::MAX);
+        let underfunded = demand > master.balance;
+
+        let mut shares = Vec::new(env);
+        for child_id in master.children.iter() {
+            let rate = try_load_child(env, child_id).map_or(0, |child| child.rate_per_second);
+            let share = if underfunded {
+                master.balance * rate / master.total_rate
+            } else {
+                rate * elapsed as i128
+            };
+            shares.push_back(share);
+        }
+        shares
+    }
+
+    /// Moves each child's accrual since the last settlement out of the
+    /// master's balance and into the child's record. The caller persists
+    /// the master.
+    fn settle_master(env: &Env, master: &mut MasterStream) {
+        let shares = Self::master_accrual(env, master);
+        for (child_id, share) in master.children.iter().zip(shares.iter()) {
+            if share > 0 {
+                if let Some(mut child) = try_load_child(env, child_id) {
+                    child.accrued += share;
+                    master.balance -= share;
+                    save_child(env, child_id, &child);
+                }
+            }
+        }
+        master.last_update_time = env.ledger().timestamp();
+    }
+
+    /// Pays `amount` of `child`'s accrual to its recipient. The caller
+    /// persists the child.
+    fn pay_child(env: &Env, master: &MasterStream, child: &mut ChildStream, amount: i128) {
+        Self::send_tokens(env, &master.token_address, &child.recipient, amount);
+        child.accrued -= amount;
+        child.withdrawn_amount += amount;
+        Self::record_volume(env, &master.sender, &master.token_address, amount);
+    }
+}
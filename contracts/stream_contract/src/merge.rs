@@ -0,0 +1,150 @@
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, StreamsMergedEvent};
+use crate::storage::{is_irrevocable, load_stream, save_stream};
+use crate::types::{ExactRate, Stream, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Merging ──────────────────────────────────────────────────────────────
+
+    /// Fold several active streams between the same sender, recipient and
+    /// token into the first of `stream_ids`, so the recipient claims from one
+    /// stream instead of many. Sender-only.
+    ///
+    /// Everything accrued so far is settled first and stays claimable. The
+    /// surviving stream takes over the others' remaining balances and
+    /// streams the pooled balance evenly, at an exact rational rate, until
+    /// the latest end time among the streams with funds left to stream; its
+    /// own options are kept, including any deposit cap, which the
+    /// pooled deposit must stay within. The other streams are left completed
+    /// and empty. No tokens move.
+    ///
+    /// Returns the surviving stream's ID.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`     — fewer than two streams, or a repeated ID.
+    /// - `StreamNotFound`    — an ID does not exist.
+    /// - `NotSender`         — caller is not the sender of every stream.
+    /// - `StreamInactive`    — a stream is not active.
+    /// - `DepositCapExceeded` — the pooled deposit exceeds the surviving
+    ///   stream's deposit cap.
+    /// - `InvalidTransition` — the streams differ in recipient or token, one
+    ///   has a hold, scheduled cancellation, pending condition or cliff, or a
+    ///   merged-away stream has an outstanding bonus or pending yield, or is
+    ///   irrevocable while the survivor is not, or the streams are unclaimed
+    ///   links.
+    pub fn merge_streams(
+        env: Env,
+        sender: Address,
+        stream_ids: Vec<u64>,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+        if stream_ids.len() < 2 {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let now = env.ledger().timestamp();
+        let survivor_id = stream_ids.get_unchecked(0);
+        let mut survivor = load_stream(&env, survivor_id)?;
+        Self::validate_mergeable(&survivor, &survivor, &sender, now)?;
+        if Self::is_unclaimed_link(&env, &survivor) {
+            return Err(StreamError::InvalidTransition);
+        }
+        Self::settle_accrued(&mut survivor, now);
+        let mut end_time = if Self::unstreamed(&survivor) > 0 {
+            survivor.end_time
+        } else {
+            now
+        };
+
+        let mut merged_ids = Vec::new(&env);
+        for stream_id in stream_ids.iter().skip(1) {
+            if stream_id == survivor_id || merged_ids.contains(stream_id) {
+                return Err(StreamError::InvalidAmount);
+            }
+            let mut stream = load_stream(&env, stream_id)?;
+            Self::validate_mergeable(&stream, &survivor, &sender, now)?;
+            if Self::bonus_outstanding(&stream)
+                || stream.pending_yield != 0
+                || (is_irrevocable(&env, stream_id) && !is_irrevocable(&env, survivor_id))
+            {
+                return Err(StreamError::InvalidTransition);
+            }
+
+            Self::settle_accrued(&mut stream, now);
+            if Self::unstreamed(&stream) > 0 {
+                end_time = end_time.max(stream.end_time);
+            }
+            survivor.deposited_amount += stream.deposited_amount - stream.withdrawn_amount;
+            survivor.settled_balance += stream.settled_balance;
+
+            Self::transition(&mut stream, StreamStatus::Completed)?;
+            stream.deposited_amount = stream.withdrawn_amount;
+            stream.settled_balance = 0;
+            stream.end_time = now;
+            save_stream(&env, stream_id, &stream);
+            merged_ids.push_back(stream_id);
+        }
+        Self::require_within_stream_cap(&env, survivor_id, &survivor)?;
+
+        let unstreamed = Self::unstreamed(&survivor);
+        if unstreamed > 0 && end_time > now {
+            survivor.rate = ExactRate {
+                numerator: unstreamed,
+                denominator: end_time - now,
+                anchor: now,
+                cliff: 0,
+            };
+        }
+        survivor.end_time = now.saturating_add(Self::seconds_to_stream(&survivor, unstreamed));
+        save_stream(&env, survivor_id, &survivor);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "streams_merged",
+                survivor_id,
+                &survivor.sender,
+                &survivor.recipient,
+            ),
+            StreamsMergedEvent {
+                stream_id: survivor_id,
+                merged_ids,
+                rate_per_second: survivor.rate_per_second(),
+                end_time: survivor.end_time,
+            },
+        );
+        Ok(survivor_id)
+    }
+}
+
+impl StreamContract {
+    /// Checks `stream` may be merged into `survivor` by `sender`.
+    fn validate_mergeable(
+        stream: &Stream,
+        survivor: &Stream,
+        sender: &Address,
+        now: u64,
+    ) -> Result<(), StreamError> {
+        Self::validate_stream_ownership(stream, sender)?;
+        Self::validate_stream_active(stream)?;
+        if stream.recipient != survivor.recipient
+            || stream.token_address != survivor.token_address
+            || stream.held_amount > 0
+            || stream.cancel_at > 0
+            || stream.is_gated()
+            || stream.is_locked(now)
+        {
+            return Err(StreamError::InvalidTransition);
+        }
+        Ok(())
+    }
+
+    /// Balance of a settled stream that has yet to accrue.
+    pub(crate) fn unstreamed(stream: &Stream) -> i128 {
+        stream.deposited_amount - stream.withdrawn_amount - stream.settled_balance
+    }
+}
@@ -0,0 +1,162 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, TryFromVal, Vec};
+
+use crate::errors::StreamError;
+use crate::events::StreamsMigratedEvent;
+use crate::storage::{
+    extend_stream_ttl, load_accounted_balance, save_accounted_balance, save_stream_terms,
+    try_load_stream_fields,
+};
+use crate::types::{
+    ExactRate, Stream, StreamCategory, StreamCondition, StreamStatus, StreamV1, YieldPolicy,
+};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Number of fields in the original `Stream` layout.
+const STREAM_V1_FIELDS: u32 = 9;
+
+/// Most stream IDs `migrate_streams` examines in one call, keeping each batch
+/// well inside the per-transaction read/write limits.
+pub(crate) const MAX_MIGRATION_BATCH: u32 = 50;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Migration ────────────────────────────────────────────────────────────
+
+    /// Rewrite stream records stored in an older layout into the current
+    /// `Stream` layout. Admin-only.
+    ///
+    /// Examines IDs `start_id .. start_id + limit` (`limit` is capped at
+    /// `MAX_MIGRATION_BATCH`); call repeatedly with advancing `start_id`
+    /// until every issued ID is covered. Missing IDs and records already
+    /// in the current layout are skipped, so re-running a batch is harmless.
+    /// Migrated records also get their TTL extended, and the unwithdrawn
+    /// balance of still-active ones is added to the token's accounted balance.
+    ///
+    /// Returns how many records were rewritten.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn migrate_streams(
+        env: Env,
+        admin: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> Result<u32, StreamError> {
+        Self::require_admin(&env, &admin, "migrate_streams")?;
+
+        let end_id = start_id.saturating_add(limit.min(MAX_MIGRATION_BATCH) as u64);
+        let is_active_key = Symbol::new(&env, "is_active");
+        let mut migrated = 0u32;
+        for stream_id in start_id..end_id {
+            let Some(fields) = try_load_stream_fields(&env, stream_id) else {
+                continue;
+            };
+            // Decoding a record into the wrong layout traps rather than
+            // failing, so recognize the old one by its shape first.
+            if fields.len() != STREAM_V1_FIELDS || !fields.contains_key(is_active_key.clone()) {
+                continue;
+            }
+            let Ok(legacy) = StreamV1::try_from_val(&env, fields.as_val()) else {
+                continue;
+            };
+
+            let mut stream = Self::upgrade_stream_v1(&env, legacy);
+            if stream.is_active() {
+                // The old layout kept no custody ledger; count the balance
+                // this stream still holds so payouts balance out.
+                let held = stream.deposited_amount - stream.withdrawn_amount;
+                let balance = load_accounted_balance(&env, &stream.token_address);
+                save_accounted_balance(&env, &stream.token_address, balance + held);
+            }
+            save_stream_terms(&env, stream_id, &stream);
+            extend_stream_ttl(&env, stream_id, &mut stream);
+            migrated += 1;
+        }
+
+        env.events().publish(
+            (Symbol::new(&env, "streams_migrated"),),
+            StreamsMigratedEvent {
+                start_id,
+                limit,
+                migrated,
+            },
+        );
+        Ok(migrated)
+    }
+}
+
+impl StreamContract {
+    /// Maps an original-layout record onto the current layout.
+    ///
+    /// The old layout accrued `rate * elapsed` since `last_update_time`,
+    /// capped at the unwithdrawn deposit; with no settled balance the current
+    /// formula gives the same amounts. Every feature added since starts off,
+    /// and `end_time` is where the remaining deposit runs out. The protocol
+    /// and withdrawal fee rates are snapshotted from the current config, as
+    /// for a newly created stream.
+    fn upgrade_stream_v1(env: &Env, legacy: StreamV1) -> Stream {
+        let remaining = legacy.deposited_amount - legacy.withdrawn_amount;
+        let status = if legacy.is_active {
+            StreamStatus::Active
+        } else if remaining <= 0 {
+            StreamStatus::Completed
+        } else {
+            StreamStatus::Cancelled
+        };
+        let runway = if legacy.is_active && legacy.rate_per_second > 0 && remaining > 0 {
+            ((remaining + legacy.rate_per_second - 1) / legacy.rate_per_second) as u64
+        } else {
+            0
+        };
+        let token_decimals = Self::validate_token_contract(env, &legacy.token_address).unwrap_or(0);
+
+        Stream {
+            sender: legacy.sender,
+            recipient: legacy.recipient,
+            token_address: legacy.token_address,
+            rate: ExactRate {
+                numerator: legacy.rate_per_second,
+                denominator: 1,
+                anchor: legacy.start_time,
+                cliff: 0,
+            },
+            deposited_amount: legacy.deposited_amount,
+            withdrawn_amount: legacy.withdrawn_amount,
+            start_time: legacy.start_time,
+            last_update_time: legacy.last_update_time,
+            status,
+            fee_rate_bps: Self::category_fee_rate(env, &StreamCategory::General),
+            withdrawal_cooldown: 0,
+            last_withdrawal_time: legacy.start_time,
+            settled_balance: 0,
+            withdrawal_cap: 0,
+            withdrawal_cap_period: 0,
+            cap_window_start: legacy.start_time,
+            cap_window_withdrawn: 0,
+            tags: Vec::new(env),
+            end_time: legacy.last_update_time.saturating_add(runway),
+            bonus_amount: 0,
+            bonus_claimed: false,
+            clawback_window: 0,
+            held_amount: 0,
+            held_until: 0,
+            hold_contested: false,
+            split_address: None,
+            split_bps: 0,
+            payout_address: None,
+            beneficiary: None,
+            beneficiary_inactivity_period: 0,
+            cancel_at: 0,
+            category: StreamCategory::General,
+            partner: None,
+            live_until_ledger: 0,
+            withdrawal_fee_bps: Self::current_withdrawal_fee_rate(env),
+            relayer_fee: 0,
+            token_decimals,
+            yield_policy: YieldPolicy::Sender,
+            pending_yield: 0,
+            condition: StreamCondition::Unconditional,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{contractimpl, Address, Bytes, Env};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, NoteUpdatedEvent};
+use crate::storage::{load_stream, save_note, try_load_note};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Largest encrypted note a stream may carry, in bytes.
+pub(crate) const MAX_NOTE_BYTES: u32 = 1_024;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Stream Notes ─────────────────────────────────────────────────────────
+
+    /// Attach an encrypted note to a stream, replacing any previous one.
+    /// Either the sender or the recipient may set it; an empty `note`
+    /// clears it.
+    ///
+    /// The contract stores the bytes as-is: parties encrypt off-chain (e.g.
+    /// to each other's keys) so payroll references or invoice details never
+    /// appear in plaintext.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `Unauthorized`   — caller is neither the sender nor the recipient.
+    /// - `NoteTooLarge`   — `note` exceeds `MAX_NOTE_BYTES`.
+    pub fn set_stream_note(
+        env: Env,
+        caller: Address,
+        stream_id: u64,
+        note: Bytes,
+    ) -> Result<(), StreamError> {
+        caller.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if caller != stream.sender && caller != stream.recipient {
+            return Err(StreamError::Unauthorized);
+        }
+        if note.len() > MAX_NOTE_BYTES {
+            return Err(StreamError::NoteTooLarge);
+        }
+        let length = note.len();
+        save_note(
+            &env,
+            stream_id,
+            if note.is_empty() { None } else { Some(&note) },
+        );
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "note_updated",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            NoteUpdatedEvent {
+                stream_id,
+                updated_by: caller,
+                length,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the stream's encrypted note, or `None` if it has none.
+    pub fn get_stream_note(env: Env, stream_id: u64) -> Option<Bytes> {
+        try_load_note(&env, stream_id)
+    }
+}
@@ -0,0 +1,351 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{
+    ManagerBudgetReplenishedEvent, ManagerBudgetSetEvent, OrgManagerUpdatedEvent,
+    OrgRegisteredEvent,
+};
+use crate::storage::{
+    load_stream, save_manager_budget, save_org_manager, save_org_owner, save_stream,
+    try_load_manager_budget, try_load_org_manager, try_load_org_owner,
+};
+use crate::types::{ManagerPermissions, SpendingLimit, StreamOptions};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Organizations ────────────────────────────────────────────────────────
+
+    /// Register `org` as an organization run by `owner`, or hand it to a new
+    /// owner. Requires `org`'s authorization.
+    ///
+    /// `org` is the address that funds the organization's streams. Its
+    /// managers never need its key: streams they create or top up are paid
+    /// through the token allowance `org` grants this contract.
+    pub fn register_org(env: Env, org: Address, owner: Address) {
+        org.require_auth();
+        save_org_owner(&env, &org, &owner);
+
+        env.events().publish(
+            (Symbol::new(&env, "org_registered"), org.clone()),
+            OrgRegisteredEvent { org, owner },
+        );
+    }
+
+    /// Add, update or (with `None`) remove one of `org`'s managers.
+    /// Owner-only.
+    ///
+    /// # Errors
+    /// - `OrgNotFound`  — `org` is not registered.
+    /// - `Unauthorized` — caller is not the org's owner.
+    pub fn set_org_manager(
+        env: Env,
+        owner: Address,
+        org: Address,
+        manager: Address,
+        permissions: Option<ManagerPermissions>,
+    ) -> Result<(), StreamError> {
+        Self::require_org_owner(&env, &owner, &org)?;
+        save_org_manager(&env, &org, &manager, permissions.as_ref());
+
+        let permissions = permissions.unwrap_or(ManagerPermissions {
+            can_create: false,
+            can_top_up: false,
+            can_cancel: false,
+        });
+        env.events().publish(
+            (Symbol::new(&env, "org_manager_updated"), org.clone()),
+            OrgManagerUpdatedEvent {
+                org,
+                manager,
+                can_create: permissions.can_create,
+                can_top_up: permissions.can_top_up,
+                can_cancel: permissions.can_cancel,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the owner of `org`, or `None` if it is not registered.
+    pub fn get_org_owner(env: Env, org: Address) -> Option<Address> {
+        try_load_org_owner(&env, &org)
+    }
+
+    /// Returns `manager`'s permissions within `org`, or `None` if it is not
+    /// one of its managers.
+    pub fn get_org_manager(env: Env, org: Address, manager: Address) -> Option<ManagerPermissions> {
+        try_load_org_manager(&env, &org, &manager)
+    }
+
+    /// Open a stream funded by `org` on a manager's authority.
+    ///
+    /// Otherwise identical to `create_stream_with_options` with `org` as
+    /// sender; `org` must have approved this contract to spend `amount`
+    /// (plus any bonus) of the token.
+    ///
+    /// # Errors
+    /// - `ManagerNotAuthorized` — `manager` may not create `org`'s streams.
+    /// - `BudgetExceeded`  — `amount` exceeds the manager's remaining budget.
+    /// - Same as `create_stream_with_options` otherwise.
+    pub fn manager_create_stream(
+        env: Env,
+        manager: Address,
+        org: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        options: StreamOptions,
+    ) -> Result<u64, StreamError> {
+        Self::require_manager(&env, &manager, &org, |p| p.can_create)?;
+        Self::require_no_approval_needed(&env, &org, amount)?;
+        Self::draw_manager_budget(&env, &org, &manager, &token_address, amount)?;
+        Self::open_stream(
+            env,
+            None,
+            org,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+            Funding::Allowance,
+        )
+    }
+
+    /// Top up one of `org`'s streams on a manager's authority, paid through
+    /// `org`'s token allowance.
+    ///
+    /// Otherwise identical to `top_up_stream` with `org` as sender.
+    ///
+    /// # Errors
+    /// - `ManagerNotAuthorized` — `manager` may not top up `org`'s streams.
+    /// - `BudgetExceeded`  — `amount` exceeds the manager's remaining budget.
+    /// - Same as `top_up_stream` otherwise.
+    pub fn manager_top_up_stream(
+        env: Env,
+        manager: Address,
+        org: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        Self::require_manager(&env, &manager, &org, |p| p.can_top_up)?;
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::draw_manager_budget(&env, &org, &manager, &stream.token_address, amount)?;
+        let net_amount = Self::deposit_top_up(
+            &env,
+            &org,
+            stream_id,
+            &mut stream,
+            amount,
+            Funding::Allowance,
+        )?;
+        let extension = Self::seconds_to_stream(&stream, net_amount);
+        stream.end_time = stream.end_time.saturating_add(extension);
+
+        save_stream(&env, stream_id, &stream);
+        Self::emit_top_up(&env, org, stream_id, net_amount, &stream);
+        Ok(())
+    }
+
+    /// Cancel one of `org`'s streams on a manager's authority. The refund
+    /// goes to `org`.
+    ///
+    /// Otherwise identical to `cancel_stream` with `org` as sender.
+    ///
+    /// # Errors
+    /// - `ManagerNotAuthorized` — `manager` may not cancel `org`'s streams.
+    /// - Same as `cancel_stream` otherwise.
+    pub fn manager_cancel_stream(
+        env: Env,
+        manager: Address,
+        org: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        Self::require_manager(&env, &manager, &org, |p| p.can_cancel)?;
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &org)?;
+        Self::validate_stream_active(&stream)?;
+        Self::settle_cancellation(&env, stream_id, &mut stream)
+    }
+
+    // ─── Manager Budgets ──────────────────────────────────────────────────────
+
+    /// Give `manager` an envelope of `limit` of `token` per epoch of
+    /// `epoch_length` seconds, drawn down by the gross amounts it commits
+    /// through `manager_create_stream` and `manager_top_up_stream`.
+    /// Owner-only. A `limit` of 0 removes the envelope; managers without
+    /// one for a token are not capped in it.
+    ///
+    /// Epochs are aligned to the moment the budget is set; setting it again
+    /// starts a fresh epoch with nothing spent.
+    ///
+    /// # Errors
+    /// - `OrgNotFound`     — `org` is not registered.
+    /// - `Unauthorized`    — caller is not the org's owner.
+    /// - `InvalidAmount`   — `limit` is negative.
+    /// - `InvalidDuration` — a budget is set with a zero `epoch_length`.
+    pub fn set_manager_budget(
+        env: Env,
+        owner: Address,
+        org: Address,
+        manager: Address,
+        token_address: Address,
+        limit: i128,
+        epoch_length: u64,
+    ) -> Result<(), StreamError> {
+        Self::require_org_owner(&env, &owner, &org)?;
+        if limit < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if limit > 0 && epoch_length == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+
+        let budget = (limit > 0).then(|| SpendingLimit {
+            limit,
+            epoch_length,
+            epoch_start: env.ledger().timestamp(),
+            spent: 0,
+        });
+        save_manager_budget(&env, &org, &manager, &token_address, budget.as_ref());
+
+        env.events().publish(
+            (Symbol::new(&env, "manager_budget_set"), org.clone()),
+            ManagerBudgetSetEvent {
+                org,
+                manager,
+                token_address,
+                limit,
+                epoch_length,
+            },
+        );
+        Ok(())
+    }
+
+    /// Add `amount` of headroom to `manager`'s budget for the current epoch.
+    /// Owner-only. The extra lapses when the epoch rolls over.
+    ///
+    /// Returns the budget left in the current epoch.
+    ///
+    /// # Errors
+    /// - `OrgNotFound`     — `org` is not registered.
+    /// - `Unauthorized`    — caller is not the org's owner.
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `RecordNotFound`  — `manager` has no budget for `token_address`.
+    pub fn replenish_manager_budget(
+        env: Env,
+        owner: Address,
+        org: Address,
+        manager: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        Self::require_org_owner(&env, &owner, &org)?;
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let budget = try_load_manager_budget(&env, &org, &manager, &token_address)
+            .ok_or(StreamError::RecordNotFound)?;
+        let mut budget = Self::roll_epoch(budget, env.ledger().timestamp());
+        budget.spent = budget.spent.saturating_sub(amount);
+        save_manager_budget(&env, &org, &manager, &token_address, Some(&budget));
+
+        let remaining = budget.limit - budget.spent;
+        env.events().publish(
+            (Symbol::new(&env, "manager_budget_replenished"), org.clone()),
+            ManagerBudgetReplenishedEvent {
+                org,
+                manager,
+                token_address,
+                amount,
+                remaining,
+            },
+        );
+        Ok(remaining)
+    }
+
+    /// Returns `manager`'s budget within `org` for `token`, rolled over to
+    /// the current epoch, or `None` if it has none.
+    pub fn get_manager_budget(
+        env: Env,
+        org: Address,
+        manager: Address,
+        token_address: Address,
+    ) -> Option<SpendingLimit> {
+        try_load_manager_budget(&env, &org, &manager, &token_address)
+            .map(|budget| Self::roll_epoch(budget, env.ledger().timestamp()))
+    }
+
+    /// Returns what `manager` may still commit in `token` this epoch, or
+    /// `None` if it has no budget (and so is not capped).
+    pub fn get_manager_budget_remaining(
+        env: Env,
+        org: Address,
+        manager: Address,
+        token_address: Address,
+    ) -> Option<i128> {
+        Self::get_manager_budget(env, org, manager, token_address)
+            .map(|budget| budget.limit - budget.spent)
+    }
+}
+
+impl StreamContract {
+    /// Authorizes `owner` and checks it owns `org`.
+    ///
+    /// # Errors
+    /// - `OrgNotFound`  — `org` is not registered.
+    /// - `Unauthorized` — `owner` is not the org's owner.
+    fn require_org_owner(env: &Env, owner: &Address, org: &Address) -> Result<(), StreamError> {
+        owner.require_auth();
+        if try_load_org_owner(env, org).ok_or(StreamError::OrgNotFound)? != *owner {
+            return Err(StreamError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Counts `amount` against `manager`'s budget for `token`, if any.
+    ///
+    /// # Errors
+    /// - `BudgetExceeded` — `amount` does not fit in the current epoch's budget.
+    fn draw_manager_budget(
+        env: &Env,
+        org: &Address,
+        manager: &Address,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        let Some(budget) = try_load_manager_budget(env, org, manager, token_address) else {
+            return Ok(());
+        };
+        let mut budget = Self::roll_epoch(budget, env.ledger().timestamp());
+        let spent = budget.spent.saturating_add(amount);
+        if spent > budget.limit {
+            return Err(StreamError::BudgetExceeded);
+        }
+        budget.spent = spent;
+        save_manager_budget(env, org, manager, token_address, Some(&budget));
+        Ok(())
+    }
+
+    /// Authorizes `manager` and checks it holds the permission `allowed`
+    /// selects within `org`.
+    fn require_manager(
+        env: &Env,
+        manager: &Address,
+        org: &Address,
+        allowed: impl Fn(&ManagerPermissions) -> bool,
+    ) -> Result<(), StreamError> {
+        manager.require_auth();
+        match try_load_org_manager(env, org, manager) {
+            Some(permissions) if allowed(&permissions) => Ok(()),
+            _ => Err(StreamError::ManagerNotAuthorized),
+        }
+    }
+}
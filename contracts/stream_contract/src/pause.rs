@@ -0,0 +1,69 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::PauseUpdatedEvent;
+use crate::storage::{is_wound_down, load_pause_flags, save_pause_flags};
+use crate::types::PauseFlags;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Pause Switches ───────────────────────────────────────────────────────
+
+    /// Set the protocol-wide pause switches. Admin-only.
+    ///
+    /// Each switch stops one kind of operation across every token, so an
+    /// incident can halt inflows while recipients keep their exit:
+    /// - `creations`   — opening streams of any kind.
+    /// - `top_ups`     — top-ups, donations, scheduling top-ups and deposits
+    ///   into vaults or fee credits.
+    /// - `withdrawals` — recipient withdrawals, claims and payouts, including
+    ///   keeper settlement and `accelerate`.
+    ///
+    /// Paused operations fail with `Paused`. Once the protocol winds
+    /// down, creations and top-ups stay paused whatever the switches say.
+    /// Cancellations and the
+    /// sender's exits (vault withdrawals, cancelling scheduled top-ups) are
+    /// never paused.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn set_pause_flags(env: Env, admin: Address, flags: PauseFlags) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "set_pause_flags")?;
+        save_pause_flags(&env, &flags);
+
+        env.events().publish(
+            (Symbol::new(&env, "pause_updated"),),
+            PauseUpdatedEvent {
+                creations: flags.creations,
+                top_ups: flags.top_ups,
+                withdrawals: flags.withdrawals,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the protocol-wide pause switches.
+    pub fn get_pause_flags(env: Env) -> PauseFlags {
+        load_pause_flags(&env)
+    }
+}
+
+impl StreamContract {
+    /// Rejects an operation whose pause switch, picked by `paused`, is on.
+    pub(crate) fn require_unpaused(
+        env: &Env,
+        paused: impl Fn(&PauseFlags) -> bool,
+    ) -> Result<(), StreamError> {
+        let mut flags = load_pause_flags(env);
+        if is_wound_down(env) {
+            flags.creations = true;
+            flags.top_ups = true;
+        }
+        if paused(&flags) {
+            return Err(StreamError::Paused);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::types::{ExactRate, Stream, StreamCondition, StreamState};
+use crate::StreamContract;
+
+/// What a stream's accrual depends on. All of it lives in the hot
+/// `StreamState` record, so read-only accrual queries can load that record
+/// alone and skip the stream's terms.
+pub(crate) trait Accrual {
+    fn rate(&self) -> &ExactRate;
+    fn last_update_time(&self) -> u64;
+    fn cancel_at(&self) -> u64;
+    fn condition(&self) -> &StreamCondition;
+    fn settled_balance(&self) -> i128;
+    /// Deposit neither withdrawn nor settled yet.
+    fn unsettled(&self) -> i128;
+}
+
+impl Accrual for Stream {
+    fn rate(&self) -> &ExactRate {
+        &self.rate
+    }
+    fn last_update_time(&self) -> u64 {
+        self.last_update_time
+    }
+    fn cancel_at(&self) -> u64 {
+        self.cancel_at
+    }
+    fn condition(&self) -> &StreamCondition {
+        &self.condition
+    }
+    fn settled_balance(&self) -> i128 {
+        self.settled_balance
+    }
+    fn unsettled(&self) -> i128 {
+        self.deposited_amount
+            .saturating_sub(self.withdrawn_amount)
+            .saturating_sub(self.settled_balance)
+    }
+}
+
+impl Accrual for StreamState {
+    fn rate(&self) -> &ExactRate {
+        &self.rate
+    }
+    fn last_update_time(&self) -> u64 {
+        self.last_update_time
+    }
+    fn cancel_at(&self) -> u64 {
+        self.cancel_at
+    }
+    fn condition(&self) -> &StreamCondition {
+        &self.condition
+    }
+    fn settled_balance(&self) -> i128 {
+        self.settled_balance
+    }
+    fn unsettled(&self) -> i128 {
+        self.deposited_amount
+            .saturating_sub(self.withdrawn_amount)
+            .saturating_sub(self.settled_balance)
+    }
+}
+
+impl Stream {
+    /// The stream's rate in whole token units per second, rounded down.
+    pub fn rate_per_second(&self) -> i128 {
+        self.rate.numerator / self.rate.denominator.max(1) as i128
+    }
+}
+
+impl StreamContract {
+    // ─── Exact Rates ──────────────────────────────────────────────────────────
+
+    /// Amount the stream accrues between `from` and `to` at its exact rate.
+    ///
+    /// Both ends are measured from `rate.anchor` and rounded down, so
+    /// successive settlements add up to exactly what one settlement over the
+    /// whole span would give: truncation never accumulates.
+    pub(crate) fn streamed_between(stream: &impl Accrual, from: u64, to: u64) -> i128 {
+        Self::streamed_since_anchor(stream, to)
+            .saturating_sub(Self::streamed_since_anchor(stream, from))
+    }
+
+    /// Whole seconds the stream needs to accrue `amount` at its exact rate,
+    /// rounded up; 0 for a stream with no rate.
+    pub(crate) fn seconds_to_stream(stream: &Stream, amount: i128) -> u64 {
+        let numerator = stream.rate.numerator;
+        if amount <= 0 || numerator <= 0 {
+            return 0;
+        }
+        let denominator = stream.rate.denominator.max(1) as i128;
+        let whole = (amount / numerator).saturating_mul(denominator);
+        let rest = amount % numerator;
+        let partial = match rest.checked_mul(denominator) {
+            Some(scaled) => (scaled + numerator - 1) / numerator,
+            // Only rates of far more than a unit per second get here.
+            None => rest / stream.rate_per_second().max(1) + 1,
+        };
+        whole.saturating_add(partial).clamp(0, u64::MAX as i128) as u64
+    }
+
+    /// `numerator * (t - anchor) / denominator`, rounded down.
+    fn streamed_since_anchor(stream: &impl Accrual, t: u64) -> i128 {
+        let rate = stream.rate();
+        mul_div_floor(
+            rate.numerator,
+            t.saturating_sub(rate.anchor),
+            rate.denominator,
+        )
+    }
+}
+
+/// `a * b / c` rounded down for `a ≥ 0`, saturating at `i128::MAX`.
+///
+/// Splits `a` into `q * c + r` so the only product that is not itself part
+/// of the result, `r * b`, is below `2^128` and computed exactly in `u128`.
+fn mul_div_floor(a: i128, b: u64, c: u64) -> i128 {
+    if a <= 0 || c == 0 {
+        return 0;
+    }
+    let (q, r) = (a / c as i128, a % c as i128);
+    let partial = (r as u128 * b as u128 / c as u128) as i128;
+    q.checked_mul(b as i128)
+        .and_then(|whole| whole.checked_add(partial))
+        .unwrap_or(i128::MAX)
+}
@@ -0,0 +1,103 @@
+use soroban_sdk::{contractimpl, xdr::ToXdr, Address, Bytes, Env, Vec};
+
+use crate::events::{stream_topics, ReceiptMintedEvent};
+use crate::storage::{
+    append_to_holder_receipts, load_holder_receipts, save_receipt, try_load_receipt,
+    try_load_stream,
+};
+use crate::types::{Stream, StreamReceipt, StreamStatement, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Stream Receipts ──────────────────────────────────────────────────────
+
+    /// Returns the receipt minted for `stream_id`, or `None`.
+    pub fn get_receipt(env: Env, stream_id: u64) -> Option<StreamReceipt> {
+        try_load_receipt(&env, stream_id)
+    }
+
+    /// Returns up to `limit` receipts held by `holder`, starting at position
+    /// `start` in minting order.
+    ///
+    /// Returns an empty vector once `start` is past the end of the index.
+    pub fn get_receipts_by_holder(
+        env: Env,
+        holder: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<StreamReceipt> {
+        let ids = load_holder_receipts(&env, &holder);
+        let end = start.saturating_add(limit).min(ids.len());
+        let mut receipts = Vec::new(&env);
+        for i in start..end {
+            if let Some(receipt) = try_load_receipt(&env, ids.get_unchecked(i)) {
+                receipts.push_back(receipt);
+            }
+        }
+        receipts
+    }
+
+    /// Renders `stream_id`'s terms and settlement state as a canonical XDR
+    /// encoding of a `StreamStatement`, for off-chain signing or archiving.
+    ///
+    /// The same ledger state always produces the same bytes. Returns `None`
+    /// if the stream doesn't exist.
+    pub fn get_statement_bytes(env: Env, stream_id: u64) -> Option<Bytes> {
+        try_load_stream(&env, stream_id).map(|stream| {
+            StreamStatement {
+                stream_id,
+                rate_per_second: stream.rate_per_second(),
+                sender: stream.sender,
+                recipient: stream.recipient,
+                token_address: stream.token_address,
+                deposited_amount: stream.deposited_amount,
+                withdrawn_amount: stream.withdrawn_amount,
+                fee_rate_bps: stream.fee_rate_bps,
+                start_time: stream.start_time,
+                end_time: stream.end_time,
+                last_withdrawal_time: stream.last_withdrawal_time,
+                is_active: stream.status == StreamStatus::Active,
+                ledger_timestamp: env.ledger().timestamp(),
+            }
+            .to_xdr(&env)
+        })
+    }
+}
+
+impl StreamContract {
+    /// Mints the soulbound receipt for a newly created stream to its recipient.
+    ///
+    /// Receipts record the stream's opening terms and are bound to the
+    /// original recipient: there is no transfer entrypoint, and they never
+    /// carry claim rights.
+    pub(crate) fn mint_receipt(env: &Env, stream_id: u64, stream: &Stream) {
+        let receipt = StreamReceipt {
+            stream_id,
+            holder: stream.recipient.clone(),
+            sender: stream.sender.clone(),
+            token_address: stream.token_address.clone(),
+            deposited_amount: stream.deposited_amount,
+            rate_per_second: stream.rate_per_second(),
+            start_time: stream.start_time,
+            end_time: stream.end_time,
+            issued_at: env.ledger().timestamp(),
+        };
+        save_receipt(env, stream_id, &receipt);
+        append_to_holder_receipts(env, &receipt.holder, stream_id);
+
+        env.events().publish(
+            stream_topics(
+                env,
+                "receipt_minted",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            ReceiptMintedEvent {
+                stream_id,
+                holder: receipt.holder,
+            },
+        );
+    }
+}
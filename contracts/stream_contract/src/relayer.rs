@@ -0,0 +1,111 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, RelayerFeeSetEvent, RelayerReimbursedEvent};
+use crate::storage::{load_stream, save_stream};
+use crate::types::Stream;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Relayed Withdrawals ──────────────────────────────────────────────────
+
+    /// Approve the fixed amount a relayer earns, out of the withdrawn tokens,
+    /// each time it submits `withdraw_for` on this stream. Sender-only; 0
+    /// turns reimbursement off. A relayed claim fails rather than pay more
+    /// than the `max_relayer_fee` the recipient signed.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `relayer_fee` is negative.
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn set_relayer_fee(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        relayer_fee: i128,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        if relayer_fee < 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+
+        stream.relayer_fee = relayer_fee;
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "relayer_fee_set",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            RelayerFeeSetEvent {
+                stream_id,
+                relayer_fee,
+            },
+        );
+        Ok(())
+    }
+
+    /// Withdraw on the recipient's behalf. The recipient signs the
+    /// authorization off-chain and `relayer` submits (and pays for) the
+    /// transaction, receiving the stream's approved relayer fee out of the
+    /// withdrawn amount.
+    ///
+    /// The recipient's signature covers `max_relayer_fee`, so the sender
+    /// cannot raise the fee on a claim the recipient has already signed.
+    ///
+    /// Otherwise identical to `withdraw`; returns the gross amount withdrawn.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — the stream's relayer fee exceeds
+    ///   `max_relayer_fee`, or the claim does not cover the relayer and
+    ///   withdrawal fees.
+    /// - Same as `withdraw` otherwise.
+    pub fn withdraw_for(
+        env: Env,
+        relayer: Address,
+        stream_id: u64,
+        max_relayer_fee: i128,
+    ) -> Result<i128, StreamError> {
+        relayer.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        stream.recipient.require_auth();
+        if stream.relayer_fee > max_relayer_fee {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::execute_withdrawal(env, stream.recipient, stream_id, Some(&relayer), None, None)
+    }
+}
+
+impl StreamContract {
+    /// Pays the stream's relayer fee to `relayer`.
+    pub(crate) fn reimburse_relayer(env: &Env, stream: &Stream, stream_id: u64, relayer: &Address) {
+        if stream.relayer_fee <= 0 {
+            return;
+        }
+        Self::send_tokens(env, &stream.token_address, relayer, stream.relayer_fee);
+        env.events().publish(
+            stream_topics(
+                env,
+                "relayer_reimbursed",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            RelayerReimbursedEvent {
+                stream_id,
+                relayer: relayer.clone(),
+                amount: stream.relayer_fee,
+            },
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{stream_topics, StreamRestartedEvent};
+use crate::storage::{
+    load_stream, save_restart, try_load_restarted_as, try_load_restarted_from,
+    try_load_stream_deposit_cap,
+};
+use crate::types::{StreamOptions, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// How long after a cancellation its sender may still restart the stream:
+/// 1 day.
+pub(crate) const RESTART_WINDOW: u64 = 86_400;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Restarts ─────────────────────────────────────────────────────────────
+
+    /// Undo a mistaken cancellation by re-escrowing the refunded remainder,
+    /// pulled again from the sender, into a continuation stream to the same
+    /// recipient over `new_duration` seconds. Sender-only, and only within
+    /// `RESTART_WINDOW` of the cancellation.
+    ///
+    /// The continuation keeps the original's options (cooldown, cap, tags,
+    /// clawback window, category, partner, yield policy) and is charged fees
+    /// like a new stream. Each stream may be restarted once; the link is
+    /// recorded both ways for `get_restarted_as` / `get_restarted_from`.
+    ///
+    /// Returns the continuation's ID.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `NotSender`         — caller is not the stream's sender.
+    /// - `InvalidTransition` — the stream is not cancelled, was already
+    ///   restarted, was cancelled more than `RESTART_WINDOW` ago, or is an
+    ///   unclaimed link.
+    /// - `InvalidAmount`     — nothing was refunded.
+    /// - Same as `create_stream_with_options` otherwise.
+    pub fn restart_stream(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        new_duration: u64,
+    ) -> Result<u64, StreamError> {
+        let original = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&original, &sender)?;
+        let cancelled_at = original.last_update_time;
+        if original.status != StreamStatus::Cancelled
+            || Self::is_unclaimed_link(&env, &original)
+            || try_load_restarted_as(&env, stream_id).is_some()
+            || env.ledger().timestamp() > cancelled_at.saturating_add(RESTART_WINDOW)
+        {
+            return Err(StreamError::InvalidTransition);
+        }
+        let amount = original.deposited_amount - original.withdrawn_amount;
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+
+        let options = StreamOptions {
+            withdrawal_cooldown: original.withdrawal_cooldown,
+            withdrawal_cap: original.withdrawal_cap,
+            withdrawal_cap_period: original.withdrawal_cap_period,
+            tags: original.tags.clone(),
+            clawback_window: original.clawback_window,
+            category: original.category,
+            partner: original.partner.clone(),
+            yield_policy: original.yield_policy,
+            deposit_cap: try_load_stream_deposit_cap(&env, stream_id).unwrap_or(0),
+            ..StreamOptions::new(&env)
+        };
+        let continuation_id = Self::open_stream(
+            env.clone(),
+            None,
+            sender,
+            original.recipient.clone(),
+            original.token_address.clone(),
+            amount,
+            new_duration,
+            options,
+            Funding::Direct,
+        )?;
+        save_restart(&env, stream_id, continuation_id);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_restarted",
+                continuation_id,
+                &original.sender,
+                &original.recipient,
+            ),
+            StreamRestartedEvent {
+                stream_id: continuation_id,
+                original_id: stream_id,
+                amount,
+            },
+        );
+        Ok(continuation_id)
+    }
+
+    /// Returns the continuation `stream_id` was restarted as, or `None`.
+    pub fn get_restarted_as(env: Env, stream_id: u64) -> Option<u64> {
+        try_load_restarted_as(&env, stream_id)
+    }
+
+    /// Returns the cancelled stream `stream_id` continues, or `None`.
+    pub fn get_restarted_from(env: Env, stream_id: u64) -> Option<u64> {
+        try_load_restarted_from(&env, stream_id)
+    }
+}
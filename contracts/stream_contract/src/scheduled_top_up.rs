@@ -0,0 +1,190 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{stream_topics, ScheduledTopUpCancelledEvent, TopUpScheduledEvent};
+use crate::storage::{
+    load_stream, next_scheduled_top_up_id, save_scheduled_top_up, save_stream,
+    try_load_scheduled_top_up,
+};
+use crate::types::ScheduledTopUp;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Scheduled Top-Ups ────────────────────────────────────────────────────
+
+    /// Commit a top-up of `amount` to an active stream in advance, e.g. a
+    /// quarterly budget release. Sender-only.
+    ///
+    /// The tokens are escrowed now, counting against the deposit cap and
+    /// the sender's spending limit, but join the stream only once anyone
+    /// calls `execute_scheduled_top_up` at or after `execute_at`. Until then
+    /// the sender may take them back with `cancel_scheduled_top_up`.
+    ///
+    /// Returns the scheduled top-up's ID.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`      — `amount` ≤ 0.
+    /// - `InvalidDuration`    — `execute_at` is not in the future.
+    /// - `StreamNotFound`     — no stream exists with `stream_id`.
+    /// - `NotSender`          — caller is not the stream's sender.
+    /// - `StreamInactive`     — stream has been cancelled or fully withdrawn.
+    /// - `InvalidTransition`  — the stream is a timelock.
+    /// - `TokenFrozen`        — the stream's token is frozen.
+    /// - `DepositCapExceeded` — `amount` would exceed the token's deposit cap.
+    /// - `BudgetExceeded`     — `amount` exceeds `sender`'s remaining spending limit.
+    pub fn schedule_top_up(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+        execute_at: u64,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if execute_at <= env.ledger().timestamp() {
+            return Err(StreamError::InvalidDuration);
+        }
+
+        let stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        if stream.is_timelock() {
+            return Err(StreamError::InvalidTransition);
+        }
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::require_within_deposit_cap(&env, &stream.token_address, amount)?;
+        Self::record_spend(&env, &sender, &stream.token_address, amount)?;
+
+        Self::receive_tokens(&env, &stream.token_address, &sender, amount);
+        let top_up_id = next_scheduled_top_up_id(&env);
+        save_scheduled_top_up(
+            &env,
+            top_up_id,
+            Some(&ScheduledTopUp {
+                stream_id,
+                sender,
+                amount,
+                execute_at,
+            }),
+        );
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "top_up_scheduled",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            TopUpScheduledEvent {
+                top_up_id,
+                stream_id,
+                amount,
+                execute_at,
+            },
+        );
+        Ok(top_up_id)
+    }
+
+    /// Add a due scheduled top-up to its stream. Callable by anyone.
+    ///
+    /// The escrow is added like `top_up_stream`: fees are charged at the
+    /// stream's rate and `end_time` moves out by the funded seconds. If the
+    /// stream has ended or changed sender in the meantime, the escrow is
+    /// refunded to whoever scheduled it instead.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`    — no pending top-up exists with `top_up_id`.
+    /// - `InvalidTransition` — `execute_at` has not been reached.
+    /// - `TokenFrozen`       — the stream's token is frozen.
+    pub fn execute_scheduled_top_up(env: Env, top_up_id: u64) -> Result<(), StreamError> {
+        let top_up =
+            try_load_scheduled_top_up(&env, top_up_id).ok_or(StreamError::StreamNotFound)?;
+        if env.ledger().timestamp() < top_up.execute_at {
+            return Err(StreamError::InvalidTransition);
+        }
+
+        let mut stream = load_stream(&env, top_up.stream_id)?;
+        if !stream.is_active() || stream.sender != top_up.sender {
+            return Self::refund_scheduled_top_up(&env, top_up_id, &top_up);
+        }
+        save_scheduled_top_up(&env, top_up_id, None);
+        let net_amount = Self::deposit_top_up(
+            &env,
+            &top_up.sender,
+            top_up.stream_id,
+            &mut stream,
+            top_up.amount,
+            Funding::Escrow,
+        )?;
+        let extension = Self::seconds_to_stream(&stream, net_amount);
+        stream.end_time = stream.end_time.saturating_add(extension);
+
+        save_stream(&env, top_up.stream_id, &stream);
+        Self::emit_top_up(&env, top_up.sender, top_up.stream_id, net_amount, &stream);
+        Ok(())
+    }
+
+    /// Take back a scheduled top-up that has not been executed yet.
+    /// Sender-only.
+    ///
+    /// Returns the amount refunded.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no pending top-up exists with `top_up_id`.
+    /// - `NotSender`      — caller did not schedule it.
+    pub fn cancel_scheduled_top_up(
+        env: Env,
+        sender: Address,
+        top_up_id: u64,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        let top_up =
+            try_load_scheduled_top_up(&env, top_up_id).ok_or(StreamError::StreamNotFound)?;
+        if top_up.sender != sender {
+            return Err(StreamError::NotSender);
+        }
+
+        Self::refund_scheduled_top_up(&env, top_up_id, &top_up)?;
+        Ok(top_up.amount)
+    }
+
+    /// Returns a pending scheduled top-up, or `None` once executed or cancelled.
+    pub fn get_scheduled_top_up(env: Env, top_up_id: u64) -> Option<ScheduledTopUp> {
+        try_load_scheduled_top_up(&env, top_up_id)
+    }
+}
+
+impl StreamContract {
+    /// Removes a scheduled top-up and returns its escrow to the sender.
+    fn refund_scheduled_top_up(
+        env: &Env,
+        top_up_id: u64,
+        top_up: &ScheduledTopUp,
+    ) -> Result<(), StreamError> {
+        let stream = load_stream(env, top_up.stream_id)?;
+        save_scheduled_top_up(env, top_up_id, None);
+        Self::send_tokens(env, &stream.token_address, &top_up.sender, top_up.amount);
+
+        env.events().publish(
+            stream_topics(
+                env,
+                "scheduled_top_up_cancelled",
+                top_up.stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            ScheduledTopUpCancelledEvent {
+                top_up_id,
+                stream_id: top_up.stream_id,
+                amount: top_up.amount,
+            },
+        );
+        Ok(())
+    }
+}
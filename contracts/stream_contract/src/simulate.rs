@@ -0,0 +1,154 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::storage::load_stream;
+use crate::types::{
+    CancelSimulation, CreateSimulation, StreamCategory, StreamOptions, WithdrawSimulation,
+};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient, MAX_TAGS, MIN_AMOUNT};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Dry Runs ─────────────────────────────────────────────────────────────
+
+    /// Preview `create_stream_with_options` without touching storage or
+    /// moving tokens. Runs the same validations and returns the fee, net
+    /// deposit and schedule the stream would get.
+    ///
+    /// Does not check `sender`'s token balance or allowance.
+    ///
+    /// # Errors
+    /// Same as `create_stream_with_options`.
+    pub fn simulate_create_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        options: StreamOptions,
+    ) -> Result<CreateSimulation, StreamError> {
+        if amount < MIN_AMOUNT
+            || options.withdrawal_cap < 0
+            || options.bonus_amount < 0
+            || options.deposit_cap < 0
+        {
+            return Err(StreamError::InvalidAmount);
+        }
+        if duration == 0
+            || options.cliff_duration > duration
+            || (options.withdrawal_cap > 0 && options.withdrawal_cap_period == 0)
+        {
+            return Err(StreamError::InvalidDuration);
+        }
+        if options.tags.len() > MAX_TAGS {
+            return Err(StreamError::TooManyTags);
+        }
+        let start_time = Self::scheduled_start(&env, &options)?;
+        Self::validate_yield_policy(&options.yield_policy)?;
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        Self::require_sender_allowed(&env, &sender)?;
+        Self::require_payee_allowed(&env, &sender, &recipient)?;
+        Self::require_attested(&env, &sender, &recipient)?;
+        Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+
+        let fee_rate_bps = Self::creation_fee_rate(&env, &options)?;
+        Self::require_streamable(amount, duration, fee_rate_bps)?;
+        let net_amount = Self::net_of_fee(amount, fee_rate_bps);
+        let rate_per_second = net_amount / (duration as i128);
+        Self::require_within_deposit_cap(
+            &env,
+            &token_address,
+            amount.saturating_add(options.bonus_amount),
+        )?;
+        Self::check_spend(&env, &sender, &token_address, amount)?;
+
+        Ok(CreateSimulation {
+            fee_rate_bps,
+            fee_amount: amount - net_amount,
+            net_amount,
+            net_bonus_amount: Self::net_of_fee(options.bonus_amount, fee_rate_bps),
+            rate_per_second,
+            start_time,
+            end_time: start_time.saturating_add(duration),
+        })
+    }
+
+    /// Preview `withdraw` on `stream_id` at the current ledger time without
+    /// touching storage or moving tokens.
+    ///
+    /// # Errors
+    /// Same as `withdraw`, except that no authorization is required.
+    pub fn simulate_withdraw(env: Env, stream_id: u64) -> Result<WithdrawSimulation, StreamError> {
+        let stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+        Self::require_token_not_frozen(&env, &stream.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let now = env.ledger().timestamp();
+        if now < Self::next_withdrawal_time(&stream) {
+            return Err(StreamError::CooldownActive);
+        }
+        if stream.hold_contested {
+            return Err(StreamError::HoldContested);
+        }
+        let accrued = Self::calculate_claimable(&stream, now);
+        if accrued <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let amount = accrued.min(Self::withdrawal_allowance(&stream, now));
+        if amount <= 0 {
+            return Err(StreamError::WithdrawalCapReached);
+        }
+        Self::require_min_claim(&env, &stream, amount)?;
+
+        let withdrawal_fee = Self::withdrawal_fee_on(&env, &stream, amount);
+        let payout = amount - withdrawal_fee;
+        let split_amount = match (&stream.split_address, stream.clawback_window) {
+            (Some(_), 0) => payout * (stream.split_bps as i128) / 10_000,
+            _ => 0,
+        };
+        let remaining_balance = stream.deposited_amount - stream.withdrawn_amount - amount;
+        Ok(WithdrawSimulation {
+            amount,
+            withdrawal_fee,
+            split_amount,
+            recipient_amount: payout - split_amount,
+            remaining_balance,
+            closes_stream: remaining_balance <= 0
+                && !Self::bonus_outstanding(&stream)
+                && stream.category != StreamCategory::Donation,
+        })
+    }
+
+    /// Preview `cancel_stream` on `stream_id` at the current ledger time
+    /// without touching storage or moving tokens.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `StreamInactive`  — stream is already inactive.
+    pub fn simulate_cancel(env: Env, stream_id: u64) -> Result<CancelSimulation, StreamError> {
+        let stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+
+        let now = env.ledger().timestamp();
+        let recipient_amount = Self::calculate_claimable(&stream, now).max(0);
+        let refunded_amount =
+            (stream.deposited_amount - stream.withdrawn_amount - recipient_amount).max(0);
+        let (bonus_to_recipient, bonus_to_sender) = if !Self::bonus_outstanding(&stream) {
+            (0, 0)
+        } else if Self::accrual_cutoff(&stream, now) >= stream.end_time {
+            (stream.bonus_amount, 0)
+        } else {
+            (0, stream.bonus_amount)
+        };
+        Ok(CancelSimulation {
+            recipient_amount,
+            refunded_amount,
+            bonus_to_recipient,
+            bonus_to_sender,
+        })
+    }
+}
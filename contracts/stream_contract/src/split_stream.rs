@@ -0,0 +1,128 @@
+use soroban_sdk::{contractimpl, Address, Env, Vec};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::types::StreamOptions;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Maximum number of recipients one split stream may pay.
+pub(crate) const MAX_SPLIT_RECIPIENTS: u32 = 10;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Split Streams ────────────────────────────────────────────────────────
+
+    /// Stream one deposit of `amount` over `duration` seconds to several
+    /// recipients at once, each receiving its `bps` share (out of 10 000).
+    ///
+    /// Each share becomes an ordinary stream with `options`, so every
+    /// recipient withdraws independently and cancelling a share settles
+    /// that recipient's accrual and refunds the rest of it to the sender.
+    /// Set `options.group` to cancel or top up all shares together with
+    /// `cancel_group` and `top_up_group`. `amount`, any completion bonus and
+    /// any deposit cap are divided by `bps`, rounding down, with the
+    /// remainder going to the last share; each share is charged the
+    /// protocol fee on its own part.
+    ///
+    /// Returns the new stream IDs, one per share in the order given.
+    ///
+    /// # Errors
+    /// - `InvalidSplit`    — `shares` is empty, has more than
+    ///   `MAX_SPLIT_RECIPIENTS` entries, repeats a recipient, has a 0 bps
+    ///   entry, or does not add up to 10 000 bps.
+    /// - `InvalidAmount`   — `amount` or a bonus or cap in `options` is too
+    ///   large to divide.
+    /// - `ApprovalRequired` — `amount` needs approvals under `sender`'s policy.
+    /// - Otherwise the same as `create_stream_with_options` for each share,
+    ///   e.g. `RateTooLow` when a share is too small for `duration`.
+    pub fn create_split_stream(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        shares: Vec<(Address, u32)>,
+        options: StreamOptions,
+    ) -> Result<Vec<u64>, StreamError> {
+        sender.require_auth();
+        Self::validate_split_shares(&shares)?;
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+
+        let mut stream_ids = Vec::new(&env);
+        let mut amount_left = amount;
+        let mut bonus_left = options.bonus_amount;
+        let mut cap_left = options.deposit_cap;
+        for (i, (recipient, bps)) in shares.iter().enumerate() {
+            let (share_amount, share_bonus, share_cap) = if i as u32 == shares.len() - 1 {
+                (amount_left, bonus_left, cap_left)
+            } else {
+                (
+                    Self::split_part(amount, bps)?,
+                    Self::split_part(options.bonus_amount, bps)?,
+                    Self::split_part(options.deposit_cap, bps)?,
+                )
+            };
+            amount_left -= share_amount;
+            bonus_left -= share_bonus;
+            cap_left -= share_cap;
+            // A cap too small to split must not leave a share uncapped.
+            let share_cap = if options.deposit_cap > 0 {
+                share_cap.max(1)
+            } else {
+                share_cap
+            };
+
+            let stream_id = Self::open_stream(
+                env.clone(),
+                None,
+                sender.clone(),
+                recipient,
+                token_address.clone(),
+                share_amount,
+                duration,
+                StreamOptions {
+                    bonus_amount: share_bonus,
+                    deposit_cap: share_cap,
+                    ..options.clone()
+                },
+                Funding::Preauthorized,
+            )?;
+            stream_ids.push_back(stream_id);
+        }
+        Ok(stream_ids)
+    }
+}
+
+impl StreamContract {
+    /// Returns the `bps` share of `total`, rounding down.
+    fn split_part(total: i128, bps: u32) -> Result<i128, StreamError> {
+        total
+            .checked_mul(bps as i128)
+            .map(|part| part / 10_000)
+            .ok_or(StreamError::InvalidAmount)
+    }
+
+    /// Checks that `shares` names between 1 and `MAX_SPLIT_RECIPIENTS`
+    /// distinct recipients with nonzero shares adding up to 10 000 bps.
+    fn validate_split_shares(shares: &Vec<(Address, u32)>) -> Result<(), StreamError> {
+        if shares.is_empty() || shares.len() > MAX_SPLIT_RECIPIENTS {
+            return Err(StreamError::InvalidSplit);
+        }
+        let mut total_bps: u32 = 0;
+        for (i, (recipient, bps)) in shares.iter().enumerate() {
+            if bps == 0
+                || shares
+                    .iter()
+                    .skip(i + 1)
+                    .any(|(other, _)| other == recipient)
+            {
+                return Err(StreamError::InvalidSplit);
+            }
+            total_bps = total_bps.saturating_add(bps);
+        }
+        if total_bps != 10_000 {
+            return Err(StreamError::InvalidSplit);
+        }
+        Ok(())
+    }
+}
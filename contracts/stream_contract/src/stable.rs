@@ -0,0 +1,388 @@
+use soroban_sdk::{contractimpl, vec, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{
+    stream_topics, StableStreamCreatedEvent, StableWithdrawnEvent, StreamCancelledEvent,
+    TopUpRequestedEvent,
+};
+use crate::storage::{
+    append_to_token_index, load_stable, next_stream_id, save_stable, try_load_stable,
+};
+use crate::types::{StableStream, StreamStatus};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Fixed-point scale of oracle prices: a price of `PRICE_SCALE` means one
+/// token unit is worth one reference unit.
+pub(crate) const PRICE_SCALE: i128 = 10_000_000;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Stable-Value Streams ─────────────────────────────────────────────────
+
+    /// Create a stream whose schedule is denominated in a stable reference
+    /// unit while the escrow is held in a (possibly volatile) token.
+    ///
+    /// `reference_amount` reference units accrue linearly over `duration`.
+    /// At each withdrawal the accrued reference value is converted into
+    /// tokens at the `oracle`'s current price, so the recipient's pay keeps
+    /// its reference value regardless of token price moves.
+    ///
+    /// The oracle must expose `get_price(token) -> i128`, returning reference
+    /// units per token unit scaled by `PRICE_SCALE` (10^7).
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `escrow_amount` or `reference_amount` ≤ 0.
+    /// - `InvalidDuration` — `duration` is 0.
+    /// - `SenderNotAllowed` — permissioned mode is on and `sender` is not allowlisted.
+    /// - `PayeeNotAllowed` — `sender`'s payee allowlist is on and omits `recipient`.
+    /// - `AttestationMissing` — a registry is configured and either party lacks an attestation.
+    /// - `InvalidTokenAddress` — `token_address` is not a token contract.
+    /// - `ApprovalRequired` — `escrow_amount` needs approvals under `sender`'s policy.
+    /// - `BudgetExceeded`  — `escrow_amount` exceeds `sender`'s spending limit.
+    /// - `TokenFrozen`     — `token_address` is frozen.
+    /// - `DepositCapExceeded` — `escrow_amount` would exceed the token's deposit cap.
+    pub fn create_stable_stream(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        oracle: Address,
+        escrow_amount: i128,
+        reference_amount: i128,
+        duration: u64,
+    ) -> Result<u64, StreamError> {
+        sender.require_auth();
+
+        if escrow_amount <= 0 || reference_amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        if duration == 0 {
+            return Err(StreamError::InvalidDuration);
+        }
+        Self::require_sender_allowed(&env, &sender)?;
+        Self::require_payee_allowed(&env, &sender, &recipient)?;
+        Self::require_attested(&env, &sender, &recipient)?;
+        Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.creations)?;
+        Self::require_within_deposit_cap(&env, &token_address, escrow_amount)?;
+        Self::require_no_approval_needed(&env, &sender, escrow_amount)?;
+        Self::record_spend(&env, &sender, &token_address, escrow_amount)?;
+
+        let stream_id = next_stream_id(&env);
+        let start_time = env.ledger().timestamp();
+
+        append_to_token_index(&env, &token_address, stream_id);
+        Self::receive_tokens(&env, &token_address, &sender, escrow_amount);
+
+        let fee_rate_bps = Self::current_fee_rate(&env);
+        let net_escrow = Self::collect_fee(
+            &env,
+            &sender,
+            &token_address,
+            escrow_amount,
+            fee_rate_bps,
+            stream_id,
+            None,
+        );
+
+        save_stable(
+            &env,
+            stream_id,
+            &StableStream {
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                token_address: token_address.clone(),
+                oracle: oracle.clone(),
+                reference_amount,
+                reference_paid: 0,
+                escrow_balance: net_escrow,
+                start_time,
+                duration,
+                is_active: true,
+                fee_rate_bps,
+            },
+        );
+
+        env.events().publish(
+            stream_topics(&env, "stable_created", stream_id, &sender, &recipient),
+            StableStreamCreatedEvent {
+                stream_id,
+                sender,
+                recipient,
+                token_address,
+                oracle,
+                escrow_amount: net_escrow,
+                reference_amount,
+                start_time,
+                duration,
+            },
+        );
+
+        Ok(stream_id)
+    }
+
+    /// Add tokens to a stable-value stream's escrow. Sender-only.
+    ///
+    /// Charged at the fee rate locked in at creation.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`   — `amount` ≤ 0.
+    /// - `StreamNotFound`  — no stable-value stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream has completed or been cancelled.
+    /// - `BudgetExceeded`  — `amount` exceeds `sender`'s spending limit.
+    /// - `TokenFrozen`     — the stream's token is frozen.
+    pub fn top_up_stable_stream(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        let mut stable = load_stable(&env, stream_id)?;
+        if stable.sender != sender {
+            return Err(StreamError::NotSender);
+        }
+        if !stable.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+        Self::require_token_not_frozen(&env, &stable.token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::record_spend(&env, &sender, &stable.token_address, amount)?;
+
+        Self::receive_tokens(&env, &stable.token_address, &sender, amount);
+        stable.escrow_balance += Self::collect_fee(
+            &env,
+            &sender,
+            &stable.token_address,
+            amount,
+            stable.fee_rate_bps,
+            stream_id,
+            None,
+        );
+
+        save_stable(&env, stream_id, &stable);
+        Ok(())
+    }
+
+    /// Withdraw the reference value accrued so far, converted to tokens at
+    /// the oracle's current price. Returns the number of tokens paid.
+    ///
+    /// If the escrow cannot cover the full value, whatever is left is paid,
+    /// the uncovered value stays owed, and a `top_up_requested` event tells
+    /// the sender how much to add. Once the full reference amount has been
+    /// paid, the leftover escrow is refunded to the sender.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stable-value stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has completed or been cancelled.
+    /// - `InvalidAmount`   — nothing accrued, or the escrow is empty.
+    /// - `OraclePriceUnavailable` — the oracle call failed or returned a
+    ///   non-positive price.
+    /// - `TokenFrozen`     — the stream's token is frozen.
+    pub fn withdraw_stable(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+
+        let mut stable = load_stable(&env, stream_id)?;
+        if stable.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        if !stable.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+
+        Self::require_token_not_frozen(&env, &stable.token_address)?;
+        Self::require_unpaused(&env, |p| p.withdrawals)?;
+
+        let now = env.ledger().timestamp();
+        let reference_due = Self::stable_reference_due(&stable, now);
+        if reference_due <= 0 || stable.escrow_balance <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let price = Self::oracle_price(&env, &stable.oracle, &stable.token_address)?;
+        let (tokens_paid, reference_covered) =
+            Self::pay_stable(&env, &mut stable, stream_id, reference_due, price);
+
+        if stable.reference_paid >= stable.reference_amount {
+            // Schedule complete: return the surplus created by price moves.
+            Self::refund_stable_escrow(&env, &mut stable);
+            stable.is_active = false;
+        }
+        save_stable(&env, stream_id, &stable);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stable_withdrawn",
+                stream_id,
+                &stable.sender,
+                &stable.recipient,
+            ),
+            StableWithdrawnEvent {
+                stream_id,
+                recipient,
+                token_amount: tokens_paid,
+                reference_amount: reference_covered,
+                price,
+                timestamp: now,
+            },
+        );
+
+        Ok(tokens_paid)
+    }
+
+    /// Cancel a stable-value stream. Sender-only.
+    ///
+    /// Accrued reference value is paid to the recipient at the oracle's
+    /// current price (as far as the escrow allows) and the rest of the escrow
+    /// is refunded to the sender.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stable-value stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream has completed or been cancelled.
+    /// - `OraclePriceUnavailable` — value is owed but no price is available.
+    pub fn cancel_stable_stream(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut stable = load_stable(&env, stream_id)?;
+        if stable.sender != sender {
+            return Err(StreamError::NotSender);
+        }
+        if !stable.is_active {
+            return Err(StreamError::StreamInactive);
+        }
+
+        let now = env.ledger().timestamp();
+        let reference_due = Self::stable_reference_due(&stable, now);
+        let mut amount_withdrawn = 0;
+        if reference_due > 0 && stable.escrow_balance > 0 {
+            let price = Self::oracle_price(&env, &stable.oracle, &stable.token_address)?;
+            amount_withdrawn =
+                Self::pay_stable(&env, &mut stable, stream_id, reference_due, price).0;
+        }
+        let refunded_amount = Self::refund_stable_escrow(&env, &mut stable);
+        stable.is_active = false;
+
+        let recipient = stable.recipient.clone();
+        save_stable(&env, stream_id, &stable);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_cancelled",
+                stream_id,
+                &stable.sender,
+                &stable.recipient,
+            ),
+            StreamCancelledEvent {
+                stream_id,
+                sender,
+                recipient,
+                amount_withdrawn,
+                refunded_amount,
+                remaining_deposit: stable.escrow_balance,
+                status: StreamStatus::Cancelled,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the stable-value stream record for `stream_id`, or `None`.
+    pub fn get_stable_stream(env: Env, stream_id: u64) -> Option<StableStream> {
+        try_load_stable(&env, stream_id)
+    }
+}
+
+impl StreamContract {
+    /// Reference value accrued by `now` but not yet paid.
+    fn stable_reference_due(stable: &StableStream, now: u64) -> i128 {
+        let elapsed = now.saturating_sub(stable.start_time).min(stable.duration);
+        let accrued = stable
+            .reference_amount
+            .checked_mul(elapsed as i128)
+            .map_or(stable.reference_amount, |v| v / stable.duration as i128);
+        accrued - stable.reference_paid
+    }
+
+    /// Queries `oracle` for the reference price of `token`.
+    fn oracle_price(env: &Env, oracle: &Address, token: &Address) -> Result<i128, StreamError> {
+        match env.try_invoke_contract::<i128, InvokeError>(
+            oracle,
+            &Symbol::new(env, "get_price"),
+            vec![env, token.into_val(env)],
+        ) {
+            Ok(Ok(price)) if price > 0 => Ok(price),
+            _ => Err(StreamError::OraclePriceUnavailable),
+        }
+    }
+
+    /// Pays `reference_due` to the recipient in tokens at `price`, limited by
+    /// the escrow. Emits `top_up_requested` for any uncovered value.
+    ///
+    /// Returns `(tokens_paid, reference_covered)`. The caller persists the stream.
+    fn pay_stable(
+        env: &Env,
+        stable: &mut StableStream,
+        stream_id: u64,
+        reference_due: i128,
+        price: i128,
+    ) -> (i128, i128) {
+        let tokens_due = reference_due * PRICE_SCALE / price;
+        let (tokens_paid, reference_covered) = if tokens_due <= stable.escrow_balance {
+            (tokens_due, reference_due)
+        } else {
+            let covered = stable.escrow_balance * price / PRICE_SCALE;
+            env.events().publish(
+                stream_topics(
+                    env,
+                    "top_up_requested",
+                    stream_id,
+                    &stable.sender,
+                    &stable.recipient,
+                ),
+                TopUpRequestedEvent {
+                    stream_id,
+                    sender: stable.sender.clone(),
+                    reference_shortfall: reference_due - covered,
+                    token_shortfall: tokens_due - stable.escrow_balance,
+                },
+            );
+            (stable.escrow_balance, covered)
+        };
+
+        if tokens_paid > 0 {
+            Self::send_tokens(env, &stable.token_address, &stable.recipient, tokens_paid);
+            Self::record_volume(env, &stable.sender, &stable.token_address, tokens_paid);
+        }
+        stable.escrow_balance -= tokens_paid;
+        stable.reference_paid += reference_covered;
+        (tokens_paid, reference_covered)
+    }
+
+    /// Returns the whole remaining escrow to the sender and returns the amount.
+    fn refund_stable_escrow(env: &Env, stable: &mut StableStream) -> i128 {
+        let refund = stable.escrow_balance;
+        if refund > 0 {
+            Self::send_tokens(env, &stable.token_address, &stable.sender, refund);
+        }
+        stable.escrow_balance = 0;
+        refund
+    }
+}
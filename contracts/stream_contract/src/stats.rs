@@ -0,0 +1,44 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::storage::{
+    load_sender_volume, load_token_volume, save_sender_volume, save_token_volume,
+};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Statistics ───────────────────────────────────────────────────────────
+
+    /// Returns the lifetime amount of `token` streamed out to recipients,
+    /// through withdrawals and cancellation or keeper settlements.
+    /// Withdrawal fees and keeper bounties count towards it; refunds do not.
+    pub fn get_token_volume(env: Env, token: Address) -> i128 {
+        load_token_volume(&env, &token)
+    }
+
+    /// Returns the lifetime amount of `token` that `sender`'s streams have
+    /// paid out to recipients, counted as in `get_token_volume`.
+    pub fn get_sender_volume(env: Env, sender: Address, token: Address) -> i128 {
+        load_sender_volume(&env, &sender, &token)
+    }
+}
+
+impl StreamContract {
+    /// Adds `amount` streamed out of a `sender` stream to the volume counters.
+    pub(crate) fn record_volume(env: &Env, sender: &Address, token: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        save_token_volume(
+            env,
+            token,
+            load_token_volume(env, token).saturating_add(amount),
+        );
+        save_sender_volume(
+            env,
+            sender,
+            token,
+            load_sender_volume(env, sender, token).saturating_add(amount),
+        );
+    }
+}
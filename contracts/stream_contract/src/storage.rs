@@ -1,7 +1,22 @@
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Symbol, Val, Vec};
 
 use crate::errors::StreamError;
-use crate::types::{DataKey, ProtocolConfig, Stream};
+use crate::events::{stream_topics, StreamDepletingEvent, TtlLowEvent};
+use crate::types::{
+    Acknowledgement, ActivityRecord, AdminRecord, ApprovalPolicy, AutoClaimSchedule, BasketStream,
+    ChildStream, DataKey, Delegation, FeeCredit, FeeRecord, HtlcTranche, LogKey,
+    ManagerPermissions, MasterStream, Partner, PauseFlags, ProtocolConfig, ScheduledTopUp,
+    SpendingLimit, StableStream, Stream, StreamCategory, StreamKey, StreamProposal, StreamReceipt,
+    StreamState, StreamStatus, StreamTerms, TokenInfo, TokenKey,
+};
+
+/// Remaining ledgers (~30 days at 5 s per ledger) below which a stream
+/// write emits `ttl_low`.
+pub const TTL_LOW_THRESHOLD: u32 = 518_400;
+
+/// Ledgers (~1 day at 5 s per ledger) a recent-activity record lives in
+/// temporary storage before the network drops it.
+pub const ACTIVITY_TTL: u32 = 17_280;
 
 // ─── Stream Counter ───────────────────────────────────────────────────────────
 
@@ -27,27 +42,1292 @@ pub fn next_stream_id(env: &Env) -> u64 {
 /// Returns `StreamNotFound` if no entry exists, keeping error handling
 /// central and preventing duplicated `match storage.get(...)` patterns.
 pub fn load_stream(env: &Env, stream_id: u64) -> Result<Stream, StreamError> {
+    try_load_stream(env, stream_id).ok_or(StreamError::StreamNotFound)
+}
+
+/// Loads a stream for the withdrawal path from its state record alone,
+/// leaving the creation-time terms (start time, deposit fee rate, tags,
+/// partner and decimals) at placeholder values. Withdrawals never read
+/// those fields, and `save_stream` never writes them back.
+///
+/// Returns `StreamNotFound` if no entry exists.
+pub fn load_stream_state_only(env: &Env, stream_id: u64) -> Result<Stream, StreamError> {
+    let state = try_load_stream_state(env, stream_id).ok_or(StreamError::StreamNotFound)?;
+    let terms = StreamTerms {
+        start_time: 0,
+        fee_rate_bps: 0,
+        tags: Vec::new(env),
+        partner: None,
+        token_decimals: 0,
+    };
+    Ok(join_stream(state, terms))
+}
+
+/// Persists the terms of a newly created stream, which never change after
+/// creation; `save_stream` then keeps its state.
+pub fn save_stream_terms(env: &Env, stream_id: u64, stream: &Stream) {
+    let (_, terms) = split_stream(stream);
     env.storage()
         .persistent()
-        .get(&DataKey::Stream(stream_id))
-        .ok_or(StreamError::StreamNotFound)
+        .set(&DataKey::StreamTerms(stream_id), &terms);
 }
 
-/// Persists a stream record in persistent storage.
+/// Persists a stream's state record; its terms are written only once, by
+/// `save_stream_terms`.
 ///
 /// Always use this instead of calling `.set` directly so that the key
 /// strategy remains the single source of truth.
+///
+/// Emits a `ttl_low` event when the entry has fewer than `TTL_LOW_THRESHOLD`
+/// ledgers left, so keepers know to call `bump_stream_ttl`, and a
+/// `stream_depleting` event when an active stream has less runway than the
+/// protocol's depletion warning period.
 pub fn save_stream(env: &Env, stream_id: u64, stream: &Stream) {
+    let (state, _) = split_stream(stream);
     env.storage()
         .persistent()
-        .set(&DataKey::Stream(stream_id), stream);
+        .set(&DataKey::Stream(stream_id), &state);
+
+    let remaining_ledgers = stream
+        .live_until_ledger
+        .saturating_sub(env.ledger().sequence());
+    if remaining_ledgers < TTL_LOW_THRESHOLD {
+        env.events().publish(
+            stream_topics(env, "ttl_low", stream_id, &stream.sender, &stream.recipient),
+            TtlLowEvent {
+                stream_id,
+                remaining_ledgers,
+            },
+        );
+    }
+
+    let warning_period = try_load_config(env).map_or(0, |cfg| cfg.depletion_warning_period);
+    if stream.status == StreamStatus::Active && stream.rate_per_second() > 0 {
+        let runway = stream.end_time.saturating_sub(env.ledger().timestamp());
+        if runway < warning_period {
+            env.events().publish(
+                stream_topics(
+                    env,
+                    "stream_depleting",
+                    stream_id,
+                    &stream.sender,
+                    &stream.recipient,
+                ),
+                StreamDepletingEvent {
+                    stream_id,
+                    runway,
+                    end_time: stream.end_time,
+                },
+            );
+        }
+    }
+}
+
+/// Extends the stream entry's TTL to the network maximum, records the new
+/// expiry in `stream.live_until_ledger`, and persists the stream.
+pub fn extend_stream_ttl(env: &Env, stream_id: u64, stream: &mut Stream) {
+    let extend_to = env.storage().max_ttl();
+    stream.live_until_ledger = env.ledger().sequence().saturating_add(extend_to);
+    save_stream(env, stream_id, stream);
+    let storage = env.storage().persistent();
+    storage.extend_ttl(&DataKey::Stream(stream_id), extend_to, extend_to);
+    storage.extend_ttl(&DataKey::StreamTerms(stream_id), extend_to, extend_to);
 }
 
 /// Returns the stream if it exists, `None` otherwise (used by read-only queries).
 pub fn try_load_stream(env: &Env, stream_id: u64) -> Option<Stream> {
+    let storage = env.storage().persistent();
+    let state: StreamState = storage.get(&DataKey::Stream(stream_id))?;
+    let terms: StreamTerms = storage.get(&DataKey::StreamTerms(stream_id))?;
+    Some(join_stream(state, terms))
+}
+
+/// Returns only the hot state record of a stream, without its terms, for
+/// read-only accrual queries; `None` if the stream does not exist.
+pub fn try_load_stream_state(env: &Env, stream_id: u64) -> Option<StreamState> {
     env.storage().persistent().get(&DataKey::Stream(stream_id))
 }
 
+/// Splits a stream into its separately stored state and terms.
+fn split_stream(stream: &Stream) -> (StreamState, StreamTerms) {
+    (
+        StreamState {
+            sender: stream.sender.clone(),
+            recipient: stream.recipient.clone(),
+            token_address: stream.token_address.clone(),
+            rate: stream.rate.clone(),
+            deposited_amount: stream.deposited_amount,
+            withdrawn_amount: stream.withdrawn_amount,
+            last_update_time: stream.last_update_time,
+            status: stream.status,
+            withdrawal_cooldown: stream.withdrawal_cooldown,
+            last_withdrawal_time: stream.last_withdrawal_time,
+            settled_balance: stream.settled_balance,
+            withdrawal_cap: stream.withdrawal_cap,
+            withdrawal_cap_period: stream.withdrawal_cap_period,
+            cap_window_start: stream.cap_window_start,
+            cap_window_withdrawn: stream.cap_window_withdrawn,
+            end_time: stream.end_time,
+            bonus_amount: stream.bonus_amount,
+            bonus_claimed: stream.bonus_claimed,
+            clawback_window: stream.clawback_window,
+            held_amount: stream.held_amount,
+            held_until: stream.held_until,
+            hold_contested: stream.hold_contested,
+            split_address: stream.split_address.clone(),
+            split_bps: stream.split_bps,
+            payout_address: stream.payout_address.clone(),
+            beneficiary: stream.beneficiary.clone(),
+            beneficiary_inactivity_period: stream.beneficiary_inactivity_period,
+            cancel_at: stream.cancel_at,
+            category: stream.category,
+            live_until_ledger: stream.live_until_ledger,
+            withdrawal_fee_bps: stream.withdrawal_fee_bps,
+            relayer_fee: stream.relayer_fee,
+            yield_policy: stream.yield_policy,
+            pending_yield: stream.pending_yield,
+            condition: stream.condition.clone(),
+        },
+        StreamTerms {
+            start_time: stream.start_time,
+            fee_rate_bps: stream.fee_rate_bps,
+            tags: stream.tags.clone(),
+            partner: stream.partner.clone(),
+            token_decimals: stream.token_decimals,
+        },
+    )
+}
+
+/// Reassembles a stream from its stored state and terms.
+fn join_stream(state: StreamState, terms: StreamTerms) -> Stream {
+    Stream {
+        sender: state.sender,
+        recipient: state.recipient,
+        token_address: state.token_address,
+        rate: state.rate,
+        deposited_amount: state.deposited_amount,
+        withdrawn_amount: state.withdrawn_amount,
+        start_time: terms.start_time,
+        last_update_time: state.last_update_time,
+        status: state.status,
+        fee_rate_bps: terms.fee_rate_bps,
+        withdrawal_cooldown: state.withdrawal_cooldown,
+        last_withdrawal_time: state.last_withdrawal_time,
+        settled_balance: state.settled_balance,
+        withdrawal_cap: state.withdrawal_cap,
+        withdrawal_cap_period: state.withdrawal_cap_period,
+        cap_window_start: state.cap_window_start,
+        cap_window_withdrawn: state.cap_window_withdrawn,
+        tags: terms.tags,
+        end_time: state.end_time,
+        bonus_amount: state.bonus_amount,
+        bonus_claimed: state.bonus_claimed,
+        clawback_window: state.clawback_window,
+        held_amount: state.held_amount,
+        held_until: state.held_until,
+        hold_contested: state.hold_contested,
+        split_address: state.split_address,
+        split_bps: state.split_bps,
+        payout_address: state.payout_address,
+        beneficiary: state.beneficiary,
+        beneficiary_inactivity_period: state.beneficiary_inactivity_period,
+        cancel_at: state.cancel_at,
+        category: state.category,
+        partner: terms.partner,
+        live_until_ledger: state.live_until_ledger,
+        withdrawal_fee_bps: state.withdrawal_fee_bps,
+        relayer_fee: state.relayer_fee,
+        token_decimals: terms.token_decimals,
+        yield_policy: state.yield_policy,
+        pending_yield: state.pending_yield,
+        condition: state.condition,
+    }
+}
+
+/// Returns the stored record of `stream_id` as a raw field map, whatever its
+/// layout, or `None` if there is none (used by `migrate_streams`).
+pub fn try_load_stream_fields(env: &Env, stream_id: u64) -> Option<Map<Symbol, Val>> {
+    env.storage().persistent().get(&DataKey::Stream(stream_id))
+}
+
+/// Returns whether a stream record exists for `stream_id`.
+pub fn stream_exists(env: &Env, stream_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Stream(stream_id))
+}
+
+/// Returns `sender`'s nonce for parameter-derived stream IDs (0 if unused).
+pub fn load_derived_nonce(env: &Env, sender: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DerivedIdNonce(sender.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores `sender`'s next parameter-derived ID nonce.
+pub fn bump_derived_nonce(env: &Env, sender: &Address, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DerivedIdNonce(sender.clone()), &nonce);
+}
+
+// ─── Basket Streams ───────────────────────────────────────────────────────────
+
+/// Loads a basket stream by ID, returning `StreamNotFound` if absent.
+pub fn load_basket(env: &Env, stream_id: u64) -> Result<BasketStream, StreamError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BasketStream(stream_id))
+        .ok_or(StreamError::StreamNotFound)
+}
+
+/// Persists a basket stream record.
+pub fn save_basket(env: &Env, stream_id: u64, basket: &BasketStream) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BasketStream(stream_id), basket);
+}
+
+/// Returns the basket stream if it exists, `None` otherwise.
+pub fn try_load_basket(env: &Env, stream_id: u64) -> Option<BasketStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BasketStream(stream_id))
+}
+
+// ─── Stable-Value Streams ─────────────────────────────────────────────────────
+
+/// Loads a stable-value stream by ID, returning `StreamNotFound` if absent.
+pub fn load_stable(env: &Env, stream_id: u64) -> Result<StableStream, StreamError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StableStream(stream_id))
+        .ok_or(StreamError::StreamNotFound)
+}
+
+/// Persists a stable-value stream record.
+pub fn save_stable(env: &Env, stream_id: u64, stable: &StableStream) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::StableStream(stream_id), stable);
+}
+
+/// Returns the stable-value stream if it exists, `None` otherwise.
+pub fn try_load_stable(env: &Env, stream_id: u64) -> Option<StableStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StableStream(stream_id))
+}
+
+// ─── Master Streams ───────────────────────────────────────────────────────────
+
+/// Loads a master stream by ID, returning `StreamNotFound` if absent.
+pub fn load_master(env: &Env, master_id: u64) -> Result<MasterStream, StreamError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MasterStream(master_id))
+        .ok_or(StreamError::StreamNotFound)
+}
+
+/// Persists a master stream record.
+pub fn save_master(env: &Env, master_id: u64, master: &MasterStream) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MasterStream(master_id), master);
+}
+
+/// Returns the master stream if it exists, `None` otherwise.
+pub fn try_load_master(env: &Env, master_id: u64) -> Option<MasterStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MasterStream(master_id))
+}
+
+/// Loads a child stream by ID, returning `StreamNotFound` if absent.
+pub fn load_child(env: &Env, child_id: u64) -> Result<ChildStream, StreamError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ChildStream(child_id))
+        .ok_or(StreamError::StreamNotFound)
+}
+
+/// Persists a child stream record.
+pub fn save_child(env: &Env, child_id: u64, child: &ChildStream) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ChildStream(child_id), child);
+}
+
+/// Returns the child stream if it exists, `None` otherwise.
+pub fn try_load_child(env: &Env, child_id: u64) -> Option<ChildStream> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ChildStream(child_id))
+}
+
+// ─── Tag Indexes ──────────────────────────────────────────────────────────────
+
+/// Returns the IDs of streams `sender` created under `tag` (empty if none).
+pub fn load_tag_index(env: &Env, sender: &Address, tag: &Symbol) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderTagStreams(sender.clone(), tag.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `stream_id` to the (sender, tag) index.
+pub fn append_to_tag_index(env: &Env, sender: &Address, tag: &Symbol, stream_id: u64) {
+    let mut ids = load_tag_index(env, sender, tag);
+    ids.push_back(stream_id);
+    env.storage().persistent().set(
+        &DataKey::SenderTagStreams(sender.clone(), tag.clone()),
+        &ids,
+    );
+}
+
+/// Removes `stream_id` from the (sender, tag) index.
+pub fn remove_from_tag_index(env: &Env, sender: &Address, tag: &Symbol, stream_id: u64) {
+    let mut ids = load_tag_index(env, sender, tag);
+    let Some(index) = ids.first_index_of(stream_id) else {
+        return;
+    };
+    ids.remove(index);
+    let key = DataKey::SenderTagStreams(sender.clone(), tag.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+// ─── Group Index ──────────────────────────────────────────────────────────────
+
+/// Returns the IDs of streams `sender` filed under `group` (empty if none).
+pub fn load_group_index(env: &Env, sender: &Address, group: &Symbol) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GroupStreams(sender.clone(), group.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `stream_id` to the (sender, group) index.
+pub fn append_to_group_index(env: &Env, sender: &Address, group: &Symbol, stream_id: u64) {
+    let mut ids = load_group_index(env, sender, group);
+    ids.push_back(stream_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::GroupStreams(sender.clone(), group.clone()), &ids);
+}
+
+// ─── Token Index ──────────────────────────────────────────────────────────────
+
+/// Returns the IDs of streams escrowing `token` (empty if none).
+pub fn load_token_index(env: &Env, token: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::Streams(token.clone())))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `stream_id` to `token`'s index.
+pub fn append_to_token_index(env: &Env, token: &Address, stream_id: u64) {
+    let mut ids = load_token_index(env, token);
+    ids.push_back(stream_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Token(TokenKey::Streams(token.clone())), &ids);
+}
+
+// ─── Receipts ─────────────────────────────────────────────────────────────────
+
+/// Persists the receipt minted for `stream_id`.
+pub fn save_receipt(env: &Env, stream_id: u64, receipt: &StreamReceipt) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Receipt(stream_id), receipt);
+}
+
+/// Returns the receipt for `stream_id`, or `None` if none was minted.
+pub fn try_load_receipt(env: &Env, stream_id: u64) -> Option<StreamReceipt> {
+    env.storage().persistent().get(&DataKey::Receipt(stream_id))
+}
+
+/// Returns the IDs of receipts held by `holder`, in minting order.
+pub fn load_holder_receipts(env: &Env, holder: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderReceipts(holder.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `stream_id` to `holder`'s receipt index.
+pub fn append_to_holder_receipts(env: &Env, holder: &Address, stream_id: u64) {
+    let mut ids = load_holder_receipts(env, holder);
+    ids.push_back(stream_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderReceipts(holder.clone()), &ids);
+}
+
+// ─── Approval Policies ────────────────────────────────────────────────────────
+
+/// Returns `org`'s approval policy, if any.
+pub fn try_load_approval_policy(env: &Env, org: &Address) -> Option<ApprovalPolicy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ApprovalPolicy(org.clone()))
+}
+
+/// Stores `org`'s approval policy, or removes it when `None`.
+pub fn save_approval_policy(env: &Env, org: &Address, policy: Option<&ApprovalPolicy>) {
+    let key = DataKey::ApprovalPolicy(org.clone());
+    match policy {
+        Some(policy) => env.storage().persistent().set(&key, policy),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns the next proposal ID and persists the updated counter. IDs start at 1.
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ProposalCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage().instance().set(&DataKey::ProposalCounter, &id);
+    id
+}
+
+/// Loads a proposal by ID, returning `ProposalNotFound` if absent.
+pub fn load_proposal(env: &Env, proposal_id: u64) -> Result<StreamProposal, StreamError> {
+    try_load_proposal(env, proposal_id).ok_or(StreamError::ProposalNotFound)
+}
+
+/// Returns the proposal if it exists, `None` otherwise.
+pub fn try_load_proposal(env: &Env, proposal_id: u64) -> Option<StreamProposal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Proposal(proposal_id))
+}
+
+/// Persists a proposal under its ID.
+pub fn save_proposal(env: &Env, proposal_id: u64, proposal: &StreamProposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Proposal(proposal_id), proposal);
+}
+
+// ─── Spending Limits ──────────────────────────────────────────────────────────
+
+/// Returns `sender`'s spending limit for `token`, if one is set.
+pub fn try_load_spending_limit(
+    env: &Env,
+    sender: &Address,
+    token: &Address,
+) -> Option<SpendingLimit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SpendingLimit(sender.clone(), token.clone()))
+}
+
+/// Stores `sender`'s spending limit for `token`, or removes it when `None`.
+pub fn save_spending_limit(
+    env: &Env,
+    sender: &Address,
+    token: &Address,
+    limit: Option<&SpendingLimit>,
+) {
+    let key = DataKey::SpendingLimit(sender.clone(), token.clone());
+    match limit {
+        Some(limit) => env.storage().persistent().set(&key, limit),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns `manager`'s budget within `org` for `token`, if one is set.
+pub fn try_load_manager_budget(
+    env: &Env,
+    org: &Address,
+    manager: &Address,
+    token: &Address,
+) -> Option<SpendingLimit> {
+    env.storage().persistent().get(&DataKey::ManagerBudget(
+        org.clone(),
+        manager.clone(),
+        token.clone(),
+    ))
+}
+
+/// Stores `manager`'s budget within `org` for `token`, or removes it when `None`.
+pub fn save_manager_budget(
+    env: &Env,
+    org: &Address,
+    manager: &Address,
+    token: &Address,
+    budget: Option<&SpendingLimit>,
+) {
+    let key = DataKey::ManagerBudget(org.clone(), manager.clone(), token.clone());
+    match budget {
+        Some(budget) => env.storage().persistent().set(&key, budget),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Fee History ──────────────────────────────────────────────────────────────
+
+/// Returns the fees charged on `stream_id`, oldest first (empty if none).
+pub fn load_fee_history(env: &Env, stream_id: u64) -> Vec<FeeRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeHistory(stream_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `record` to the fee history of `stream_id`.
+pub fn append_fee_record(env: &Env, stream_id: u64, record: FeeRecord) {
+    let mut history = load_fee_history(env, stream_id);
+    history.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeHistory(stream_id), &history);
+}
+
+// ─── Acknowledgements ─────────────────────────────────────────────────────────
+
+/// Returns the recipient's acknowledgements on `stream_id`, oldest first
+/// (empty if none).
+pub fn load_acknowledgements(env: &Env, stream_id: u64) -> Vec<Acknowledgement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Acknowledgements(stream_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Appends `acknowledgement` to those recorded on `stream_id`.
+pub fn append_acknowledgement(env: &Env, stream_id: u64, acknowledgement: Acknowledgement) {
+    let mut acknowledgements = load_acknowledgements(env, stream_id);
+    acknowledgements.push_back(acknowledgement);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Acknowledgements(stream_id), &acknowledgements);
+}
+
+// ─── Subscribers ──────────────────────────────────────────────────────────────
+
+/// Returns the notification subscribers of `stream_id` (empty if none).
+pub fn load_subscribers(env: &Env, stream_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PerStream(StreamKey::Subscribers(stream_id)))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Stores the notification subscribers of `stream_id`, removing the entry
+/// when none are left.
+pub fn save_subscribers(env: &Env, stream_id: u64, subscribers: &Vec<Address>) {
+    let key = DataKey::PerStream(StreamKey::Subscribers(stream_id));
+    if subscribers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, subscribers);
+    }
+}
+
+/// Returns the creation-time deposit cap of `stream_id`, if one is set.
+pub fn try_load_stream_deposit_cap(env: &Env, stream_id: u64) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PerStream(StreamKey::DepositCap(stream_id)))
+}
+
+/// Stores the deposit cap of `stream_id`.
+pub fn save_stream_deposit_cap(env: &Env, stream_id: u64, cap: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PerStream(StreamKey::DepositCap(stream_id)), &cap);
+}
+
+/// Returns the address proposed to take over the sender role of
+/// `stream_id`, if any.
+pub fn try_load_pending_sender(env: &Env, stream_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PerStream(StreamKey::PendingSender(stream_id)))
+}
+
+/// Stores the proposed new sender of `stream_id`, or removes it when `None`.
+pub fn save_pending_sender(env: &Env, stream_id: u64, new_sender: Option<&Address>) {
+    let key = DataKey::PerStream(StreamKey::PendingSender(stream_id));
+    match new_sender {
+        Some(new_sender) => env.storage().persistent().set(&key, new_sender),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Fee Sponsorship ──────────────────────────────────────────────────────────
+
+/// Returns `sender`'s fee credit in `token`, if sponsored.
+pub fn try_load_fee_credit(env: &Env, sender: &Address, token: &Address) -> Option<FeeCredit> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeCredit(sender.clone(), token.clone()))
+}
+
+/// Stores `sender`'s fee credit in `token`, or removes it when `None`.
+pub fn save_fee_credit(env: &Env, sender: &Address, token: &Address, credit: Option<&FeeCredit>) {
+    let key = DataKey::FeeCredit(sender.clone(), token.clone());
+    match credit {
+        Some(credit) => env.storage().persistent().set(&key, credit),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Category Fees ────────────────────────────────────────────────────────────
+
+/// Returns the fee rate override for `category`, if one is set.
+pub fn try_load_category_fee_rate(env: &Env, category: &StreamCategory) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CategoryFeeRate(*category))
+}
+
+/// Stores the fee rate override for `category`, or removes it when `None`.
+pub fn save_category_fee_rate(env: &Env, category: &StreamCategory, fee_rate_bps: Option<u32>) {
+    let key = DataKey::CategoryFeeRate(*category);
+    match fee_rate_bps {
+        Some(rate) => env.storage().instance().set(&key, &rate),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+/// Returns the custom fee rate reserved for `stream_id`, if any.
+pub fn try_load_custom_fee_rate(env: &Env, stream_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CustomFeeRate(stream_id))
+}
+
+/// Stores the custom fee rate reserved for `stream_id`, or removes it when
+/// `None`.
+pub fn save_custom_fee_rate(env: &Env, stream_id: u64, fee_rate_bps: Option<u32>) {
+    let key = DataKey::CustomFeeRate(stream_id);
+    match fee_rate_bps {
+        Some(rate) => env.storage().persistent().set(&key, &rate),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Stream Notes ─────────────────────────────────────────────────────────────
+
+/// Returns the stream's encrypted note, if any.
+pub fn try_load_note(env: &Env, stream_id: u64) -> Option<Bytes> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StreamNote(stream_id))
+}
+
+/// Stores the stream's encrypted note, or removes it when `None`.
+pub fn save_note(env: &Env, stream_id: u64, note: Option<&Bytes>) {
+    let key = DataKey::StreamNote(stream_id);
+    match note {
+        Some(note) => env.storage().persistent().set(&key, note),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Claim Links ──────────────────────────────────────────────────────────────
+
+/// Returns the hash locking a link stream's recipient role, if unclaimed.
+pub fn try_load_claim_link(env: &Env, stream_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimLink(stream_id))
+}
+
+/// Stores the hash locking a link stream, or removes it when `None`.
+pub fn save_claim_link(env: &Env, stream_id: u64, hash: Option<&BytesN<32>>) {
+    let key = DataKey::ClaimLink(stream_id);
+    match hash {
+        Some(hash) => env.storage().persistent().set(&key, hash),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Tranches ─────────────────────────────────────────────────────────────────
+
+/// Returns the stream's hash-locked tranche, if one is set aside.
+pub fn try_load_tranche(env: &Env, stream_id: u64) -> Option<HtlcTranche> {
+    env.storage().persistent().get(&DataKey::Tranche(stream_id))
+}
+
+/// Stores the stream's hash-locked tranche, or removes it when `None`.
+pub fn save_tranche(env: &Env, stream_id: u64, tranche: Option<&HtlcTranche>) {
+    let key = DataKey::Tranche(stream_id);
+    match tranche {
+        Some(tranche) => env.storage().persistent().set(&key, tranche),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Distributors ─────────────────────────────────────────────────────────────
+
+/// Returns the operator `recipient` approved to distribute its streams.
+pub fn try_load_distributor(env: &Env, recipient: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Distributor(recipient.clone()))
+}
+
+/// Stores `recipient`'s distributor, or removes it when `None`.
+pub fn save_distributor(env: &Env, recipient: &Address, operator: Option<&Address>) {
+    let key = DataKey::Distributor(recipient.clone());
+    match operator {
+        Some(operator) => env.storage().persistent().set(&key, operator),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Keeper Bounty ────────────────────────────────────────────────────────────
+
+/// Returns the keeper bounty rate in bps (0 if unset).
+pub fn load_keeper_bounty_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::KeeperBountyBps)
+        .unwrap_or(0)
+}
+
+/// Stores the keeper bounty rate in bps.
+pub fn save_keeper_bounty_bps(env: &Env, bounty_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::KeeperBountyBps, &bounty_bps);
+}
+
+// ─── Pause Flags ──────────────────────────────────────────────────────────────
+
+/// Returns the protocol's pause switches (all off if never set).
+pub fn load_pause_flags(env: &Env) -> PauseFlags {
+    env.storage()
+        .instance()
+        .get(&DataKey::PauseFlags)
+        .unwrap_or_default()
+}
+
+/// Stores the protocol's pause switches.
+pub fn save_pause_flags(env: &Env, flags: &PauseFlags) {
+    env.storage().instance().set(&DataKey::PauseFlags, flags);
+}
+
+/// Returns whether the protocol is winding down.
+pub fn is_wound_down(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::WindDown)
+}
+
+/// Marks the protocol as winding down, which cannot be undone, keeping
+/// the treasury of a renounced config.
+pub fn set_wound_down(env: &Env, retired_treasury: Option<&Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::WindDown, &retired_treasury.cloned());
+}
+
+/// Returns the treasury the protocol had when its admin renounced, if so.
+pub fn try_load_retired_treasury(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<_, Option<Address>>(&DataKey::WindDown)
+        .flatten()
+}
+
+// ─── Idle Yield ───────────────────────────────────────────────────────────────
+
+/// Returns the configured yield adapter, if any.
+pub fn try_load_yield_adapter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::YieldAdapter)
+}
+
+/// Stores the yield adapter, or removes it when `None`.
+pub fn save_yield_adapter(env: &Env, adapter: Option<&Address>) {
+    match adapter {
+        Some(adapter) => env
+            .storage()
+            .instance()
+            .set(&DataKey::YieldAdapter, adapter),
+        None => env.storage().instance().remove(&DataKey::YieldAdapter),
+    }
+}
+
+// ─── Partners ─────────────────────────────────────────────────────────────────
+
+/// Returns the partner registered under `partner_id`, if any.
+pub fn try_load_partner(env: &Env, partner_id: &Symbol) -> Option<Partner> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Partner(partner_id.clone()))
+}
+
+/// Stores the partner under `partner_id`, or removes it when `None`.
+pub fn save_partner(env: &Env, partner_id: &Symbol, partner: Option<&Partner>) {
+    let key = DataKey::Partner(partner_id.clone());
+    match partner {
+        Some(partner) => env.storage().persistent().set(&key, partner),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Vote Delegation ──────────────────────────────────────────────────────────
+
+/// Returns the stream's vote delegation, if any.
+pub fn try_load_delegation(env: &Env, stream_id: u64) -> Option<Delegation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegation(stream_id))
+}
+
+/// Stores the stream's vote delegation, or removes it when `None`.
+pub fn save_delegation(env: &Env, stream_id: u64, delegation: Option<&Delegation>) {
+    let key = DataKey::Delegation(stream_id);
+    match delegation {
+        Some(delegation) => env.storage().persistent().set(&key, delegation),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Custody ──────────────────────────────────────────────────────────────────
+
+/// Returns the accounted balance of `token` (0 if none).
+pub fn load_accounted_balance(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::AccountedBalance(token.clone())))
+        .unwrap_or(0)
+}
+
+/// Stores the accounted balance of `token`.
+pub fn save_accounted_balance(env: &Env, token: &Address, balance: i128) {
+    env.storage().persistent().set(
+        &DataKey::Token(TokenKey::AccountedBalance(token.clone())),
+        &balance,
+    );
+}
+
+/// Returns the protocol fees in `token` awaiting `claim_fees` (0 if none).
+pub fn load_accrued_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::AccruedFees(token.clone())))
+        .unwrap_or(0)
+}
+
+/// Stores the protocol fees in `token` awaiting `claim_fees`.
+pub fn save_accrued_fees(env: &Env, token: &Address, amount: i128) {
+    env.storage().persistent().set(
+        &DataKey::Token(TokenKey::AccruedFees(token.clone())),
+        &amount,
+    );
+}
+
+/// Returns the amount of `token` withheld from `account` by the compliance
+/// oracle (0 if none).
+pub fn load_compliance_hold(env: &Env, token: &Address, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::ComplianceHold(
+            token.clone(),
+            account.clone(),
+        )))
+        .unwrap_or(0)
+}
+
+/// Stores the amount of `token` withheld from `account`, removing the entry
+/// at 0.
+pub fn save_compliance_hold(env: &Env, token: &Address, account: &Address, amount: i128) {
+    let key = DataKey::Token(TokenKey::ComplianceHold(token.clone(), account.clone()));
+    if amount > 0 {
+        env.storage().persistent().set(&key, &amount);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the deposit cap of `token`, if one is set.
+pub fn try_load_deposit_cap(env: &Env, token: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::DepositCap(token.clone())))
+}
+
+/// Stores the deposit cap of `token`, or removes it when `None`.
+pub fn save_deposit_cap(env: &Env, token: &Address, cap: Option<i128>) {
+    let key = DataKey::Token(TokenKey::DepositCap(token.clone()));
+    match cap {
+        Some(cap) => env.storage().persistent().set(&key, &cap),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns whether `token` is frozen.
+pub fn is_token_frozen(env: &Env, token: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Token(TokenKey::Frozen(token.clone())))
+}
+
+/// Freezes or unfreezes `token`.
+pub fn set_token_frozen(env: &Env, token: &Address, frozen: bool) {
+    let key = DataKey::Token(TokenKey::Frozen(token.clone()));
+    if frozen {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ─── Token Metadata ───────────────────────────────────────────────────────────
+
+/// Returns the cached metadata of `token`, if it has been used before.
+pub fn try_load_token_info(env: &Env, token: &Address) -> Option<TokenInfo> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::Info(token.clone())))
+}
+
+/// Caches the metadata of `token`.
+pub fn save_token_info(env: &Env, token: &Address, info: &TokenInfo) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Token(TokenKey::Info(token.clone())), info);
+}
+
+// ─── Condition Oracles ────────────────────────────────────────────────────────
+
+/// Returns whether `oracle` is a registered condition oracle.
+pub fn is_condition_oracle(env: &Env, oracle: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::ConditionOracle(oracle.clone()))
+}
+
+/// Registers or deregisters `oracle` as a condition oracle.
+pub fn set_condition_oracle(env: &Env, oracle: &Address, registered: bool) {
+    let key = DataKey::ConditionOracle(oracle.clone());
+    if registered {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ─── Irrevocable Streams ──────────────────────────────────────────────────────
+
+/// Returns whether `stream_id` was created irrevocable.
+pub fn is_irrevocable(env: &Env, stream_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Irrevocable(stream_id))
+}
+
+/// Marks `stream_id` irrevocable, or clears the mark.
+pub fn set_irrevocable(env: &Env, stream_id: u64, irrevocable: bool) {
+    let key = DataKey::Irrevocable(stream_id);
+    if irrevocable {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ─── Vaults ───────────────────────────────────────────────────────────────────
+
+/// Returns `sender`'s vault balance of `token` (0 if none).
+pub fn load_vault_balance(env: &Env, sender: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Vault(sender.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores `sender`'s vault balance of `token`, removing the entry at 0.
+pub fn save_vault_balance(env: &Env, sender: &Address, token: &Address, balance: i128) {
+    let key = DataKey::Vault(sender.clone(), token.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+}
+
+// ─── Scheduled Top-Ups ────────────────────────────────────────────────────────
+
+/// Increments and returns the next scheduled top-up ID (starting at 1).
+pub fn next_scheduled_top_up_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::ScheduledTopUpCounter)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::ScheduledTopUpCounter, &id);
+    id
+}
+
+/// Returns the scheduled top-up if it is still pending, `None` otherwise.
+pub fn try_load_scheduled_top_up(env: &Env, top_up_id: u64) -> Option<ScheduledTopUp> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ScheduledTopUp(top_up_id))
+}
+
+/// Stores a scheduled top-up, or removes it once executed or cancelled.
+pub fn save_scheduled_top_up(env: &Env, top_up_id: u64, top_up: Option<&ScheduledTopUp>) {
+    let key = DataKey::ScheduledTopUp(top_up_id);
+    match top_up {
+        Some(top_up) => env.storage().persistent().set(&key, top_up),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Organizations ────────────────────────────────────────────────────────────
+
+/// Returns the owner of the organization funded by `org`, if registered.
+pub fn try_load_org_owner(env: &Env, org: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgOwner(org.clone()))
+}
+
+/// Stores the owner of the organization funded by `org`.
+pub fn save_org_owner(env: &Env, org: &Address, owner: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgOwner(org.clone()), owner);
+}
+
+/// Returns `manager`'s permissions within `org`, if it is a manager.
+pub fn try_load_org_manager(
+    env: &Env,
+    org: &Address,
+    manager: &Address,
+) -> Option<ManagerPermissions> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgManager(org.clone(), manager.clone()))
+}
+
+/// Stores `manager`'s permissions within `org`, or removes it when `None`.
+pub fn save_org_manager(
+    env: &Env,
+    org: &Address,
+    manager: &Address,
+    permissions: Option<&ManagerPermissions>,
+) {
+    let key = DataKey::OrgManager(org.clone(), manager.clone());
+    match permissions {
+        Some(permissions) => env.storage().persistent().set(&key, permissions),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Auto-Claim ───────────────────────────────────────────────────────────────
+
+/// Returns the auto-claim schedule of `stream_id`, if one is set.
+pub fn try_load_auto_claim(env: &Env, stream_id: u64) -> Option<AutoClaimSchedule> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AutoClaim(stream_id))
+}
+
+/// Stores the auto-claim schedule of `stream_id`, or removes it when `None`.
+pub fn save_auto_claim(env: &Env, stream_id: u64, schedule: Option<&AutoClaimSchedule>) {
+    let key = DataKey::AutoClaim(stream_id);
+    match schedule {
+        Some(schedule) => env.storage().persistent().set(&key, schedule),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ─── Statistics ───────────────────────────────────────────────────────────────
+
+/// Returns the lifetime streamed volume of `token` (0 if none).
+pub fn load_token_volume(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Token(TokenKey::Volume(token.clone())))
+        .unwrap_or(0)
+}
+
+/// Stores the lifetime streamed volume of `token`.
+pub fn save_token_volume(env: &Env, token: &Address, volume: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Token(TokenKey::Volume(token.clone())), &volume);
+}
+
+/// Returns the lifetime volume of `token` streamed by `sender` (0 if none).
+pub fn load_sender_volume(env: &Env, sender: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderVolume(sender.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores the lifetime volume of `token` streamed by `sender`.
+pub fn save_sender_volume(env: &Env, sender: &Address, token: &Address, volume: i128) {
+    env.storage().persistent().set(
+        &DataKey::SenderVolume(sender.clone(), token.clone()),
+        &volume,
+    );
+}
+
+// ─── Restarts ─────────────────────────────────────────────────────────────────
+
+/// Returns the continuation `stream_id` was restarted as, if any.
+pub fn try_load_restarted_as(env: &Env, stream_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RestartedAs(stream_id))
+}
+
+/// Returns the stream `stream_id` was restarted from, if any.
+pub fn try_load_restarted_from(env: &Env, stream_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RestartedFrom(stream_id))
+}
+
+/// Links `original_id` to its continuation `continuation_id` both ways.
+pub fn save_restart(env: &Env, original_id: u64, continuation_id: u64) {
+    let storage = env.storage().persistent();
+    storage.set(&DataKey::RestartedAs(original_id), &continuation_id);
+    storage.set(&DataKey::RestartedFrom(continuation_id), &original_id);
+}
+
+// ─── Recent Events ────────────────────────────────────────────────────────────
+
+/// Returns how many activity records have ever been written (0 if none).
+pub fn load_activity_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Log(LogKey::ActivityCount))
+        .unwrap_or(0)
+}
+
+/// Stores the number of activity records ever written.
+pub fn save_activity_count(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Log(LogKey::ActivityCount), &count);
+}
+
+/// Returns the activity record in ring-buffer `slot`, if written and not
+/// yet expired.
+pub fn try_load_activity_slot(env: &Env, slot: u32) -> Option<ActivityRecord> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Log(LogKey::ActivitySlot(slot)))
+}
+
+/// Overwrites ring-buffer `slot` with `record`.
+///
+/// Records are only a polling aid, so they go to temporary storage for
+/// `ACTIVITY_TTL` ledgers: cheaper rent, and the buffer stops occupying the
+/// ledger once the protocol goes quiet.
+pub fn save_activity_slot(env: &Env, slot: u32, record: &ActivityRecord) {
+    let key = DataKey::Log(LogKey::ActivitySlot(slot));
+    let storage = env.storage().temporary();
+    storage.set(&key, record);
+    storage.extend_ttl(&key, ACTIVITY_TTL, ACTIVITY_TTL);
+}
+
+// ─── Admin Log ────────────────────────────────────────────────────────────────
+
+/// Returns how many admin actions have ever been recorded (0 if none).
+pub fn load_admin_log_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Log(LogKey::AdminCount))
+        .unwrap_or(0)
+}
+
+/// Stores the number of admin actions ever recorded.
+pub fn save_admin_log_count(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Log(LogKey::AdminCount), &count);
+}
+
+/// Returns the admin record in ring-buffer `slot`, if written.
+pub fn try_load_admin_log_slot(env: &Env, slot: u32) -> Option<AdminRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Log(LogKey::AdminSlot(slot)))
+}
+
+/// Overwrites admin-log `slot` with `record`.
+pub fn save_admin_log_slot(env: &Env, slot: u32, record: &AdminRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Log(LogKey::AdminSlot(slot)), record);
+}
+
+// ─── Sender Allowlist ─────────────────────────────────────────────────────────
+
+/// Returns whether `sender` is on the permissioned-mode allowlist.
+pub fn is_allowed_sender(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AllowedSender(sender.clone()))
+}
+
+/// Adds or removes `sender` from the permissioned-mode allowlist.
+pub fn set_allowed_sender(env: &Env, sender: &Address, allowed: bool) {
+    let key = DataKey::AllowedSender(sender.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ─── Payee Allowlists ─────────────────────────────────────────────────────────
+
+/// Returns whether `org` restricts streams to its allowlisted payees.
+pub fn is_payee_allowlist_enabled(env: &Env, org: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::PayeeAllowlistEnabled(org.clone()))
+}
+
+/// Turns `org`'s payee allowlist on or off.
+pub fn set_payee_allowlist_enabled(env: &Env, org: &Address, enabled: bool) {
+    let key = DataKey::PayeeAllowlistEnabled(org.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns whether `payee` is on `org`'s payee allowlist.
+pub fn is_allowed_payee(env: &Env, org: &Address, payee: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AllowedPayee(org.clone(), payee.clone()))
+}
+
+/// Adds or removes `payee` from `org`'s payee allowlist.
+pub fn set_allowed_payee(env: &Env, org: &Address, payee: &Address, allowed: bool) {
+    let key = DataKey::AllowedPayee(org.clone(), payee.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 // ─── Protocol Config ──────────────────────────────────────────────────────────
 
 /// Checks whether the protocol config has already been initialized.
@@ -72,6 +1352,11 @@ pub fn save_config(env: &Env, config: &ProtocolConfig) {
         .set(&DataKey::ProtocolConfig, config);
 }
 
+/// Removes the protocol config, leaving the protocol without an admin.
+pub fn remove_config(env: &Env) {
+    env.storage().instance().remove(&DataKey::ProtocolConfig);
+}
+
 /// Reads the protocol config as an `Option` (returns `None` if unset).
 /// Used by optional fee-collection logic.
 pub fn try_load_config(env: &Env) -> Option<ProtocolConfig> {
@@ -0,0 +1,137 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol, Vec};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, StreamActivityEvent, SubscribersUpdatedEvent};
+use crate::storage::{load_stream, load_subscribers, save_subscribers};
+use crate::types::ActivityRecord;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+/// Maximum notification subscribers per stream.
+pub(crate) const MAX_SUBSCRIBERS: u32 = 3;
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Subscribers ──────────────────────────────────────────────────────────
+
+    /// Add `subscriber` to the addresses notified of `stream_id`'s lifecycle
+    /// events. Sender or recipient only; adding an existing subscriber is a
+    /// no-op.
+    ///
+    /// Every event kept in the recent-events buffer (creation, top-ups,
+    /// withdrawals, cancellation, settlement) is also published as a
+    /// `stream_activity` event under the subscriber's address, so a service
+    /// such as an accountant's monitor can filter on its own topic instead
+    /// of processing every protocol event.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `Unauthorized`   — caller is neither the sender nor the recipient.
+    /// - `TooManyTags`    — the stream already has `MAX_SUBSCRIBERS` subscribers.
+    pub fn add_subscriber(
+        env: Env,
+        caller: Address,
+        stream_id: u64,
+        subscriber: Address,
+    ) -> Result<(), StreamError> {
+        caller.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if caller != stream.sender && caller != stream.recipient {
+            return Err(StreamError::Unauthorized);
+        }
+        let mut subscribers = load_subscribers(&env, stream_id);
+        if subscribers.contains(&subscriber) {
+            return Ok(());
+        }
+        if subscribers.len() >= MAX_SUBSCRIBERS {
+            return Err(StreamError::TooManyTags);
+        }
+        subscribers.push_back(subscriber.clone());
+        save_subscribers(&env, stream_id, &subscribers);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "subscribers_updated",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            SubscribersUpdatedEvent {
+                stream_id,
+                subscriber,
+                subscribed: true,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop notifying `subscriber` of `stream_id`'s lifecycle events.
+    /// Callable by the sender, the recipient or the subscriber itself;
+    /// removing an address that is not subscribed is a no-op.
+    ///
+    /// # Errors
+    /// - `StreamNotFound` — no stream exists with `stream_id`.
+    /// - `Unauthorized`   — caller is not a party to the stream or `subscriber`.
+    pub fn remove_subscriber(
+        env: Env,
+        caller: Address,
+        stream_id: u64,
+        subscriber: Address,
+    ) -> Result<(), StreamError> {
+        caller.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if caller != stream.sender && caller != stream.recipient && caller != subscriber {
+            return Err(StreamError::Unauthorized);
+        }
+        let mut subscribers = load_subscribers(&env, stream_id);
+        let Some(index) = subscribers.first_index_of(&subscriber) else {
+            return Ok(());
+        };
+        subscribers.remove(index);
+        save_subscribers(&env, stream_id, &subscribers);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "subscribers_updated",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            SubscribersUpdatedEvent {
+                stream_id,
+                subscriber,
+                subscribed: false,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the addresses notified of `stream_id`'s lifecycle events.
+    pub fn get_subscribers(env: Env, stream_id: u64) -> Vec<Address> {
+        load_subscribers(&env, stream_id)
+    }
+}
+
+impl StreamContract {
+    /// Publishes `record` to each of its stream's subscribers.
+    pub(crate) fn notify_subscribers(env: &Env, record: &ActivityRecord) {
+        for subscriber in load_subscribers(env, record.stream_id).iter() {
+            env.events().publish(
+                (
+                    Symbol::new(env, "stream_activity"),
+                    subscriber,
+                    record.stream_id,
+                ),
+                StreamActivityEvent {
+                    stream_id: record.stream_id,
+                    kind: record.kind,
+                    amount: record.amount,
+                    timestamp: record.timestamp,
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::errors::StreamError;
+use crate::events::{stream_topics, TokensWithdrawnEvent, WindDownEvent};
+use crate::storage::{is_wound_down, load_stream, remove_config, save_stream, set_wound_down};
+use crate::types::ActivityKind;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Wind-Down ────────────────────────────────────────────────────────────
+
+    /// Start winding the protocol down. Admin-only and irreversible.
+    ///
+    /// From now on no streams can be created or topped up, and recipients
+    /// may settle through `sunset_withdraw`, which no operator setting can
+    /// block. Senders reclaim their remainders with `cancel_stream` as
+    /// usual. Existing streams keep streaming on their own terms.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn enter_wind_down(env: Env, admin: Address) -> Result<(), StreamError> {
+        Self::require_admin(&env, &admin, "enter_wind_down")?;
+        Self::wind_down(&env, None);
+        Ok(())
+    }
+
+    /// Give up the admin role for good. Admin-only.
+    ///
+    /// Removes the protocol config, so no admin operation succeeds again,
+    /// new deposits and withdrawals pay no protocol fee and `initialize`
+    /// stays closed. Fees accrued until now can still be claimed to the
+    /// last treasury with `claim_fees`. With
+    /// nobody left to operate it, the protocol winds down exactly as after
+    /// `enter_wind_down`.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — `initialize` has not been called.
+    /// - `NotAdmin`       — caller is not the current admin.
+    pub fn renounce_admin(env: Env, admin: Address) -> Result<(), StreamError> {
+        let config = Self::require_admin(&env, &admin, "renounce_admin")?;
+        remove_config(&env);
+        Self::wind_down(&env, Some(&config.treasury));
+        Ok(())
+    }
+
+    /// Returns whether the protocol is winding down.
+    pub fn is_wound_down(env: Env) -> bool {
+        is_wound_down(&env)
+    }
+
+    /// Withdraw everything accrued on a stream while the protocol winds
+    /// down. Recipient-only.
+    ///
+    /// Unlike `withdraw`, this ignores every operator control: token
+    /// freezes, pause switches, the minimum claim and the withdrawal fee.
+    /// It also ignores the stream's withdrawal cap and cooldown, so the
+    /// whole accrued amount is paid in one call. A clawback window still
+    /// applies, and the payout split and payout address are honored.
+    ///
+    /// Returns the amount withdrawn.
+    ///
+    /// # Errors
+    /// - `InvalidTransition` — the protocol is not winding down.
+    /// - `StreamNotFound`    — no stream exists with `stream_id`.
+    /// - `NotRecipient`      — caller is not the stream's recipient.
+    /// - `StreamInactive`    — stream has been cancelled or fully withdrawn.
+    /// - `InvalidAmount`     — nothing has accrued.
+    pub fn sunset_withdraw(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, StreamError> {
+        recipient.require_auth();
+        if !is_wound_down(&env) {
+            return Err(StreamError::InvalidTransition);
+        }
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if stream.recipient != recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+
+        let now = env.ledger().timestamp();
+        let accrued = Self::calculate_claimable(&stream, now);
+        if accrued <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+
+        let destination = Self::payout_destination(&stream);
+        let split_amount =
+            Self::transfer_and_update_stream(&env, &mut stream, &destination, accrued, 0, now)?;
+        Self::settle_yield(&env, &mut stream, stream_id);
+        save_stream(&env, stream_id, &stream);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "tokens_withdrawn",
+                stream_id,
+                &stream.sender,
+                &stream.recipient,
+            ),
+            TokensWithdrawnEvent {
+                stream_id,
+                recipient,
+                destination,
+                amount: accrued,
+                timestamp: now,
+                split_address: stream.split_address.clone(),
+                split_amount,
+                withdrawal_fee: 0,
+                total_withdrawn: stream.withdrawn_amount,
+                remaining_deposit: stream.deposited_amount - stream.withdrawn_amount,
+                status: stream.status,
+            },
+        );
+        Self::record_activity(&env, ActivityKind::Withdrawn, stream_id, accrued);
+        Ok(accrued)
+    }
+}
+
+impl StreamContract {
+    /// Marks the protocol as winding down and announces it.
+    fn wind_down(env: &Env, retired_treasury: Option<&Address>) {
+        set_wound_down(env, retired_treasury);
+        env.events().publish(
+            (Symbol::new(env, "wind_down"),),
+            WindDownEvent {
+                renounced: retired_treasury.is_some(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+}
@@ -4,16 +4,23 @@ extern crate std;
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Events, Ledger},
-    token, xdr, Address, Env, Symbol, TryFromVal,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contracttype,
+    testutils::{Address as _, AuthorizedFunction, Events, Ledger},
+    token, vec, xdr, Address, Bytes, BytesN, Env, Symbol, TryFromVal, Vec,
 };
 
 use errors::StreamError;
 use events::{
-    FeeCollectedEvent, StreamCancelledEvent, StreamCreatedEvent, StreamToppedUpEvent,
-    TokensWithdrawnEvent,
+    FeeCollectedEvent, StreamActivityEvent, StreamCancelledEvent, StreamCreatedEvent,
+    StreamDepletingEvent, StreamExtendedEvent, StreamToppedUpEvent, StreamTransferredEvent,
+    TokensWithdrawnEvent, TopUpRequestedEvent, TtlLowEvent,
+};
+use types::{
+    ActivityKind, ApprovalPolicy, ApprovalTier, DataKey, ExactRate, FeeKind, ManagerPermissions,
+    PauseFlags, Stream, StreamCategory, StreamCondition, StreamOptions, StreamStatement,
+    StreamStatus, StreamV1, TokenKey, YieldPolicy,
 };
-use types::{DataKey, Stream};
 
 // ─── Test Helpers ─────────────────────────────────────────────────────────────
 
@@ -36,6 +43,209 @@ fn mint(env: &Env, token_address: &Address, recipient: &Address, amount: i128) {
     asset.mint(recipient, &amount);
 }
 
+/// Storage keys of the mock contracts below, typed like the contract's own
+/// `DataKey` so no mock relies on ad-hoc tuple or symbol keys.
+#[contracttype]
+enum MockKey {
+    Alias(Address, Symbol),
+    Price,
+    Attested(Address),
+    Blocked(Address),
+    Condition(Symbol),
+    Delegation(u64),
+}
+
+/// Minimal stand-in for the `alias_registry` contract's `resolve` entrypoint.
+#[contract]
+struct MockAliasRegistry;
+
+#[contractimpl]
+impl MockAliasRegistry {
+    pub fn set_alias(env: Env, org: Address, label: Symbol, address: Address) {
+        env.storage()
+            .persistent()
+            .set(&MockKey::Alias(org, label), &address);
+    }
+
+    pub fn resolve(env: Env, org: Address, label: Symbol) -> Option<Address> {
+        env.storage().persistent().get(&MockKey::Alias(org, label))
+    }
+}
+
+/// Price oracle stand-in returning a settable price for every token.
+#[contract]
+struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, price: i128) {
+        env.storage().instance().set(&MockKey::Price, &price);
+    }
+
+    pub fn get_price(env: Env, _token: Address) -> i128 {
+        env.storage().instance().get(&MockKey::Price).unwrap()
+    }
+}
+
+/// Compliance oracle stand-in that rejects transfers to blocked accounts.
+#[contract]
+struct MockComplianceOracle;
+
+#[contractimpl]
+impl MockComplianceOracle {
+    pub fn set_blocked(env: Env, account: Address, blocked: bool) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Blocked(account), &blocked);
+    }
+
+    pub fn check_transfer(env: Env, _token: Address, to: Address, _amount: i128) -> bool {
+        !env.storage()
+            .instance()
+            .get(&MockKey::Blocked(to))
+            .unwrap_or(false)
+    }
+}
+
+/// Attestation registry stand-in with a settable attested flag per account.
+#[contract]
+struct MockAttestationRegistry;
+
+#[contractimpl]
+impl MockAttestationRegistry {
+    pub fn set_attested(env: Env, account: Address, attested: bool) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Attested(account), &attested);
+    }
+
+    pub fn is_attested(env: Env, account: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&MockKey::Attested(account))
+            .unwrap_or(false)
+    }
+}
+
+#[contract]
+struct MockConditionOracle;
+
+#[contractimpl]
+impl MockConditionOracle {
+    pub fn set_condition_met(env: Env, condition: Symbol, met: bool) {
+        env.storage()
+            .instance()
+            .set(&MockKey::Condition(condition), &met);
+    }
+
+    pub fn is_condition_met(env: Env, condition: Symbol) -> bool {
+        env.storage()
+            .instance()
+            .get(&MockKey::Condition(condition))
+            .unwrap_or(false)
+    }
+}
+
+#[contract]
+struct MockGovernance;
+
+#[contractimpl]
+impl MockGovernance {
+    pub fn set_delegation(
+        env: Env,
+        delegator: Address,
+        delegation_id: u64,
+        delegatee: Address,
+        amount: i128,
+    ) {
+        delegator.require_auth();
+        env.storage()
+            .instance()
+            .set(&MockKey::Delegation(delegation_id), &(delegatee, amount));
+    }
+
+    pub fn delegated(env: Env, delegation_id: u64) -> i128 {
+        env.storage()
+            .instance()
+            .get::<_, (Address, i128)>(&MockKey::Delegation(delegation_id))
+            .map_or(0, |(_, amount)| amount)
+    }
+}
+
+/// DAO-style treasury contract acting as a stream sender. Each call
+/// pre-authorizes the token pull the stream contract makes on its behalf.
+#[contract]
+struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn open_stream(
+        env: Env,
+        streams: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+        duration: u64,
+    ) -> u64 {
+        Self::authorize_pull(&env, &streams, &token, amount);
+        StreamContractClient::new(&env, &streams).create_stream(
+            &env.current_contract_address(),
+            &recipient,
+            &token,
+            &amount,
+            &duration,
+        )
+    }
+
+    pub fn open_stream_unauthorized(
+        env: Env,
+        streams: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+        duration: u64,
+    ) -> u64 {
+        StreamContractClient::new(&env, &streams).create_stream(
+            &env.current_contract_address(),
+            &recipient,
+            &token,
+            &amount,
+            &duration,
+        )
+    }
+
+    pub fn top_up(env: Env, streams: Address, token: Address, stream_id: u64, amount: i128) {
+        Self::authorize_pull(&env, &streams, &token, amount);
+        StreamContractClient::new(&env, &streams).top_up_stream(
+            &env.current_contract_address(),
+            &stream_id,
+            &amount,
+        );
+    }
+
+    pub fn cancel(env: Env, streams: Address, stream_id: u64) {
+        StreamContractClient::new(&env, &streams)
+            .cancel_stream(&env.current_contract_address(), &stream_id);
+    }
+}
+
+impl MockTreasury {
+    fn authorize_pull(env: &Env, streams: &Address, token: &Address, amount: i128) {
+        let this = env.current_contract_address();
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (this, streams.clone(), amount).into_val(env),
+                },
+                sub_invocations: vec![env],
+            }),
+        ]);
+    }
+}
+
 // ─── DataKey Serialization ────────────────────────────────────────────────────
 
 #[test]
@@ -62,12 +272,48 @@ fn test_datakey_stream_serializes_deterministically() {
         sender: Address::generate(&env),
         recipient: Address::generate(&env),
         token_address: Address::generate(&env),
-        rate_per_second: 100,
+        rate: ExactRate {
+            numerator: 100,
+            denominator: 1,
+            anchor: 1,
+            cliff: 0,
+        },
         deposited_amount: 1_000,
         withdrawn_amount: 0,
         start_time: 1,
         last_update_time: 1,
-        is_active: true,
+        status: StreamStatus::Active,
+        fee_rate_bps: 0,
+        withdrawal_cooldown: 0,
+        last_withdrawal_time: 1,
+        settled_balance: 0,
+        withdrawal_cap: 0,
+        withdrawal_cap_period: 0,
+        cap_window_start: 1,
+        cap_window_withdrawn: 0,
+        tags: Vec::new(&env),
+        end_time: 101,
+        bonus_amount: 0,
+        bonus_claimed: false,
+        clawback_window: 0,
+        held_amount: 0,
+        held_until: 0,
+        hold_contested: false,
+        split_address: None,
+        split_bps: 0,
+        payout_address: None,
+        beneficiary: None,
+        beneficiary_inactivity_period: 0,
+        cancel_at: 0,
+        category: StreamCategory::General,
+        partner: None,
+        live_until_ledger: 0,
+        withdrawal_fee_bps: 0,
+        relayer_fee: 0,
+        token_decimals: 7,
+        yield_policy: YieldPolicy::Sender,
+        pending_yield: 0,
+        condition: StreamCondition::Unconditional,
     };
     env.as_contract(&contract_id, || {
         env.storage().persistent().set(&key, &stream);
@@ -84,6 +330,36 @@ fn test_datakey_stream_counter_serializes_deterministically() {
     assert_eq!(scval_a, scval_b);
 }
 
+#[test]
+fn test_datakey_namespaces_never_collide() {
+    let env = Env::default();
+    let token = Address::generate(&env);
+    let keys = [
+        DataKey::Stream(1),
+        DataKey::StreamTerms(1),
+        DataKey::Receipt(1),
+        DataKey::Token(TokenKey::Frozen(token.clone())),
+        DataKey::Token(TokenKey::Info(token.clone())),
+        DataKey::AllowedSender(token.clone()),
+    ];
+    let encoded: std::vec::Vec<xdr::ScVal> =
+        keys.iter().map(|key| key.try_into().unwrap()).collect();
+    for (i, a) in encoded.iter().enumerate() {
+        for b in &encoded[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    // Nested keys carry their namespace as the outer symbol.
+    let expected: xdr::ScVal = (
+        &Symbol::new(&env, "Token"),
+        (Symbol::new(&env, "Info"), token.clone()),
+    )
+        .try_into()
+        .unwrap();
+    assert_eq!(encoded[4], expected);
+}
+
 // ─── Protocol Initialization ──────────────────────────────────────────────────
 
 #[test]
@@ -178,6 +454,190 @@ fn test_update_fee_config_rejects_invalid_fee_rate() {
     assert_eq!(result, Err(Ok(StreamError::InvalidFeeRate)));
 }
 
+#[test]
+fn test_permissioned_mode_restricts_creation_to_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury_sender = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    mint(&env, &token, &treasury_sender, 1_000);
+    mint(&env, &token, &outsider, 1_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    assert!(client.is_sender_allowed(&outsider));
+
+    client.set_sender_allowlist_enabled(&admin, &true);
+    client.set_sender_allowed(&admin, &treasury_sender, &true);
+    assert!(client.is_sender_allowed(&treasury_sender));
+    assert!(!client.is_sender_allowed(&outsider));
+
+    client.create_stream(
+        &treasury_sender,
+        &Address::generate(&env),
+        &token,
+        &500,
+        &100,
+    );
+    assert_eq!(
+        client.try_create_stream(&outsider, &Address::generate(&env), &token, &500, &100),
+        Err(Ok(StreamError::SenderNotAllowed))
+    );
+
+    // Removing a sender or disabling the mode takes effect immediately.
+    client.set_sender_allowed(&admin, &treasury_sender, &false);
+    assert_eq!(
+        client.try_create_stream(
+            &treasury_sender,
+            &Address::generate(&env),
+            &token,
+            &500,
+            &100
+        ),
+        Err(Ok(StreamError::SenderNotAllowed))
+    );
+    client.set_sender_allowlist_enabled(&admin, &false);
+    client.create_stream(&outsider, &Address::generate(&env), &token, &500, &100);
+}
+
+#[test]
+fn test_allowlist_management_is_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+
+    assert_eq!(
+        client.try_set_sender_allowlist_enabled(&attacker, &true),
+        Err(Ok(StreamError::NotAdmin))
+    );
+    assert_eq!(
+        client.try_set_sender_allowed(&attacker, &attacker, &true),
+        Err(Ok(StreamError::NotAdmin))
+    );
+}
+
+#[test]
+fn test_attestation_registry_gates_both_parties() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let registry_id = env.register(MockAttestationRegistry, ());
+    let registry = MockAttestationRegistryClient::new(&env, &registry_id);
+    client.set_attestation_registry(&admin, &Some(registry_id.clone()));
+
+    registry.set_attested(&sender, &true);
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &500, &100),
+        Err(Ok(StreamError::AttestationMissing))
+    );
+
+    registry.set_attested(&recipient, &true);
+    client.create_stream(&sender, &recipient, &token, &500, &100);
+
+    registry.set_attested(&sender, &false);
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &500, &100),
+        Err(Ok(StreamError::AttestationMissing))
+    );
+
+    // Clearing the registry turns gating off.
+    client.set_attestation_registry(&admin, &None);
+    client.create_stream(&sender, &recipient, &token, &500, &100);
+}
+
+#[test]
+fn test_compliance_oracle_holds_rejected_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let token_client = token::Client::new(&env, &token);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let oracle_id = env.register(MockComplianceOracle, ());
+    let oracle = MockComplianceOracleClient::new(&env, &oracle_id);
+    client.set_compliance_oracle(&admin, &Some(oracle_id));
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    oracle.set_blocked(&recipient, &true);
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    assert_eq!(client.withdraw(&recipient, &id), 300);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_compliance_hold(&token, &recipient), 300);
+    assert_eq!(client.get_accounted_balance(&token), 1_000);
+    assert_eq!(
+        client.try_release_compliance_hold(&token, &recipient),
+        Err(Ok(StreamError::Unauthorized))
+    );
+
+    // A blocked sender's refund is withheld the same way.
+    oracle.set_blocked(&recipient, &false);
+    oracle.set_blocked(&sender, &true);
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&recipient), 200);
+    assert_eq!(client.get_compliance_hold(&token, &sender), 500);
+
+    assert_eq!(client.release_compliance_hold(&token, &recipient), 300);
+    assert_eq!(token_client.balance(&recipient), 500);
+    assert_eq!(client.get_compliance_hold(&token, &recipient), 0);
+
+    // Turning screening off releases everything still withheld.
+    client.set_compliance_oracle(&admin, &None);
+    assert_eq!(client.release_compliance_hold(&token, &sender), 500);
+    assert_eq!(token_client.balance(&sender), 500);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+#[test]
+fn test_wind_down_payouts_bypass_compliance_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let token_client = token::Client::new(&env, &token);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let oracle_id = env.register(MockComplianceOracle, ());
+    let oracle = MockComplianceOracleClient::new(&env, &oracle_id);
+    client.set_compliance_oracle(&admin, &Some(oracle_id));
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    oracle.set_blocked(&recipient, &true);
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &id);
+    assert_eq!(client.get_compliance_hold(&token, &recipient), 300);
+
+    // After wind-down the oracle no longer decides: the sunset payout goes
+    // straight through, and earlier holds can be released.
+    client.enter_wind_down(&admin);
+    env.ledger().with_mut(|li| li.timestamp = 70);
+    assert_eq!(client.sunset_withdraw(&recipient, &id), 400);
+    assert_eq!(token_client.balance(&recipient), 400);
+    assert_eq!(client.release_compliance_hold(&token, &recipient), 300);
+    assert_eq!(token_client.balance(&recipient), 700);
+}
+
 // ─── create_stream ────────────────────────────────────────────────────────────
 
 #[test]
@@ -197,10 +657,10 @@ fn test_create_stream_persists_state() {
     assert_eq!(s.sender, sender);
     assert_eq!(s.recipient, recipient);
     assert_eq!(s.token_address, token);
-    assert_eq!(s.rate_per_second, 5); // 500 / 100
+    assert_eq!(s.rate_per_second(), 5); // 500 / 100
     assert_eq!(s.deposited_amount, 500);
     assert_eq!(s.withdrawn_amount, 0);
-    assert!(s.is_active);
+    assert!(s.is_active());
 }
 
 #[test]
@@ -265,6 +725,25 @@ fn test_create_stream_rejects_zero_duration() {
     assert_eq!(result, Err(Ok(StreamError::InvalidDuration)));
 }
 
+#[test]
+fn test_create_stream_rejects_zero_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let result = client.try_create_stream(&sender, &Address::generate(&env), &token, &99, &100);
+    assert_eq!(result, Err(Ok(StreamError::RateTooLow)));
+
+    // 100 tokens over 100 s clears the bar, but not once a 1% fee is taken.
+    client.initialize(&Address::generate(&env), &Address::generate(&env), &100);
+    let result = client.try_create_stream(&sender, &Address::generate(&env), &token, &100, &100);
+    assert_eq!(result, Err(Ok(StreamError::RateTooLow)));
+    assert_eq!(token::Client::new(&env, &token).balance(&sender), 1_000);
+}
+
 #[test]
 fn test_create_stream_rejects_invalid_token_address() {
     let env = Env::default();
@@ -312,6 +791,39 @@ fn test_create_stream_emits_event() {
     assert_eq!(payload.rate_per_second, 5);
 }
 
+#[test]
+fn test_create_stream_to_alias_resolves_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let org = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let registry_id = env.register(MockAliasRegistry, ());
+    let registry = MockAliasRegistryClient::new(&env, &registry_id);
+    let label = Symbol::new(&env, "alice");
+    registry.set_alias(&org, &label, &alice);
+
+    let client = create_contract(&env);
+    let id = client.create_stream_to_alias(&sender, &registry_id, &org, &label, &token, &500, &100);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, alice);
+
+    assert_eq!(
+        client.try_create_stream_to_alias(
+            &sender,
+            &registry_id,
+            &org,
+            &Symbol::new(&env, "mallory"),
+            &token,
+            &500,
+            &100
+        ),
+        Err(Ok(StreamError::AliasNotFound))
+    );
+}
+
 // ─── top_up_stream ────────────────────────────────────────────────────────────
 
 #[test]
@@ -390,7 +902,7 @@ fn test_top_up_rejects_unauthorized_sender() {
 
     assert_eq!(
         client.try_top_up_stream(&attacker, &id, &1_000),
-        Err(Ok(StreamError::Unauthorized))
+        Err(Ok(StreamError::NotSender))
     );
 }
 
@@ -468,7 +980,94 @@ fn test_withdraw_transfers_tokens_to_recipient() {
 
     let s = client.get_stream(&id).unwrap();
     assert_eq!(s.withdrawn_amount, 500);
-    assert!(!s.is_active); // fully drained
+    assert!(!s.is_active()); // fully drained
+}
+
+#[test]
+fn test_withdraw_amount_takes_only_the_requested_part() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &500, &100);
+    env.ledger().with_mut(|l| l.timestamp += 40);
+
+    for amount in [0, 201] {
+        assert_eq!(
+            client.try_withdraw_amount(&recipient, &id, &amount),
+            Err(Ok(StreamError::InvalidAmount))
+        );
+    }
+    assert_eq!(client.withdraw_amount(&recipient, &id, &150), 150);
+    assert_eq!(token_client.balance(&recipient), 150);
+    assert_eq!(client.get_claimable_amount(&id), Some(50));
+
+    // The remainder keeps accruing and is still claimable in full.
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    assert_eq!(client.withdraw(&recipient, &id), 350);
+    assert!(!client.get_stream(&id).unwrap().is_active());
+}
+
+#[test]
+fn test_withdraw_to_routes_funds_to_destination() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &500, &100);
+    env.ledger().with_mut(|l| l.timestamp += 40);
+
+    assert_eq!(
+        client.try_withdraw_to(&Address::generate(&env), &id, &cold_wallet),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    assert_eq!(client.withdraw_to(&recipient, &id, &cold_wallet), 200);
+    let withdrawn = env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "tokens_withdrawn")
+        })
+        .unwrap();
+    let event = TokensWithdrawnEvent::try_from_val(&env, &withdrawn.2).unwrap();
+    assert_eq!(event.recipient, recipient);
+    assert_eq!(event.destination, cold_wallet);
+    assert_eq!(token_client.balance(&cold_wallet), 200);
+    assert_eq!(token_client.balance(&recipient), 0);
+
+    // Held withdrawals are released to the recipient's usual destination,
+    // so a clawback stream refuses a one-off `to`.
+    mint(&env, &token, &sender, 1_000);
+    let held = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &StreamOptions {
+            clawback_window: 3_600,
+            ..StreamOptions::new(&env)
+        },
+    );
+    env.ledger().with_mut(|l| l.timestamp += 40);
+    assert_eq!(
+        client.try_withdraw_to(&recipient, &held, &cold_wallet),
+        Err(Ok(StreamError::ClawbackWindowOpen))
+    );
+    assert_eq!(client.get_stream(&held).unwrap().held_amount, 0);
 }
 
 #[test]
@@ -485,7 +1084,7 @@ fn test_withdraw_rejects_non_recipient() {
 
     assert_eq!(
         client.try_withdraw(&attacker, &id),
-        Err(Ok(StreamError::Unauthorized))
+        Err(Ok(StreamError::NotRecipient))
     );
 }
 
@@ -552,83 +1151,121 @@ fn test_withdraw_emits_event() {
     assert_eq!(payload.stream_id, id);
     assert_eq!(payload.recipient, recipient);
     assert_eq!(payload.amount, 500);
+    assert_eq!(payload.total_withdrawn, 500);
+    assert_eq!(payload.remaining_deposit, 0);
+    assert_eq!(payload.status, StreamStatus::Completed);
 }
 
-// ─── cancel_stream ────────────────────────────────────────────────────────────
-
 #[test]
-fn test_cancel_stream_refunds_unspent_balance() {
+fn test_withdraw_respects_cooldown() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
 
     let client = create_contract(&env);
-    let token_client = token::Client::new(&env, &token);
+    let options = StreamOptions {
+        withdrawal_cooldown: 50,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
+    let start = env.ledger().timestamp();
+    assert_eq!(client.get_next_withdrawal_time(&id), Some(start + 50));
 
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
-    let sender_balance_before = token_client.balance(&sender);
+    env.ledger().with_mut(|l| l.timestamp += 49);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::CooldownActive))
+    );
 
-    client.cancel_stream(&sender, &id);
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(client.withdraw(&recipient, &id), 50);
+    assert_eq!(client.get_next_withdrawal_time(&id), Some(start + 100));
 
-    // Full 500 should be refunded since nothing was withdrawn.
-    assert_eq!(token_client.balance(&sender) - sender_balance_before, 500);
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::CooldownActive))
+    );
 
-    let s = client.get_stream(&id).unwrap();
-    assert!(!s.is_active);
+    // Accrual continues during the cooldown and is paid out in one claim.
+    env.ledger().with_mut(|l| l.timestamp += 40);
+    assert_eq!(client.withdraw(&recipient, &id), 50);
 }
 
 #[test]
-fn test_cancel_stream_rejects_non_sender() {
+fn test_stream_without_cooldown_is_always_withdrawable() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
-    let attacker = Address::generate(&env);
+    let recipient = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
 
     let client = create_contract(&env);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
-
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
     assert_eq!(
-        client.try_cancel_stream(&attacker, &id),
-        Err(Ok(StreamError::Unauthorized))
+        client.get_next_withdrawal_time(&id),
+        Some(env.ledger().timestamp())
     );
-}
-
-#[test]
-fn test_cancel_stream_rejects_missing_stream() {
-    let env = Env::default();
-    env.mock_all_auths();
-    let client = create_contract(&env);
 
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(client.withdraw(&recipient, &id), 1);
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(client.withdraw(&recipient, &id), 1);
     assert_eq!(
-        client.try_cancel_stream(&Address::generate(&env), &999),
-        Err(Ok(StreamError::StreamNotFound))
+        client.get_next_withdrawal_time(&id),
+        Some(env.ledger().timestamp())
     );
 }
 
 #[test]
-fn test_cancel_stream_rejects_already_inactive() {
+fn test_withdraw_limited_by_period_cap() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
 
     let client = create_contract(&env);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
-    client.cancel_stream(&sender, &id);
+    // 1 token/sec, at most 100 tokens per 300-second window.
+    let options = StreamOptions {
+        withdrawal_cap: 100,
+        withdrawal_cap_period: 300,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
 
+    env.ledger().with_mut(|l| l.timestamp += 250);
+    assert_eq!(client.get_claimable_amount(&id), Some(250));
+    assert_eq!(client.get_withdrawable_amount(&id), Some(100));
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+
+    // Window exhausted, but the excess accrual is preserved.
+    env.ledger().with_mut(|l| l.timestamp += 10);
     assert_eq!(
-        client.try_cancel_stream(&sender, &id),
-        Err(Ok(StreamError::StreamInactive))
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::WithdrawalCapReached))
     );
+    assert_eq!(client.get_claimable_amount(&id), Some(160));
+
+    // Window [0, 300) has expired; a new one opens on the next claim.
+    env.ledger().with_mut(|l| l.timestamp += 40);
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.withdrawn_amount, 200);
+    assert_eq!(s.settled_balance, 100);
+    assert_eq!(s.cap_window_start, env.ledger().timestamp());
 }
 
 #[test]
-fn test_cancel_stream_emits_event_with_refund_amount() {
+fn test_cancel_pays_accrual_held_back_by_cap() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
@@ -637,310 +1274,5171 @@ fn test_cancel_stream_emits_event_with_refund_amount() {
     mint(&env, &token, &sender, 1_000);
 
     let client = create_contract(&env);
-    let id = client.create_stream(&sender, &recipient, &token, &500, &100);
-    client.cancel_stream(&sender, &id);
+    let token_client = token::Client::new(&env, &token);
+    let options = StreamOptions {
+        withdrawal_cap: 100,
+        withdrawal_cap_period: 1_000,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
 
-    let events = env.events().all();
-    let ev = events
-        .iter()
-        .find(|e| {
-            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
-                == Symbol::new(&env, "stream_cancelled")
-        })
-        .expect("stream_cancelled event not found");
+    env.ledger().with_mut(|l| l.timestamp += 400);
+    client.withdraw(&recipient, &id);
+    client.cancel_stream(&sender, &id);
 
-    let payload: StreamCancelledEvent = StreamCancelledEvent::try_from_val(&env, &ev.2).unwrap();
-    assert_eq!(payload.stream_id, id);
-    assert_eq!(payload.sender, sender);
-    assert_eq!(payload.recipient, recipient);
-    assert_eq!(payload.amount_withdrawn, 0);
-    assert_eq!(payload.refunded_amount, 500);
+    // Recipient gets all 400 accrued; sender gets the remaining 600.
+    assert_eq!(token_client.balance(&recipient), 400);
+    assert_eq!(token_client.balance(&sender), 600);
 }
 
-// ─── Protocol Fee Integration ─────────────────────────────────────────────────
-
 #[test]
-fn test_create_stream_with_fee_deduction() {
+fn test_create_stream_rejects_invalid_cap() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
-    let treasury = Address::generate(&env);
-    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
-
     let client = create_contract(&env);
-    let token_client = token::Client::new(&env, &token);
 
-    // 2% fee (200 bps). Gross: 500, fee: 10, net: 490.
-    client.initialize(&admin, &treasury, &200);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+    let negative = StreamOptions {
+        withdrawal_cap: -1,
+        withdrawal_cap_period: 100,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(&sender, &recipient, &token, &500, &100, &negative),
+        Err(Ok(StreamError::InvalidAmount))
+    );
 
-    assert_eq!(token_client.balance(&treasury), 10);
-    let s = client.get_stream(&id).unwrap();
-    assert_eq!(s.deposited_amount, 490);
-    assert_eq!(s.rate_per_second, 4); // 490 / 100 = 4 (integer division)
+    let no_period = StreamOptions {
+        withdrawal_cap: 100,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(&sender, &recipient, &token, &500, &100, &no_period),
+        Err(Ok(StreamError::InvalidDuration))
+    );
 }
 
 #[test]
-fn test_top_up_with_fee_deduction() {
+fn test_get_streams_by_tag_groups_sender_streams() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
-    let treasury = Address::generate(&env);
-    let admin = Address::generate(&env);
-    mint(&env, &token, &sender, 2_000);
+    let other_sender = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    mint(&env, &token, &other_sender, 10_000);
 
     let client = create_contract(&env);
-    let token_client = token::Client::new(&env, &token);
+    let payroll = Symbol::new(&env, "payroll");
+    let grant = Symbol::new(&env, "grant");
 
-    // 1% fee (100 bps). Create: gross 1 000, fee 10, net 990.
-    client.initialize(&admin, &treasury, &100);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
-    assert_eq!(token_client.balance(&treasury), 10);
+    let tagged = |tags: Vec<Symbol>| StreamOptions {
+        tags,
+        ..StreamOptions::new(&env)
+    };
+    let recipient = Address::generate(&env);
+    let id1 = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &500,
+        &100,
+        &tagged(vec![&env, payroll.clone()]),
+    );
+    let id2 = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &500,
+        &100,
+        &tagged(vec![&env, grant.clone(), payroll.clone(), grant.clone()]),
+    );
+    client.create_stream(&sender, &recipient, &token, &500, &100);
+    client.create_stream_with_options(
+        &other_sender,
+        &recipient,
+        &token,
+        &500,
+        &100,
+        &tagged(vec![&env, payroll.clone()]),
+    );
 
-    // Top up: gross 500, fee 5, net 495. Treasury total: 15.
-    client.top_up_stream(&sender, &id, &500);
-    assert_eq!(token_client.balance(&treasury), 15);
+    assert_eq!(
+        client.get_streams_by_tag(&sender, &payroll, &0, &10),
+        vec![&env, id1, id2]
+    );
+    assert_eq!(
+        client.get_streams_by_tag(&sender, &grant, &0, &10),
+        vec![&env, id2]
+    );
+    assert_eq!(
+        client.get_streams_by_tag(&sender, &payroll, &1, &1),
+        vec![&env, id2]
+    );
+    assert_eq!(
+        client.get_streams_by_tag(&sender, &payroll, &5, &10).len(),
+        0
+    );
 
-    let s = client.get_stream(&id).unwrap();
-    assert_eq!(s.deposited_amount, 990 + 495);
+    // Duplicate tags are stored once.
+    assert_eq!(
+        client.get_stream(&id2).unwrap().tags,
+        vec![&env, grant, payroll]
+    );
 }
 
 #[test]
-fn test_fee_collected_event_emitted_on_create() {
+fn test_create_stream_rejects_too_many_tags() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
-    let treasury = Address::generate(&env);
-    let admin = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
-
     let client = create_contract(&env);
 
-    // 5% fee (500 bps). Gross: 1 000, fee: 50.
-    client.initialize(&admin, &treasury, &500);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
-
-    let events = env.events().all();
-    let ev = events
-        .iter()
-        .find(|e| {
-            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
-                == Symbol::new(&env, "fee_collected")
-        })
-        .expect("fee_collected event not found");
-
-    let payload: FeeCollectedEvent = FeeCollectedEvent::try_from_val(&env, &ev.2).unwrap();
-    assert_eq!(payload.stream_id, id);
-    assert_eq!(payload.treasury, treasury);
-    assert_eq!(payload.fee_amount, 50);
+    let mut tags = Vec::new(&env);
+    for name in ["a", "b", "c", "d", "e", "f"] {
+        tags.push_back(Symbol::new(&env, name));
+    }
+    let options = StreamOptions {
+        tags,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(
+            &sender,
+            &Address::generate(&env),
+            &token,
+            &500,
+            &100,
+            &options
+        ),
+        Err(Ok(StreamError::TooManyTags))
+    );
 }
 
 #[test]
-fn test_no_fee_event_when_fee_rate_is_zero() {
+fn test_create_stream_detailed_returns_persisted_stream() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
     let admin = Address::generate(&env);
-    let treasury = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
 
     let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &100); // 1%
+
+    let (id, stream) = client.create_stream_detailed(&sender, &recipient, &token, &1_000, &99);
+    assert_eq!(stream, client.get_stream(&id).unwrap());
+    assert_eq!(stream.deposited_amount, 990);
+    assert_eq!(stream.rate_per_second(), 10);
+    assert_eq!(stream.fee_rate_bps, 100);
+    assert_eq!(stream.end_time, stream.start_time + 99);
+}
 
-    // 0 bps fee — no fee_collected event must be emitted.
-    client.initialize(&admin, &treasury, &0);
-    client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+#[test]
+fn test_salted_creation_is_idempotent() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
 
-    let events = env.events().all();
-    let fee_event = events.iter().find(|e| {
-        Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
-            == Symbol::new(&env, "fee_collected")
-    });
-    assert!(
-        fee_event.is_none(),
-        "fee_collected must not fire when fee rate is 0"
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    let predicted = client.get_salted_stream_id(&sender, &salt);
+    let id = client.create_stream_with_salt(&sender, &recipient, &token, &1_000, &100, &salt);
+    assert_eq!(id, predicted);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_000);
+
+    // A retry with the same salt is rejected and moves no funds.
+    assert_eq!(
+        client.try_create_stream_with_salt(&sender, &recipient, &token, &1_000, &100, &salt),
+        Err(Ok(StreamError::DuplicateSalt))
+    );
+    assert_eq!(token_client.balance(&sender), 1_000);
+
+    // The salt is scoped to the sender, and counter IDs are unaffected.
+    let other = Address::generate(&env);
+    assert_ne!(client.get_salted_stream_id(&other, &salt), id);
+    assert_eq!(
+        client.create_stream(&sender, &recipient, &token, &500, &50),
+        1
     );
 }
 
 #[test]
-fn test_no_fee_without_protocol_config() {
+fn test_derived_stream_id_is_predictable() {
     let env = Env::default();
     env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
-    mint(&env, &token, &sender, 1_000);
-
-    // No `initialize` call — fee collection is a silent no-op.
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
     let client = create_contract(&env);
-    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
 
-    let s = client.get_stream(&id).unwrap();
-    assert_eq!(s.deposited_amount, 500); // Full amount, no fee deducted.
+    let predicted = client.predict_stream_id(&sender, &recipient, &token, &1_000, &100);
+    let id = client.create_stream_derived(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(id, predicted);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, recipient);
+
+    // Same parameters again get a fresh ID from the advanced nonce.
+    let next = client.predict_stream_id(&sender, &recipient, &token, &1_000, &100);
+    assert_ne!(next, id);
+    assert_eq!(
+        client.create_stream_derived(&sender, &recipient, &token, &1_000, &100),
+        next
+    );
 }
 
 #[test]
-fn test_withdraw_time_based_calculation() {
+fn test_create_stream_mints_receipt_to_recipient() {
     let env = Env::default();
     env.mock_all_auths();
-
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
-    mint(&env, &token, &sender, 1_000);
-
+    mint(&env, &token, &sender, 2_000);
     let client = create_contract(&env);
-    let _token_client = token::Client::new(&env, &token);
 
-    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
-    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
-
-    // Advance time by 100 seconds
-    env.ledger().with_mut(|l| {
-        l.timestamp += 100;
-    });
+    let first = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let second = client.create_stream(&sender, &recipient, &token, &500, &50);
+
+    let receipt = client.get_receipt(&first).unwrap();
+    assert_eq!(receipt.holder, recipient);
+    assert_eq!(receipt.sender, sender);
+    assert_eq!(receipt.deposited_amount, 1_000);
+    assert_eq!(receipt.rate_per_second, 10);
+    assert_eq!(receipt.end_time, receipt.start_time + 100);
+
+    let held = client.get_receipts_by_holder(&recipient, &0, &10);
+    assert_eq!(held.len(), 2);
+    assert_eq!(held.get(1).unwrap().stream_id, second);
+    assert_eq!(client.get_receipts_by_holder(&recipient, &1, &10).len(), 1);
+    assert!(client.get_receipts_by_holder(&sender, &0, &10).is_empty());
+}
 
-    // First withdrawal: should get 100 tokens (100 seconds * 1 token/second)
-    let withdrawn1 = client.withdraw(&recipient, &stream_id);
-    assert_eq!(withdrawn1, 100);
+#[test]
+fn test_statement_bytes_round_trip() {
+    use soroban_sdk::xdr::FromXdr as _;
 
-    let stream = client.get_stream(&stream_id).unwrap();
-    assert_eq!(stream.withdrawn_amount, 100);
-    assert_eq!(stream.last_update_time, env.ledger().timestamp());
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
 
-    // Advance time by another 200 seconds
-    env.ledger().with_mut(|l| {
-        l.timestamp += 200;
-    });
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp += 30);
+    client.withdraw(&recipient, &id);
 
-    // Second withdrawal: should get 200 tokens (200 seconds * 1 token/second)
-    let withdrawn2 = client.withdraw(&recipient, &stream_id);
-    assert_eq!(withdrawn2, 200);
+    let bytes = client.get_statement_bytes(&id).unwrap();
+    assert_eq!(client.get_statement_bytes(&id).unwrap(), bytes);
 
-    let stream = client.get_stream(&stream_id).unwrap();
-    assert_eq!(stream.withdrawn_amount, 300);
+    let statement = StreamStatement::from_xdr(&env, &bytes).unwrap();
+    assert_eq!(statement.stream_id, id);
+    assert_eq!(statement.recipient, recipient);
+    assert_eq!(statement.withdrawn_amount, 300);
+    assert_eq!(statement.ledger_timestamp, env.ledger().timestamp());
+    assert!(client.get_statement_bytes(&99).is_none());
 }
 
 #[test]
-fn test_withdraw_caps_at_remaining_balance() {
+fn test_completion_bonus_paid_after_end_time() {
     let env = Env::default();
     env.mock_all_auths();
-
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
-    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &sender, 1_200);
 
     let client = create_contract(&env);
-    let _token_client = token::Client::new(&env, &token);
-
-    // Create stream: 100 tokens over 100 seconds = 1 token/second
-    let stream_id = client.create_stream(&sender, &recipient, &token, &100, &100);
+    let token_client = token::Client::new(&env, &token);
+    let options = StreamOptions {
+        bonus_amount: 200,
+        ..StreamOptions::new(&env)
+    };
+    let id = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options);
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.end_time, s.start_time + 100);
+    assert_eq!(token_client.balance(&sender), 0);
 
-    // Advance time by 200 seconds (more than the stream duration)
-    env.ledger().with_mut(|l| {
-        l.timestamp += 200;
-    });
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    assert_eq!(
+        client.try_claim_bonus(&recipient, &id),
+        Err(Ok(StreamError::BonusLocked))
+    );
 
-    // Withdrawal should be capped at remaining balance (100 tokens), not 200
-    let withdrawn = client.withdraw(&recipient, &stream_id);
-    assert_eq!(withdrawn, 100);
+    // Draining the stream leaves it active while the bonus is outstanding.
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    assert_eq!(client.withdraw(&recipient, &id), 1_000);
+    assert!(client.get_stream(&id).unwrap().is_active());
 
-    let stream = client.get_stream(&stream_id).unwrap();
-    assert_eq!(stream.withdrawn_amount, 100);
-    assert!(!stream.is_active);
+    assert_eq!(client.claim_bonus(&recipient, &id), 200);
+    assert_eq!(token_client.balance(&recipient), 1_200);
+    assert!(!client.get_stream(&id).unwrap().is_active());
 }
 
 #[test]
-fn test_cancel_stream_refunds_sender() {
+fn test_completion_bonus_refunded_on_early_cancel() {
     let env = Env::default();
     env.mock_all_auths();
-
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
-    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &sender, 1_200);
 
-    let contract_id = env.register(StreamContract, ());
-    let client = StreamContractClient::new(&env, &contract_id);
+    let client = create_contract(&env);
     let token_client = token::Client::new(&env, &token);
+    let options = StreamOptions {
+        bonus_amount: 200,
+        ..StreamOptions::new(&env)
+    };
+    let id = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options);
 
-    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
-    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    env.ledger().with_mut(|l| l.timestamp += 40);
+    client.cancel_stream(&sender, &id);
 
-    let sender_balance_before = token_client.balance(&sender);
+    assert_eq!(token_client.balance(&recipient), 400);
+    assert_eq!(token_client.balance(&sender), 600 + 200);
+    assert!(client.get_stream(&id).unwrap().bonus_claimed);
+}
 
-    // Advance time by 300 seconds (300 tokens should be claimable by recipient)
-    env.ledger().with_mut(|l| {
-        l.timestamp += 300;
-    });
+#[test]
+fn test_top_up_extends_end_time_at_same_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
 
-    // Cancel stream: should pay 300 to recipient and refund 700 to sender
-    client.cancel_stream(&sender, &stream_id);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    let end_before = client.get_stream(&id).unwrap().end_time;
 
-    let sender_balance_after = token_client.balance(&sender);
-    let contract_balance_after = token_client.balance(&contract_id);
-    let recipient_balance_after = token_client.balance(&recipient);
+    // 10 tokens/sec: 55 more tokens take 5.5s, rounded up to 6.
+    client.top_up_stream(&sender, &id, &55);
+    assert_eq!(client.get_stream(&id).unwrap().end_time, end_before + 6);
+}
 
-    // Sender should receive 700 tokens back
-    assert_eq!(sender_balance_after - sender_balance_before, 700);
-    // Recipient should receive final claimable 300 immediately
-    assert_eq!(recipient_balance_after, 300);
-    // Contract should be fully drained
-    assert_eq!(contract_balance_after, 0);
+#[test]
+fn test_top_up_extend_recomputes_end_without_drift() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
 
-    let stream = client.get_stream(&stream_id).unwrap();
-    assert!(!stream.is_active);
-    assert_eq!(stream.withdrawn_amount, 300);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    // Two 5-token top-ups at 10 tokens/sec: one extra second in total, not two.
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.top_up_extend(&sender, &id, &5), 101);
+    let new_end = client.top_up_extend(&sender, &id, &5);
+    assert_eq!(new_end, 101);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "stream_extended")
+        })
+        .expect("stream_extended event not found");
+    let payload = StreamExtendedEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.rate_per_second, 10);
+    assert_eq!(payload.previous_end_time, 101);
+    assert_eq!(payload.new_end_time, 101);
+    assert_eq!(payload.remainder, 0);
+
+    // The rate is unchanged and the whole deposit is out exactly at the end.
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.rate_per_second(), 10);
+    env.ledger().with_mut(|li| li.timestamp = 101);
+    assert_eq!(client.get_claimable_amount(&id), Some(1_010));
 }
 
 #[test]
-fn test_cancel_stream_after_partial_withdrawal() {
+fn test_top_up_extend_rejects_non_sender() {
     let env = Env::default();
     env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let other = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &other, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(
+        client.try_top_up_extend(&other, &id, &100),
+        Err(Ok(StreamError::NotSender))
+    );
+}
 
+#[test]
+fn test_clawback_hold_finalized_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
     let (token, _) = create_token(&env);
     let sender = Address::generate(&env);
     let recipient = Address::generate(&env);
     mint(&env, &token, &sender, 1_000);
 
-    let contract_id = env.register(StreamContract, ());
-    let client = StreamContractClient::new(&env, &contract_id);
+    let client = create_contract(&env);
     let token_client = token::Client::new(&env, &token);
+    let options = StreamOptions {
+        clawback_window: 3_600,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
 
-    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
-    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
-
-    // Advance time by 200 seconds
-    env.ledger().with_mut(|l| {
-        l.timestamp += 200;
-    });
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_stream(&id).unwrap().held_amount, 100);
 
-    // Recipient withdraws 200 tokens
-    client.withdraw(&recipient, &stream_id);
+    env.ledger().with_mut(|l| l.timestamp += 3_599);
+    assert_eq!(
+        client.try_finalize_withdrawal(&recipient, &id),
+        Err(Ok(StreamError::ClawbackWindowOpen))
+    );
 
-    let sender_balance_before = token_client.balance(&sender);
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert_eq!(
+        client.try_contest_withdrawal(&sender, &id),
+        Err(Ok(StreamError::ClawbackWindowClosed))
+    );
+    assert_eq!(client.finalize_withdrawal(&recipient, &id), 100);
+    assert_eq!(token_client.balance(&recipient), 100);
+    assert_eq!(client.get_stream(&id).unwrap().held_amount, 0);
+}
+
+#[test]
+fn test_contested_hold_frozen_until_admin_resolves() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let options = StreamOptions {
+        clawback_window: 3_600,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.withdraw(&recipient, &id);
+    client.contest_withdrawal(&sender, &id);
+
+    env.ledger().with_mut(|l| l.timestamp += 3_600);
+    assert_eq!(
+        client.try_finalize_withdrawal(&recipient, &id),
+        Err(Ok(StreamError::HoldContested))
+    );
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::HoldContested))
+    );
+    assert_eq!(
+        client.try_resolve_contest(&sender, &id, &true),
+        Err(Ok(StreamError::NotAdmin))
+    );
+
+    assert_eq!(client.resolve_contest(&admin, &id, &true), 100);
+    assert_eq!(token_client.balance(&sender), 100);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert!(!client.get_stream(&id).unwrap().hold_contested);
+}
+
+#[test]
+fn test_withdraw_splits_payout_to_secondary_address() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let savings = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    client.set_payout_split(&recipient, &id, &savings, &2_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 500);
+    assert_eq!(client.withdraw(&recipient, &id), 500);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "tokens_withdrawn")
+        })
+        .expect("tokens_withdrawn event not found");
+    let payload: TokensWithdrawnEvent = TokensWithdrawnEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.amount, 500);
+    assert_eq!(payload.split_address, Some(savings.clone()));
+    assert_eq!(payload.split_amount, 100);
+    assert_eq!(token_client.balance(&recipient), 400);
+    assert_eq!(token_client.balance(&savings), 100);
+
+    // Clearing the split sends everything to the recipient again.
+    client.set_payout_split(&recipient, &id, &savings, &0);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.withdraw(&recipient, &id);
+    assert_eq!(token_client.balance(&recipient), 500);
+    assert_eq!(token_client.balance(&savings), 100);
+}
+
+#[test]
+fn test_set_payout_split_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    let savings = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_payout_split(&sender, &id, &savings, &1_000),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    assert_eq!(
+        client.try_set_payout_split(&recipient, &id, &savings, &10_001),
+        Err(Ok(StreamError::InvalidSplit))
+    );
+}
+
+#[test]
+fn test_update_payout_address_redirects_withdrawals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    assert_eq!(
+        client.try_update_payout_address(&sender, &id, &new_wallet),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    client.update_payout_address(&recipient, &id, &new_wallet);
+
+    // The recipient still authorizes; tokens land at the new wallet.
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    client.withdraw(&recipient, &id);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "tokens_withdrawn")
+        })
+        .expect("tokens_withdrawn event not found");
+    let payload: TokensWithdrawnEvent = TokensWithdrawnEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.recipient, recipient);
+    assert_eq!(payload.destination, new_wallet);
+
+    assert_eq!(token_client.balance(&new_wallet), 100);
+    assert_eq!(token_client.balance(&recipient), 0);
+
+    // Cancellation settles accrued tokens to the payout address as well.
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&new_wallet), 150);
+
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.recipient, recipient);
+    assert_eq!(s.payout_address, Some(new_wallet));
+}
+
+#[test]
+fn test_checkpoint_materializes_accrual() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 300);
+    assert_eq!(client.checkpoint(&id), 300);
+
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.settled_balance, 300);
+    assert_eq!(s.last_update_time, env.ledger().timestamp());
+    assert_eq!(s.withdrawn_amount, 0);
+
+    // Accrual continues on top of the settled balance.
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.get_claimable_amount(&id), Some(400));
+    assert_eq!(client.withdraw(&recipient, &id), 400);
+}
+
+#[test]
+fn test_top_up_preserves_accrued_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 200);
+    client.top_up_stream(&sender, &id, &500);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.withdraw(&recipient, &id), 300);
+}
+
+#[test]
+fn test_checkpoint_rejects_inactive_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &1_000);
+    client.cancel_stream(&sender, &id);
+
+    assert_eq!(
+        client.try_checkpoint(&id),
+        Err(Ok(StreamError::StreamInactive))
+    );
+}
+
+#[test]
+fn test_get_stream_health_reports_runway() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 400);
+    let health = client.get_stream_health(&id).unwrap();
+    assert_eq!(health.runway_seconds, 600);
+    assert_eq!(health.depletes_at, env.ledger().timestamp() + 600);
+    assert_eq!(health.unstreamed_balance, 600);
+    assert_eq!(health.owed_amount, 400);
+    assert!(!health.is_underfunded);
+
+    env.ledger().with_mut(|l| l.timestamp += 600);
+    let health = client.get_stream_health(&id).unwrap();
+    assert_eq!(health.runway_seconds, 0);
+    assert_eq!(health.owed_amount, 1_000);
+
+    assert_eq!(client.get_stream_health(&999), None);
+}
+
+#[test]
+fn test_get_progress_reports_amount_and_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 250);
+    client.withdraw(&recipient, &id);
+    env.ledger().with_mut(|l| l.timestamp += 150);
+    let progress = client.get_progress(&id).unwrap();
+    assert_eq!(progress.streamed_bps, 4_000);
+    assert_eq!(progress.elapsed_seconds, 400);
+    assert_eq!(progress.total_duration, 1_000);
+    assert_eq!(progress.withdrawn_amount, 250);
+    assert_eq!(progress.deposited_amount, 1_000);
+    assert_eq!(progress.runway_seconds, 600);
+
+    env.ledger().with_mut(|l| l.timestamp += 2_000);
+    let progress = client.get_progress(&id).unwrap();
+    assert_eq!(progress.streamed_bps, 10_000);
+    assert_eq!(progress.elapsed_seconds, 1_000);
+    assert_eq!(progress.runway_seconds, 0);
+
+    assert_eq!(client.get_progress(&999), None);
+}
+
+#[test]
+fn test_beneficiary_claims_after_recipient_inactivity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let backup = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &10_000, &10_000);
+    client.set_beneficiary(&recipient, &id, &backup, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 500);
+    client.withdraw(&recipient, &id);
+
+    // Inactivity is measured from the last withdrawal.
+    env.ledger().with_mut(|l| l.timestamp += 999);
+    assert_eq!(
+        client.try_claim_as_beneficiary(&backup, &id),
+        Err(Ok(StreamError::BeneficiaryNotEligible))
+    );
+    assert_eq!(
+        client.try_claim_as_beneficiary(&sender, &id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    client.claim_as_beneficiary(&backup, &id);
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.recipient, backup);
+    assert_eq!(s.beneficiary, None);
+
+    // Funds accrued while the recipient was inactive now go to the beneficiary.
+    assert_eq!(client.withdraw(&backup, &id), 1_000);
+    assert_eq!(token_client.balance(&backup), 1_000);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::NotRecipient))
+    );
+}
+
+#[test]
+fn test_beneficiary_must_pass_payee_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let backup = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+
+    client.set_payee_allowlist_enabled(&sender, &true);
+    client.set_payee_allowed(&sender, &recipient, &true);
+    let id = client.create_stream(&sender, &recipient, &token, &10_000, &10_000);
+    assert_eq!(
+        client.try_set_beneficiary(&recipient, &id, &backup, &100),
+        Err(Ok(StreamError::PayeeNotAllowed))
+    );
+
+    // A beneficiary dropped from the list after designation cannot claim.
+    client.set_payee_allowed(&sender, &backup, &true);
+    client.set_beneficiary(&recipient, &id, &backup, &100);
+    client.set_payee_allowed(&sender, &backup, &false);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.try_claim_as_beneficiary(&backup, &id),
+        Err(Ok(StreamError::PayeeNotAllowed))
+    );
+    client.set_payee_allowed(&sender, &backup, &true);
+    client.claim_as_beneficiary(&backup, &id);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, backup);
+}
+
+#[test]
+fn test_beneficiary_must_be_attested() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let backup = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let registry_id = env.register(MockAttestationRegistry, ());
+    let registry = MockAttestationRegistryClient::new(&env, &registry_id);
+    client.set_attestation_registry(&admin, &Some(registry_id));
+    registry.set_attested(&sender, &true);
+    registry.set_attested(&recipient, &true);
+
+    let id = client.create_stream(&sender, &recipient, &token, &10_000, &10_000);
+    assert_eq!(
+        client.try_set_beneficiary(&recipient, &id, &backup, &100),
+        Err(Ok(StreamError::AttestationMissing))
+    );
+
+    // An attestation revoked after designation blocks the claim.
+    registry.set_attested(&backup, &true);
+    client.set_beneficiary(&recipient, &id, &backup, &100);
+    registry.set_attested(&backup, &false);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.try_claim_as_beneficiary(&backup, &id),
+        Err(Ok(StreamError::AttestationMissing))
+    );
+    registry.set_attested(&backup, &true);
+    client.claim_as_beneficiary(&backup, &id);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, backup);
+}
+
+#[test]
+fn test_transfer_stream_recipient_settles_accrued_to_old_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.set_beneficiary(&recipient, &id, &Address::generate(&env), &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 30);
+    assert_eq!(
+        client.try_transfer_stream_recipient(&sender, &id, &new_wallet),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    assert_eq!(
+        client.transfer_stream_recipient(&recipient, &id, &new_wallet),
+        300
+    );
+    let transferred = env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "stream_transferred")
+        })
+        .unwrap();
+    assert_eq!(
+        StreamTransferredEvent::try_from_val(&env, &transferred.2).unwrap(),
+        StreamTransferredEvent {
+            stream_id: id,
+            previous_recipient: recipient.clone(),
+            new_recipient: new_wallet.clone(),
+            settled_amount: 300,
+        }
+    );
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.recipient, new_wallet);
+    assert_eq!(s.beneficiary, None);
+    assert_eq!(token_client.balance(&recipient), 300);
+
+    // Only accrual after the handover goes to the new recipient.
+    env.ledger().with_mut(|l| l.timestamp += 70);
+    assert_eq!(client.withdraw(&new_wallet, &id), 700);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::NotRecipient))
+    );
+}
+
+#[test]
+fn test_transfer_stream_recipient_respects_clawback_and_withdrawal_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let new_wallet = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    // A clawback stream would park the settlement in a hold that the new
+    // recipient releases, so it cannot be handed over.
+    let clawback = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &StreamOptions {
+            clawback_window: 3_600,
+            ..StreamOptions::new(&env)
+        },
+    );
+    env.ledger().with_mut(|l| l.timestamp += 30);
+    assert_eq!(
+        client.try_transfer_stream_recipient(&recipient, &clawback, &new_wallet),
+        Err(Ok(StreamError::ClawbackWindowOpen))
+    );
+    assert_eq!(client.get_stream(&clawback).unwrap().recipient, recipient);
+
+    // The settling withdrawal obeys the cooldown, which delays the handover.
+    let cooled = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &200,
+        &StreamOptions {
+            withdrawal_cooldown: 50,
+            ..StreamOptions::new(&env)
+        },
+    );
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    assert_eq!(client.withdraw(&recipient, &cooled), 250);
+    env.ledger().with_mut(|l| l.timestamp += 20);
+    assert_eq!(
+        client.try_transfer_stream_recipient(&recipient, &cooled, &new_wallet),
+        Err(Ok(StreamError::CooldownActive))
+    );
+    env.ledger().with_mut(|l| l.timestamp += 30);
+    assert_eq!(
+        client.transfer_stream_recipient(&recipient, &cooled, &new_wallet),
+        250
+    );
+    assert_eq!(token_client.balance(&recipient), 500);
+}
+
+#[test]
+fn test_sender_transfer_needs_acceptance_and_moves_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &treasury, 500);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    assert_eq!(
+        client.try_propose_sender_transfer(&recipient, &id, &Some(treasury.clone())),
+        Err(Ok(StreamError::NotSender))
+    );
+    assert_eq!(
+        client.try_accept_sender_transfer(&treasury, &id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    client.propose_sender_transfer(&sender, &id, &Some(treasury.clone()));
+    assert_eq!(client.get_pending_sender(&id), Some(treasury.clone()));
+    assert_eq!(
+        client.try_accept_sender_transfer(&Address::generate(&env), &id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    // Until accepted, the original sender keeps full control.
+    assert_eq!(client.get_stream(&id).unwrap().sender, sender);
+
+    client.accept_sender_transfer(&treasury, &id);
+    assert_eq!(client.get_stream(&id).unwrap().sender, treasury);
+    assert_eq!(client.get_pending_sender(&id), None);
+    assert_eq!(
+        client.try_cancel_stream(&sender, &id),
+        Err(Ok(StreamError::NotSender))
+    );
+
+    // The new sender tops up and cancels, and receives the refund.
+    client.top_up_stream(&treasury, &id, &500);
+    env.ledger().with_mut(|l| l.timestamp += 50);
+    client.cancel_stream(&treasury, &id);
+    assert_eq!(token_client.balance(&recipient), 500);
+    assert_eq!(token_client.balance(&treasury), 1_000);
+    assert_eq!(token_client.balance(&sender), 0);
+}
+
+#[test]
+fn test_sender_transfer_moves_tags_and_refunds_scheduled_top_ups() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    mint(&env, &token, &sender, 1_500);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let payroll = Symbol::new(&env, "payroll");
+    let options = StreamOptions {
+        tags: vec![&env, payroll.clone()],
+        ..StreamOptions::new(&env)
+    };
+    let id = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options);
+    let top_up_id = client.schedule_top_up(&sender, &id, &500, &50);
+    assert_eq!(token_client.balance(&sender), 0);
+
+    client.propose_sender_transfer(&sender, &id, &Some(treasury.clone()));
+    client.accept_sender_transfer(&treasury, &id);
+    assert_eq!(
+        client.get_streams_by_tag(&sender, &payroll, &0, &10).len(),
+        0
+    );
+    assert_eq!(
+        client.get_streams_by_tag(&treasury, &payroll, &0, &10),
+        vec![&env, id]
+    );
+
+    // The previous sender's escrow goes back to it, not into the stream.
+    env.ledger().with_mut(|l| l.timestamp = 50);
+    client.execute_scheduled_top_up(&top_up_id);
+    assert_eq!(client.get_scheduled_top_up(&top_up_id), None);
+    assert_eq!(token_client.balance(&sender), 500);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_000);
+}
+
+#[test]
+fn test_get_claimable_amounts_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 3_000);
+
+    let client = create_contract(&env);
+    let id1 = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &1_000);
+    let id2 = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &500);
+    let id3 = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    client.cancel_stream(&sender, &id3);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(
+        client.get_claimable_amounts(&vec![&env, id1, id2, id3, 999]),
+        vec![&env, 100, 200, 0, 0]
+    );
+    assert_eq!(client.get_claimable_amounts(&Vec::new(&env)).len(), 0);
+}
+
+// ─── cancel_stream ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_cancel_stream_refunds_unspent_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+    let sender_balance_before = token_client.balance(&sender);
+
+    client.cancel_stream(&sender, &id);
+
+    // Full 500 should be refunded since nothing was withdrawn.
+    assert_eq!(token_client.balance(&sender) - sender_balance_before, 500);
+
+    let s = client.get_stream(&id).unwrap();
+    assert!(!s.is_active());
+}
+
+#[test]
+fn test_cancel_stream_rejects_non_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+
+    assert_eq!(
+        client.try_cancel_stream(&attacker, &id),
+        Err(Ok(StreamError::NotSender))
+    );
+}
+
+#[test]
+fn test_cancel_stream_rejects_missing_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = create_contract(&env);
+
+    assert_eq!(
+        client.try_cancel_stream(&Address::generate(&env), &999),
+        Err(Ok(StreamError::StreamNotFound))
+    );
+}
+
+#[test]
+fn test_cancel_stream_rejects_already_inactive() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+    client.cancel_stream(&sender, &id);
+
+    assert_eq!(
+        client.try_cancel_stream(&sender, &id),
+        Err(Ok(StreamError::StreamInactive))
+    );
+}
+
+#[test]
+fn test_cancel_stream_emits_event_with_refund_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &500, &100);
+    client.cancel_stream(&sender, &id);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "stream_cancelled")
+        })
+        .expect("stream_cancelled event not found");
+
+    let payload: StreamCancelledEvent = StreamCancelledEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.stream_id, id);
+    assert_eq!(payload.sender, sender);
+    assert_eq!(payload.recipient, recipient);
+    assert_eq!(payload.amount_withdrawn, 0);
+    assert_eq!(payload.refunded_amount, 500);
+    assert_eq!(payload.remaining_deposit, 0);
+    assert_eq!(payload.status, StreamStatus::Cancelled);
+}
+
+#[test]
+fn test_scheduled_cancel_stops_accrual_at_effective_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.cancel_stream_at(&sender, &id, &60);
+
+    // Still accruing before the effective time.
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    assert_eq!(client.get_claimable_amount(&id), Some(300));
+    assert_eq!(
+        client.try_execute_scheduled_cancel(&id),
+        Err(Ok(StreamError::CancelNotDue))
+    );
+
+    // Frozen at 600 once the effective time has passed.
+    env.ledger().with_mut(|li| li.timestamp = 90);
+    assert_eq!(client.get_claimable_amount(&id), Some(600));
+    assert_eq!(
+        client.try_cancel_stream_at(&sender, &id, &95),
+        Err(Ok(StreamError::CancelAlreadyEffective))
+    );
+
+    client.execute_scheduled_cancel(&id);
+    assert_eq!(token_client.balance(&recipient), 600);
+    assert_eq!(token_client.balance(&sender), 400);
+    assert!(!client.get_stream(&id).unwrap().is_active());
+}
+
+#[test]
+fn test_cancel_stream_at_past_time_cancels_immediately() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    client.cancel_stream_at(&sender, &id, &0);
+
+    assert!(!client.get_stream(&id).unwrap().is_active());
+    assert_eq!(token_client.balance(&recipient), 200);
+    assert_eq!(token_client.balance(&sender), 800);
+}
+
+#[test]
+fn test_spending_limit_caps_creation_and_top_up_per_epoch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &manager, 10_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_spending_limit(&admin, &manager, &token, &1_500, &1_000);
+
+    let id = client.create_stream(&manager, &recipient, &token, &1_000, &100);
+    client.top_up_stream(&manager, &id, &400);
+    assert_eq!(
+        client.try_top_up_stream(&manager, &id, &200),
+        Err(Ok(StreamError::BudgetExceeded))
+    );
+    assert_eq!(
+        client.try_create_stream(&manager, &recipient, &token, &200, &100),
+        Err(Ok(StreamError::BudgetExceeded))
+    );
+    assert_eq!(
+        client.get_spending_limit(&manager, &token).unwrap().spent,
+        1_400
+    );
+
+    // The counter rolls over once the epoch ends.
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(
+        client.get_spending_limit(&manager, &token).unwrap().spent,
+        0
+    );
+    client.create_stream(&manager, &recipient, &token, &1_500, &100);
+
+    // Removing the limit lifts the cap.
+    client.set_spending_limit(&admin, &manager, &token, &0, &0);
+    client.create_stream(&manager, &recipient, &token, &2_000, &100);
+}
+
+// ─── Protocol Fee Integration ─────────────────────────────────────────────────
+
+#[test]
+fn test_create_stream_with_fee_deduction() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    // 2% fee (200 bps). Gross: 500, fee: 10, net: 490.
+    client.initialize(&admin, &treasury, &200);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+
+    client.claim_fees(&token);
+    assert_eq!(token_client.balance(&treasury), 10);
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.deposited_amount, 490);
+    assert_eq!(s.rate_per_second(), 4); // 490 / 100 = 4 (integer division)
+}
+
+#[test]
+fn test_top_up_with_fee_deduction() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    // 1% fee (100 bps). Create: gross 1 000, fee 10, net 990.
+    client.initialize(&admin, &treasury, &100);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+
+    // Top up: gross 500, fee 5, net 495. Treasury total: 15.
+    client.top_up_stream(&sender, &id, &500);
+    assert_eq!(client.claim_fees(&token), 15);
+    assert_eq!(token_client.balance(&treasury), 15);
+
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.deposited_amount, 990 + 495);
+}
+
+#[test]
+fn test_fee_collected_event_emitted_on_create() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+
+    // 5% fee (500 bps). Gross: 1 000, fee: 50.
+    client.initialize(&admin, &treasury, &500);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "fee_collected")
+        })
+        .expect("fee_collected event not found");
+
+    let payload: FeeCollectedEvent = FeeCollectedEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.stream_id, id);
+    assert_eq!(payload.treasury, treasury);
+    assert_eq!(payload.fee_amount, 50);
+}
+
+#[test]
+fn test_no_fee_event_when_fee_rate_is_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+
+    // 0 bps fee — no fee_collected event must be emitted.
+    client.initialize(&admin, &treasury, &0);
+    client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+
+    let events = env.events().all();
+    let fee_event = events.iter().find(|e| {
+        Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+            == Symbol::new(&env, "fee_collected")
+    });
+    assert!(
+        fee_event.is_none(),
+        "fee_collected must not fire when fee rate is 0"
+    );
+}
+
+#[test]
+fn test_no_fee_without_protocol_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    // No `initialize` call — fee collection is a silent no-op.
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &500, &100);
+
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.deposited_amount, 500); // Full amount, no fee deducted.
+}
+
+#[test]
+fn test_category_fee_rate_applies_at_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 20_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &500); // 5% globally
+    client.set_category_fee_rate(&admin, &StreamCategory::Payroll, &Some(0));
+    assert_eq!(client.get_category_fee_rate(&StreamCategory::Payroll), 0);
+    assert_eq!(client.get_category_fee_rate(&StreamCategory::Vesting), 500);
+
+    let payroll = StreamOptions {
+        category: StreamCategory::Payroll,
+        ..StreamOptions::new(&env)
+    };
+    let payroll_id = client.create_stream_with_options(
+        &sender,
+        &Address::generate(&env),
+        &token,
+        &10_000,
+        &100,
+        &payroll,
+    );
+    let general_id = client.create_stream(&sender, &Address::generate(&env), &token, &10_000, &100);
+
+    let payroll_stream = client.get_stream(&payroll_id).unwrap();
+    assert_eq!(payroll_stream.category, StreamCategory::Payroll);
+    assert_eq!(payroll_stream.deposited_amount, 10_000);
+    assert_eq!(
+        client.get_stream(&general_id).unwrap().deposited_amount,
+        9_500
+    );
+    client.claim_fees(&token);
+    assert_eq!(token::Client::new(&env, &token).balance(&treasury), 500);
+
+    // Clearing the override falls back to the global rate.
+    client.set_category_fee_rate(&admin, &StreamCategory::Payroll, &None);
+    assert_eq!(client.get_category_fee_rate(&StreamCategory::Payroll), 500);
+}
+
+#[test]
+fn test_partner_fee_override_and_revenue_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let partner_wallet = Address::generate(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 20_000);
+    let token_client = token::Client::new(&env, &token);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &500); // 5% globally
+    let partner_id = Symbol::new(&env, "acme");
+    // 2% fee on partner streams, a quarter of which goes to the partner.
+    client.register_partner(&admin, &partner_id, &partner_wallet, &Some(200), &2_500);
+
+    let options = StreamOptions {
+        partner: Some(partner_id.clone()),
+        ..StreamOptions::new(&env)
+    };
+    let id = client.create_stream_with_options(
+        &sender,
+        &Address::generate(&env),
+        &token,
+        &10_000,
+        &100,
+        &options,
+    );
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 9_800);
+    assert_eq!(token_client.balance(&partner_wallet), 50);
+    client.claim_fees(&token);
+    assert_eq!(token_client.balance(&treasury), 150);
+
+    // Top-ups keep sharing with the partner.
+    client.top_up_stream(&sender, &id, &5_000);
+    assert_eq!(token_client.balance(&partner_wallet), 75);
+    assert_eq!(client.claim_fees(&token), 75);
+    assert_eq!(token_client.balance(&treasury), 225);
+
+    client.remove_partner(&admin, &partner_id);
+    assert_eq!(
+        client.try_create_stream_with_options(
+            &sender,
+            &Address::generate(&env),
+            &token,
+            &1_000,
+            &100,
+            &options
+        ),
+        Err(Ok(StreamError::PartnerNotFound))
+    );
+}
+
+#[test]
+fn test_top_up_uses_fee_rate_locked_at_creation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    // 1% fee at creation: gross 1 000, fee 10, net 990.
+    client.initialize(&admin, &treasury, &100);
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(client.get_stream_fee_rate(&id), Some(100));
+
+    // Raising the global rate to 10% must not affect the existing stream.
+    client.update_fee_config(&admin, &treasury, &1_000);
+    client.top_up_stream(&sender, &id, &500);
+
+    // Top-up still charged at 1%: fee 5. Treasury total: 15.
+    assert_eq!(client.claim_fees(&token), 15);
+    assert_eq!(token_client.balance(&treasury), 15);
+    let s = client.get_stream(&id).unwrap();
+    assert_eq!(s.deposited_amount, 990 + 495);
+    assert_eq!(s.fee_rate_bps, 100);
+}
+
+#[test]
+fn test_stream_created_before_initialize_stays_fee_free() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(client.get_stream_fee_rate(&id), Some(0));
+
+    client.initialize(&admin, &treasury, &500);
+    client.top_up_stream(&sender, &id, &500);
+
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_500);
+}
+
+#[test]
+fn test_get_stream_fee_rate_missing_stream() {
+    let env = Env::default();
+    let client = create_contract(&env);
+    assert_eq!(client.get_stream_fee_rate(&999), None);
+}
+
+#[test]
+fn test_withdraw_time_based_calculation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let _token_client = token::Client::new(&env, &token);
+
+    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
+    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    // Advance time by 100 seconds
+    env.ledger().with_mut(|l| {
+        l.timestamp += 100;
+    });
+
+    // First withdrawal: should get 100 tokens (100 seconds * 1 token/second)
+    let withdrawn1 = client.withdraw(&recipient, &stream_id);
+    assert_eq!(withdrawn1, 100);
+
+    let stream = client.get_stream(&stream_id).unwrap();
+    assert_eq!(stream.withdrawn_amount, 100);
+    assert_eq!(stream.last_update_time, env.ledger().timestamp());
+
+    // Advance time by another 200 seconds
+    env.ledger().with_mut(|l| {
+        l.timestamp += 200;
+    });
+
+    // Second withdrawal: should get 200 tokens (200 seconds * 1 token/second)
+    let withdrawn2 = client.withdraw(&recipient, &stream_id);
+    assert_eq!(withdrawn2, 200);
+
+    let stream = client.get_stream(&stream_id).unwrap();
+    assert_eq!(stream.withdrawn_amount, 300);
+}
+
+#[test]
+fn test_withdraw_caps_at_remaining_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let _token_client = token::Client::new(&env, &token);
+
+    // Create stream: 100 tokens over 100 seconds = 1 token/second
+    let stream_id = client.create_stream(&sender, &recipient, &token, &100, &100);
+
+    // Advance time by 200 seconds (more than the stream duration)
+    env.ledger().with_mut(|l| {
+        l.timestamp += 200;
+    });
+
+    // Withdrawal should be capped at remaining balance (100 tokens), not 200
+    let withdrawn = client.withdraw(&recipient, &stream_id);
+    assert_eq!(withdrawn, 100);
+
+    let stream = client.get_stream(&stream_id).unwrap();
+    assert_eq!(stream.withdrawn_amount, 100);
+    assert!(!stream.is_active());
+}
+
+#[test]
+fn test_cancel_stream_refunds_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let contract_id = env.register(StreamContract, ());
+    let client = StreamContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+
+    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
+    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    let sender_balance_before = token_client.balance(&sender);
+
+    // Advance time by 300 seconds (300 tokens should be claimable by recipient)
+    env.ledger().with_mut(|l| {
+        l.timestamp += 300;
+    });
+
+    // Cancel stream: should pay 300 to recipient and refund 700 to sender
+    client.cancel_stream(&sender, &stream_id);
+
+    let sender_balance_after = token_client.balance(&sender);
+    let contract_balance_after = token_client.balance(&contract_id);
+    let recipient_balance_after = token_client.balance(&recipient);
+
+    // Sender should receive 700 tokens back
+    assert_eq!(sender_balance_after - sender_balance_before, 700);
+    // Recipient should receive final claimable 300 immediately
+    assert_eq!(recipient_balance_after, 300);
+    // Contract should be fully drained
+    assert_eq!(contract_balance_after, 0);
+
+    let stream = client.get_stream(&stream_id).unwrap();
+    assert!(!stream.is_active());
+    assert_eq!(stream.withdrawn_amount, 300);
+}
+
+#[test]
+fn test_cancel_stream_after_partial_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let contract_id = env.register(StreamContract, ());
+    let client = StreamContractClient::new(&env, &contract_id);
+    let token_client = token::Client::new(&env, &token);
+
+    // Create stream: 1000 tokens over 1000 seconds = 1 token/second
+    let stream_id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    // Advance time by 200 seconds
+    env.ledger().with_mut(|l| {
+        l.timestamp += 200;
+    });
+
+    // Recipient withdraws 200 tokens
+    client.withdraw(&recipient, &stream_id);
+
+    let sender_balance_before = token_client.balance(&sender);
     let _contract_balance_before = token_client.balance(&contract_id);
 
-    // Advance time by another 100 seconds (100 more tokens accrued)
-    env.ledger().with_mut(|l| {
-        l.timestamp += 100;
-    });
+    // Advance time by another 100 seconds (100 more tokens accrued)
+    env.ledger().with_mut(|l| {
+        l.timestamp += 100;
+    });
+
+    // Cancel stream: should pay final 100 to recipient and refund 700 to sender
+    client.cancel_stream(&sender, &stream_id);
+
+    let sender_balance_after = token_client.balance(&sender);
+    let contract_balance_after = token_client.balance(&contract_id);
+    let recipient_balance_after = token_client.balance(&recipient);
+
+    // Sender should receive 700 tokens back
+    assert_eq!(sender_balance_after - sender_balance_before, 700);
+    // Recipient should now hold total 300 (200 withdrawn earlier + 100 settled at cancel)
+    assert_eq!(recipient_balance_after, 300);
+    // Contract should be fully drained
+    assert_eq!(contract_balance_after, 0);
+}
+
+// ─── Basket Streams ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_basket_stream_releases_tokens_proportionally() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (usdc, _) = create_token(&env);
+    let (gov, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &usdc, &sender, 8_000);
+    mint(&env, &gov, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let deposits = vec![&env, (usdc.clone(), 8_000_i128), (gov.clone(), 2_000_i128)];
+    let id = client.create_basket_stream(&sender, &recipient, &deposits, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 250);
+    assert_eq!(
+        client.get_basket_claimable(&id),
+        Some(vec![&env, 2_000, 500])
+    );
+    assert_eq!(
+        client.withdraw_basket(&recipient, &id),
+        vec![&env, 2_000, 500]
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 1_000);
+    assert_eq!(
+        client.withdraw_basket(&recipient, &id),
+        vec![&env, 6_000, 1_500]
+    );
+
+    assert_eq!(token::Client::new(&env, &usdc).balance(&recipient), 8_000);
+    assert_eq!(token::Client::new(&env, &gov).balance(&recipient), 2_000);
+    assert!(!client.get_basket_stream(&id).unwrap().is_active);
+}
+
+#[test]
+fn test_cancel_basket_stream_settles_each_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (usdc, _) = create_token(&env);
+    let (gov, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &usdc, &sender, 1_000);
+    mint(&env, &gov, &sender, 400);
+
+    let client = create_contract(&env);
+    let deposits = vec![&env, (usdc.clone(), 1_000_i128), (gov.clone(), 400_i128)];
+    let id = client.create_basket_stream(&sender, &recipient, &deposits, &100);
+
+    env.ledger().with_mut(|l| l.timestamp += 25);
+    client.cancel_basket_stream(&sender, &id);
+
+    let usdc_client = token::Client::new(&env, &usdc);
+    let gov_client = token::Client::new(&env, &gov);
+    assert_eq!(usdc_client.balance(&recipient), 250);
+    assert_eq!(usdc_client.balance(&sender), 750);
+    assert_eq!(gov_client.balance(&recipient), 100);
+    assert_eq!(gov_client.balance(&sender), 300);
+
+    assert_eq!(
+        client.try_withdraw_basket(&recipient, &id),
+        Err(Ok(StreamError::StreamInactive))
+    );
+}
+
+#[test]
+fn test_create_basket_stream_rejects_invalid_basket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (usdc, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &usdc, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let empty: Vec<(Address, i128)> = Vec::new(&env);
+    assert_eq!(
+        client.try_create_basket_stream(&sender, &recipient, &empty, &100),
+        Err(Ok(StreamError::InvalidBasket))
+    );
+
+    let duplicate = vec![&env, (usdc.clone(), 100_i128), (usdc.clone(), 100_i128)];
+    assert_eq!(
+        client.try_create_basket_stream(&sender, &recipient, &duplicate, &100),
+        Err(Ok(StreamError::InvalidBasket))
+    );
+}
+
+// ─── Stable-Value Streams ─────────────────────────────────────────────────────
+
+/// Registers a mock oracle quoting `price` (scaled by 10^7) and returns its address.
+fn create_oracle(env: &Env, price: i128) -> Address {
+    let id = env.register(MockPriceOracle, ());
+    MockPriceOracleClient::new(env, &id).set_price(&price);
+    id
+}
+
+#[test]
+fn test_stable_stream_converts_at_oracle_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 5_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    // Token worth 1.0 reference unit; 1 000 reference units over 1 000 s.
+    let oracle = create_oracle(&env, 10_000_000);
+    let id =
+        client.create_stable_stream(&sender, &recipient, &token, &oracle, &5_000, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.withdraw_stable(&recipient, &id), 100);
+
+    // Token halves in value: the same reference accrual now costs twice the tokens.
+    MockPriceOracleClient::new(&env, &oracle).set_price(&5_000_000);
+    env.ledger().with_mut(|l| l.timestamp += 100);
+    assert_eq!(client.withdraw_stable(&recipient, &id), 200);
+
+    // Finish at the lower price; the leftover escrow goes back to the sender.
+    env.ledger().with_mut(|l| l.timestamp += 800);
+    assert_eq!(client.withdraw_stable(&recipient, &id), 1_600);
+    assert_eq!(token_client.balance(&recipient), 1_900);
+    assert_eq!(token_client.balance(&sender), 3_100);
+
+    let s = client.get_stable_stream(&id).unwrap();
+    assert_eq!(s.reference_paid, 1_000);
+    assert!(!s.is_active);
+}
+
+#[test]
+fn test_stable_stream_requests_top_up_when_underfunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let oracle = create_oracle(&env, 10_000_000);
+    let id =
+        client.create_stable_stream(&sender, &recipient, &token, &oracle, &500, &1_000, &1_000);
+
+    // Price drops to 0.5: 400 reference units need 800 tokens; only 500 escrowed.
+    MockPriceOracleClient::new(&env, &oracle).set_price(&5_000_000);
+    env.ledger().with_mut(|l| l.timestamp += 400);
+    assert_eq!(client.withdraw_stable(&recipient, &id), 500);
+
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "top_up_requested")
+        })
+        .expect("top_up_requested event not found");
+    let payload: TopUpRequestedEvent = TopUpRequestedEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.reference_shortfall, 150);
+    assert_eq!(payload.token_shortfall, 300);
+
+    // The shortfall stays owed and is paid once the sender tops up.
+    client.top_up_stable_stream(&sender, &id, &500);
+    assert_eq!(client.withdraw_stable(&recipient, &id), 300);
+    assert_eq!(token_client.balance(&recipient), 800);
+    assert_eq!(client.get_stable_stream(&id).unwrap().reference_paid, 400);
+}
+
+#[test]
+fn test_cancel_stable_stream_refunds_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    // Token worth 2.0 reference units.
+    let oracle = create_oracle(&env, 20_000_000);
+    let id =
+        client.create_stable_stream(&sender, &recipient, &token, &oracle, &2_000, &1_000, &1_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 500);
+    client.cancel_stable_stream(&sender, &id);
+
+    assert_eq!(token_client.balance(&recipient), 250);
+    assert_eq!(token_client.balance(&sender), 1_750);
+    assert_eq!(
+        client.try_withdraw_stable(&recipient, &id),
+        Err(Ok(StreamError::StreamInactive))
+    );
+}
+
+// ─── Multi-Approver Creation ──────────────────────────────────────────────────
+
+/// Registers a policy for `org` requiring `approvals` of `approvers` at and
+/// above `min_amount`.
+fn set_policy(
+    env: &Env,
+    client: &StreamContractClient,
+    org: &Address,
+    approvers: &Vec<Address>,
+    min_amount: i128,
+    approvals: u32,
+) {
+    let policy = ApprovalPolicy {
+        approvers: approvers.clone(),
+        tiers: vec![
+            env,
+            ApprovalTier {
+                min_amount,
+                approvals,
+            },
+        ],
+    };
+    client.set_approval_policy(org, &policy, &Vec::new(env));
+}
+
+#[test]
+fn test_high_value_creation_requires_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let org = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (a, b, c) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    mint(&env, &token, &org, 20_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    set_policy(
+        &env,
+        &client,
+        &org,
+        &vec![&env, a.clone(), b.clone(), c.clone()],
+        5_000,
+        2,
+    );
+
+    // Below the threshold the org still creates directly.
+    client.create_stream(&org, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_create_stream(&org, &recipient, &token, &5_000, &100),
+        Err(Ok(StreamError::ApprovalRequired))
+    );
+
+    // A proposal that could never stream is refused before anyone signs it.
+    assert_eq!(
+        client.try_propose_stream(&a, &org, &recipient, &token, &99, &100),
+        Err(Ok(StreamError::RateTooLow))
+    );
+    let proposal_id = client.propose_stream(&a, &org, &recipient, &token, &5_000, &100);
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(StreamError::ApprovalRequired))
+    );
+    assert_eq!(token_client.balance(&org), 19_000);
+
+    assert_eq!(
+        client.try_approve_proposal(&a, &proposal_id),
+        Err(Ok(StreamError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&recipient, &proposal_id),
+        Err(Ok(StreamError::NotApprover))
+    );
+    client.approve_proposal(&b, &proposal_id);
+
+    let stream_id = client.execute_proposal(&proposal_id);
+    assert_eq!(client.get_stream(&stream_id).unwrap().sender, org);
+    assert_eq!(token_client.balance(&org), 14_000);
+    assert_eq!(
+        client.get_proposal(&proposal_id).unwrap().stream_id,
+        stream_id
+    );
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(StreamError::ProposalExecuted))
+    );
+}
+
+#[test]
+fn test_changing_approval_policy_needs_approver_signers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let org = Address::generate(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let client = create_contract(&env);
+
+    let approvers = vec![&env, a.clone(), b.clone()];
+    set_policy(&env, &client, &org, &approvers, 5_000, 2);
+
+    let removal = ApprovalPolicy {
+        approvers: Vec::new(&env),
+        tiers: Vec::new(&env),
+    };
+    assert_eq!(
+        client.try_set_approval_policy(&org, &removal, &vec![&env, a.clone()]),
+        Err(Ok(StreamError::ApprovalRequired))
+    );
+    assert_eq!(
+        client.try_set_approval_policy(&org, &removal, &vec![&env, org.clone(), a.clone()]),
+        Err(Ok(StreamError::NotApprover))
+    );
+
+    client.set_approval_policy(&org, &removal, &approvers);
+    assert!(client.get_approval_policy(&org).is_none());
+}
+
+#[test]
+fn test_approval_policy_rejects_unreachable_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let org = Address::generate(&env);
+    let client = create_contract(&env);
+
+    let policy = ApprovalPolicy {
+        approvers: vec![&env, Address::generate(&env)],
+        tiers: vec![
+            &env,
+            ApprovalTier {
+                min_amount: 1_000,
+                approvals: 2,
+            },
+        ],
+    };
+    assert_eq!(
+        client.try_set_approval_policy(&org, &policy, &Vec::new(&env)),
+        Err(Ok(StreamError::InvalidApprovalPolicy))
+    );
+}
+
+// ─── Custody ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_skim_recovers_only_unaccounted_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let rescue = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    client.initialize(&admin, &treasury, &100);
+
+    let id = client.create_stream(&sender, &recipient, &token, &10_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    client.withdraw(&recipient, &id);
+    client.claim_fees(&token);
+    assert_eq!(client.get_accounted_balance(&token), 5_940);
+    assert_eq!(
+        client.try_skim(&admin, &token, &rescue),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+
+    // Tokens sent straight to the contract are surplus.
+    mint(&env, &token, &client.address, 700);
+    assert_eq!(client.get_token_surplus(&token), 700);
+    assert_eq!(
+        client.try_skim(&Address::generate(&env), &token, &rescue),
+        Err(Ok(StreamError::NotAdmin))
+    );
+    assert_eq!(client.skim(&admin, &token, &rescue), 700);
+    assert_eq!(token_client.balance(&rescue), 700);
+
+    // Stream funds are untouched and still fully claimable.
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+#[test]
+fn test_frozen_token_blocks_create_top_up_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let (other_token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 5_000);
+    mint(&env, &other_token, &sender, 5_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 10);
+
+    client.freeze_token(&admin, &token);
+    assert!(client.is_token_frozen(&token));
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &1_000, &100),
+        Err(Ok(StreamError::TokenFrozen))
+    );
+    assert_eq!(
+        client.try_top_up_stream(&sender, &id, &100),
+        Err(Ok(StreamError::TokenFrozen))
+    );
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::TokenFrozen))
+    );
+    // Other assets are untouched.
+    client.create_stream(&sender, &recipient, &other_token, &1_000, &100);
+
+    client.unfreeze_token(&admin, &token);
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+}
+
+// ─── Storage TTL ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_ttl_low_event_and_bump() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert!(client.get_stream(&id).unwrap().live_until_ledger > env.ledger().sequence());
+    // Keeps the contract instance alive alongside the stream.
+    let live_until = client.bump_stream_ttl(&id);
+
+    let ttl_low_topic = Symbol::new(&env, "ttl_low");
+    let has_ttl_low = |env: &Env| {
+        env.events()
+            .all()
+            .iter()
+            .any(|e| Symbol::try_from_val(env, &e.1.get(0).unwrap()).unwrap() == ttl_low_topic)
+    };
+
+    // Plenty of TTL left: no warning.
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.checkpoint(&id);
+    assert!(!has_ttl_low(&env));
+
+    // Close to expiry, any write warns with the ledgers left.
+    env.ledger().with_mut(|li| {
+        li.sequence_number = live_until - 100;
+        li.timestamp = 20;
+    });
+    client.checkpoint(&id);
+    let events = env.events().all();
+    let ev = events
+        .iter()
+        .find(|e| Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap() == ttl_low_topic)
+        .expect("ttl_low event not found");
+    let payload = TtlLowEvent::try_from_val(&env, &ev.2).unwrap();
+    assert_eq!(payload.remaining_ledgers, 100);
+
+    let new_live_until = client.bump_stream_ttl(&id);
+    assert!(new_live_until > live_until);
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.checkpoint(&id);
+    assert!(!has_ttl_low(&env));
+}
+
+// ─── Donation Streams ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_donation_stream_accepts_donations_from_anyone() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let donor = Address::generate(&env);
+    mint(&env, &token, &donor, 2_000);
+
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &500);
+    client.set_category_fee_rate(&admin, &StreamCategory::Donation, &Some(0));
+
+    let id = client.create_donation_stream(&creator, &token, &10);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.sender, creator);
+    assert_eq!(stream.recipient, creator);
+    assert_eq!(stream.category, StreamCategory::Donation);
+    assert_eq!(stream.fee_rate_bps, 0);
+
+    assert_eq!(client.donate(&donor, &id, &1_000), 1_000);
+    assert_eq!(client.get_stream(&id).unwrap().end_time, 100);
+
+    // Drain the stream: it stays open for the next donation.
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    assert_eq!(client.withdraw(&creator, &id), 1_000);
+    assert!(client.get_stream(&id).unwrap().is_active());
+
+    // The idle gap is not paid out; the new donation streams from now.
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.donate(&donor, &id, &500);
+    assert_eq!(client.get_stream(&id).unwrap().end_time, 250);
+    env.ledger().with_mut(|li| li.timestamp = 210);
+    assert_eq!(client.get_claimable_amount(&id), Some(100));
+    assert_eq!(token::Client::new(&env, &token).balance(&treasury), 0);
+}
+
+#[test]
+fn test_donate_rejects_regular_streams() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let donor = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &donor, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(
+        client.try_donate(&donor, &id, &100),
+        Err(Ok(StreamError::Unauthorized))
+    );
+}
+
+// ─── Vote Delegation ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_delegate_unvested_tracks_vesting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let governance_id = env.register(MockGovernance, ());
+    let governance = MockGovernanceClient::new(&env, &governance_id);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.delegate_unvested(&recipient, &id, &governance_id),
+        1_000
+    );
+    assert_eq!(governance.delegated(&id), 1_000);
+    let delegation = client.get_delegation(&id).unwrap();
+    assert_eq!(delegation.delegatee, recipient);
+    assert_eq!(delegation.amount, 1_000);
+
+    // Vested tokens drop out of the delegation on sync.
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.sync_delegation(&id), 600);
+    assert_eq!(governance.delegated(&id), 600);
+
+    // Cancellation unlocks everything, so the next sync revokes it.
+    client.cancel_stream(&sender, &id);
+    assert_eq!(client.sync_delegation(&id), 0);
+    assert_eq!(governance.delegated(&id), 0);
+    assert_eq!(client.get_delegation(&id), None);
+    assert_eq!(
+        client.try_sync_delegation(&id),
+        Err(Ok(StreamError::RecordNotFound))
+    );
+}
+
+#[test]
+fn test_delegate_unvested_requires_recipient_and_governance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let governance_id = env.register(MockGovernance, ());
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_delegate_unvested(&sender, &id, &governance_id),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    // A contract without `set_delegation` rejects the call.
+    assert_eq!(
+        client.try_delegate_unvested(&recipient, &id, &token),
+        Err(Ok(StreamError::DelegationFailed))
+    );
+
+    client.delegate_unvested(&recipient, &id, &governance_id);
+    client.undelegate_unvested(&recipient, &id);
+    assert_eq!(
+        MockGovernanceClient::new(&env, &governance_id).delegated(&id),
+        0
+    );
+    assert_eq!(client.get_delegation(&id), None);
+}
+
+// ─── Contract Senders ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_treasury_contract_runs_full_stream_lifecycle() {
+    let env = Env::default();
+    let (token, _) = create_token(&env);
+    let recipient = Address::generate(&env);
+    let client = create_contract(&env);
+    let treasury_id = env.register(MockTreasury, ());
+    let treasury = MockTreasuryClient::new(&env, &treasury_id);
+    env.mock_all_auths();
+    mint(&env, &token, &treasury_id, 2_000);
+
+    // From here on only real authorizations count: the treasury authorizes
+    // itself as the invoker and pre-authorizes each token pull.
+    env.set_auths(&[]);
+    let id = treasury.open_stream(&client.address, &token, &recipient, &1_000, &100);
+    assert_eq!(client.get_stream(&id).unwrap().sender, treasury_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    treasury.top_up(&client.address, &token, &id, &500);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_500);
+
+    treasury.cancel(&client.address, &id);
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&recipient), 500);
+    assert_eq!(token_client.balance(&treasury_id), 1_500);
+    assert!(!client.get_stream(&id).unwrap().is_active());
+}
+
+#[test]
+fn test_treasury_contract_must_authorize_token_pull() {
+    let env = Env::default();
+    let (token, _) = create_token(&env);
+    let client = create_contract(&env);
+    let treasury_id = env.register(MockTreasury, ());
+    let treasury = MockTreasuryClient::new(&env, &treasury_id);
+    env.mock_all_auths();
+    mint(&env, &token, &treasury_id, 1_000);
+
+    env.set_auths(&[]);
+    let result = treasury.try_open_stream_unauthorized(
+        &client.address,
+        &token,
+        &Address::generate(&env),
+        &1_000,
+        &100,
+    );
+    assert!(result.is_err());
+}
+
+// ─── Keepers ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_settle_expired_pays_residue_and_bounty() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_keeper_bounty(&admin, &100);
+
+    let ended = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let running = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    client.withdraw(&recipient, &ended);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+
+    let settled = client.settle_expired(&keeper, &vec![&env, ended, running, 99]);
+    assert_eq!(settled, vec![&env, ended]);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&keeper), 6);
+    assert_eq!(token_client.balance(&recipient), 400 + 594);
+    let stream = client.get_stream(&ended).unwrap();
+    assert!(!stream.is_active());
+    assert_eq!(stream.withdrawn_amount, 1_000);
+    assert!(client.get_stream(&running).unwrap().is_active());
+
+    // Already-settled streams are skipped.
+    assert_eq!(client.settle_expired(&keeper, &vec![&env, ended]).len(), 0);
+}
+
+#[test]
+fn test_settle_expired_charges_withdrawal_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &0);
+    client.set_keeper_bounty(&admin, &100);
+    client.set_withdrawal_fee(&admin, &100);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    assert_eq!(
+        client.settle_expired(&keeper, &vec![&env, id]),
+        vec![&env, id]
+    );
+
+    // The recipient pays the same 1% fee as on a withdrawal, plus the bounty.
+    let token_client = token::Client::new(&env, &token);
+    client.claim_fees(&token);
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert_eq!(token_client.balance(&keeper), 10);
+    assert_eq!(token_client.balance(&recipient), 980);
+}
+
+#[test]
+fn test_settle_expired_skips_capped_and_auto_claimed_streams() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+
+    let capped_options = StreamOptions {
+        withdrawal_cap: 100,
+        withdrawal_cap_period: 300,
+        ..StreamOptions::new(&env)
+    };
+    let capped = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &capped_options,
+    );
+    let cooled_options = StreamOptions {
+        withdrawal_cooldown: 500,
+        ..StreamOptions::new(&env)
+    };
+    let cooled = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &cooled_options,
+    );
+    let scheduled = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.set_auto_claim(&recipient, &scheduled, &50, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    assert_eq!(
+        client
+            .settle_expired(&keeper, &vec![&env, capped, cooled, scheduled])
+            .len(),
+        0
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&recipient), 0);
+
+    // Once the cooldown has passed, the keeper may settle that stream.
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(
+        client.settle_expired(&keeper, &vec![&env, capped, cooled]),
+        vec![&env, cooled]
+    );
+}
+
+#[test]
+fn test_set_keeper_bounty_rejects_excessive_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+
+    assert_eq!(
+        client.try_set_keeper_bounty(&admin, &101),
+        Err(Ok(StreamError::InvalidFeeRate))
+    );
+    assert_eq!(client.get_keeper_bounty(), 0);
+}
+
+// ─── Protocol Limits ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_limits_reports_constants_and_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = create_contract(&env);
+
+    let limits = client.get_limits();
+    assert_eq!(limits.max_fee_rate_bps, 1_000);
+    assert_eq!(limits.fee_rate_bps, 0);
+    assert_eq!(limits.min_amount, 1);
+    assert_eq!(limits.max_tags, 5);
+
+    client.initialize(&admin, &Address::generate(&env), &250);
+    client.set_keeper_bounty(&admin, &50);
+    let limits = client.get_limits();
+    assert_eq!(limits.fee_rate_bps, 250);
+    assert_eq!(limits.keeper_bounty_bps, 50);
+}
+
+// ─── Event Topics ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_stream_events_carry_sender_and_recipient_topics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.withdraw(&recipient, &id);
+
+    let events = env.events().all();
+    let withdrawn = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "tokens_withdrawn")
+        })
+        .expect("tokens_withdrawn event not found");
+    let topics = withdrawn.1;
+    assert_eq!(topics.len(), 4);
+    assert_eq!(
+        u64::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+        id
+    );
+    assert_eq!(
+        Address::try_from_val(&env, &topics.get(2).unwrap()).unwrap(),
+        sender
+    );
+    assert_eq!(
+        Address::try_from_val(&env, &topics.get(3).unwrap()).unwrap(),
+        recipient
+    );
+}
+
+// ─── Stream Notes ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_stream_note_set_by_either_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    let note = Bytes::from_array(&env, &[0xab; 64]);
+    client.set_stream_note(&sender, &id, &note);
+    assert_eq!(client.get_stream_note(&id), Some(note));
+
+    let reply = Bytes::from_array(&env, &[0xcd; 16]);
+    client.set_stream_note(&recipient, &id, &reply);
+    assert_eq!(client.get_stream_note(&id), Some(reply));
+
+    client.set_stream_note(&recipient, &id, &Bytes::new(&env));
+    assert_eq!(client.get_stream_note(&id), None);
+
+    assert_eq!(
+        client.try_set_stream_note(&Address::generate(&env), &id, &Bytes::new(&env)),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_stream_note(&sender, &id, &Bytes::from_array(&env, &[0; 1_025])),
+        Err(Ok(StreamError::NoteTooLarge))
+    );
+}
+
+// ─── Token Index ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_streams_by_token_pages_in_creation_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token_a, _) = create_token(&env);
+    let (token_b, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token_a, &sender, 3_000);
+    mint(&env, &token_b, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let a1 = client.create_stream(&sender, &recipient, &token_a, &1_000, &100);
+    let b1 = client.create_stream(&sender, &recipient, &token_b, &1_000, &100);
+    let a2 = client.create_stream(&sender, &recipient, &token_a, &1_000, &100);
+    let a3 = client.create_stream(&sender, &recipient, &token_a, &1_000, &100);
+
+    assert_eq!(
+        client.get_streams_by_token(&token_a, &0, &10),
+        vec![&env, a1, a2, a3]
+    );
+    assert_eq!(
+        client.get_streams_by_token(&token_a, &1, &1),
+        vec![&env, a2]
+    );
+    assert_eq!(
+        client.get_streams_by_token(&token_b, &0, &10),
+        vec![&env, b1]
+    );
+    assert_eq!(client.get_streams_by_token(&token_a, &5, &10).len(), 0);
+}
+
+#[test]
+fn test_token_deposit_cap_limits_creation_and_top_up() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 5_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_token_deposit_cap(&admin, &token, &Some(1_500));
+    assert_eq!(client.get_token_deposit_cap(&token), Some(1_500));
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &600, &100),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+    assert_eq!(
+        client.try_top_up_stream(&sender, &id, &501),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+    client.top_up_stream(&sender, &id, &500);
+
+    // Lifting the cap re-opens deposits.
+    client.set_token_deposit_cap(&admin, &token, &None);
+    client.create_stream(&sender, &recipient, &token, &600, &100);
+    assert_eq!(client.get_accounted_balance(&token), 2_100);
+}
+
+#[test]
+fn test_stream_deposit_cap_limits_top_ups() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 5_000);
+    let client = create_contract(&env);
+
+    let capped = |deposit_cap| StreamOptions {
+        deposit_cap,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(
+            &sender,
+            &recipient,
+            &token,
+            &1_000,
+            &100,
+            &capped(999)
+        ),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+    let id = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &capped(1_500),
+    );
+    assert_eq!(client.get_remaining_capacity(&id), Some(500));
+
+    client.top_up_stream(&sender, &id, &300);
+    assert_eq!(client.get_remaining_capacity(&id), Some(200));
+    assert_eq!(
+        client.try_top_up_extend(&sender, &id, &201),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+    client.top_up_extend(&sender, &id, &200);
+    assert_eq!(client.get_remaining_capacity(&id), Some(0));
+
+    let uncapped = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_remaining_capacity(&uncapped), None);
+}
+
+// ─── Withdrawal Fee ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_withdrawal_fee_goes_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &0);
+    assert_eq!(
+        client.try_set_withdrawal_fee(&admin, &101),
+        Err(Ok(StreamError::InvalidFeeRate))
+    );
+
+    let before = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.set_withdrawal_fee(&admin, &100); // 1%
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_stream(&id).unwrap().withdrawal_fee_bps, 100);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    assert_eq!(client.withdraw(&recipient, &id), 500);
+
+    let events = env.events().all();
+    let withdrawn = events
+        .iter()
+        .find(|e| {
+            Symbol::try_from_val(&env, &e.1.get(0).unwrap()).unwrap()
+                == Symbol::new(&env, "tokens_withdrawn")
+        })
+        .unwrap();
+    let payload = TokensWithdrawnEvent::try_from_val(&env, &withdrawn.2).unwrap();
+    assert_eq!(payload.amount, 500);
+    assert_eq!(payload.withdrawal_fee, 5);
+
+    let token_client = token::Client::new(&env, &token);
+    client.claim_fees(&token);
+    assert_eq!(token_client.balance(&treasury), 5);
+    assert_eq!(token_client.balance(&recipient), 495);
+
+    // Streams created before the fee keep their zero rate.
+    client.withdraw(&recipient, &before);
+    assert_eq!(token_client.balance(&treasury), 5);
+}
+
+// ─── Relayed Withdrawals ──────────────────────────────────────────────────────
+
+#[test]
+fn test_withdraw_for_reimburses_relayer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_set_relayer_fee(&recipient, &id, &5),
+        Err(Ok(StreamError::NotSender))
+    );
+    client.set_relayer_fee(&sender, &id, &5);
+
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    assert_eq!(client.withdraw_for(&relayer, &id, &5), 300);
+
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&relayer), 5);
+    assert_eq!(token_client.balance(&recipient), 295);
+    assert_eq!(client.get_stream(&id).unwrap().withdrawn_amount, 300);
+
+    // A direct withdrawal pays no relayer fee.
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+    assert_eq!(token_client.balance(&recipient), 395);
+
+    // A claim that cannot cover the fee is rejected.
+    client.set_relayer_fee(&sender, &id, &50);
+    env.ledger().with_mut(|li| li.timestamp = 45);
+    assert_eq!(
+        client.try_withdraw_for(&relayer, &id, &50),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_withdraw_for_caps_relayer_fee_at_recipient_signed_maximum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.set_relayer_fee(&sender, &id, &5);
+
+    // The recipient signs a claim allowing at most 5; the sender then
+    // raises the fee before the relayer submits it.
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.set_relayer_fee(&sender, &id, &290);
+    assert_eq!(
+        client.try_withdraw_for(&relayer, &id, &5),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(token_client.balance(&relayer), 0);
+
+    client.set_relayer_fee(&sender, &id, &5);
+    assert_eq!(client.withdraw_for(&relayer, &id, &5), 300);
+    // The signed maximum is part of what the recipient authorized.
+    let auths = env.auths();
+    let (_, invocation) = auths.iter().find(|(a, _)| *a == recipient).unwrap();
+    let AuthorizedFunction::Contract((_, _, args)) = &invocation.function else {
+        panic!("expected a contract call");
+    };
+    assert_eq!(i128::try_from_val(&env, &args.get(2).unwrap()).unwrap(), 5);
+    assert_eq!(token_client.balance(&recipient), 295);
+}
+
+// ─── Rate Units ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_stream_rate_converts_to_whole_tokens_per_day() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 100_000_000_000);
+    let client = create_contract(&env);
+
+    // 8 640 tokens (7 decimals) over one day: 1 000 000 stroops per second.
+    let id = client.create_stream(
+        &sender,
+        &Address::generate(&env),
+        &token,
+        &86_400_000_000,
+        &86_400,
+    );
+    assert_eq!(client.get_stream(&id).unwrap().token_decimals, 7);
+
+    let rate = client.get_stream_rate(&id).unwrap();
+    assert_eq!(rate.rate_per_second, 1_000_000);
+    assert_eq!(rate.rate_per_day, 86_400_000_000);
+    assert_eq!(rate.decimals, 7);
+    assert_eq!(rate.whole_tokens_per_day, 8_640);
+    assert_eq!(rate.per_day_remainder, 0);
+    assert_eq!(client.get_stream_rate(&99), None);
+}
+
+#[test]
+fn test_token_info_is_cached_on_first_use() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+
+    assert_eq!(client.get_token_info(&token), None);
+    client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    let info = client.get_token_info(&token).unwrap();
+    assert_eq!(info.decimals, 7);
+    assert!(info.symbol.is_some());
+
+    // Later streams in the same token reuse the cached decimals.
+    let id = client.create_stream(&sender, &Address::generate(&env), &token, &1_000, &100);
+    assert_eq!(client.get_stream(&id).unwrap().token_decimals, 7);
+    assert_eq!(client.get_token_info(&Address::generate(&env)), None);
+}
+
+// ─── Dry Runs ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_simulations_match_real_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &100);
+    client.set_withdrawal_fee(&admin, &100);
+
+    let options = StreamOptions::new(&env);
+    let preview =
+        client.simulate_create_stream(&sender, &recipient, &token, &1_000, &100, &options);
+    assert_eq!(preview.fee_amount, 10);
+    assert_eq!(preview.net_amount, 990);
+    assert_eq!(preview.rate_per_second, 9);
+    assert_eq!(preview.end_time, 100);
+    // Nothing was written.
+    assert_eq!(client.get_accounted_balance(&token), 0);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.get_stream(&id).unwrap().deposited_amount,
+        preview.net_amount
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    let withdraw_preview = client.simulate_withdraw(&id);
+    assert_eq!(withdraw_preview.amount, 495);
+    assert_eq!(withdraw_preview.withdrawal_fee, 4);
+    assert_eq!(withdraw_preview.recipient_amount, 491);
+    assert_eq!(withdraw_preview.remaining_balance, 495);
+    assert!(!withdraw_preview.closes_stream);
+
+    env.ledger().with_mut(|li| li.timestamp = 60);
+    let cancel_preview = client.simulate_cancel(&id);
+    assert_eq!(cancel_preview.recipient_amount, 594);
+    assert_eq!(cancel_preview.refunded_amount, 396);
+
+    let token_client = token::Client::new(&env, &token);
+    let sender_before = token_client.balance(&sender);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&recipient), 594);
+    assert_eq!(token_client.balance(&sender) - sender_before, 396);
+
+    assert_eq!(
+        client.try_simulate_create_stream(&sender, &recipient, &token, &50, &100, &options),
+        Err(Ok(StreamError::RateTooLow))
+    );
+}
+
+// ─── Statistics ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_volume_counts_withdrawals_and_cancellation_payouts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let other_sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    mint(&env, &token, &other_sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let other_id = client.create_stream(&other_sender, &recipient, &token, &1_000, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &id);
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    client.cancel_stream(&sender, &id);
+    client.withdraw(&recipient, &other_id);
+
+    // 300 withdrawn + 100 paid at cancellation; the 600 refund is excluded.
+    assert_eq!(client.get_sender_volume(&sender, &token), 400);
+    assert_eq!(client.get_sender_volume(&other_sender, &token), 400);
+    assert_eq!(client.get_token_volume(&token), 800);
+    assert_eq!(client.get_token_volume(&Address::generate(&env)), 0);
+}
+
+// ─── Lifecycle ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_only_active_streams_transition_and_only_to_terminal_statuses() {
+    let statuses = [
+        StreamStatus::Active,
+        StreamStatus::Completed,
+        StreamStatus::Cancelled,
+        StreamStatus::Settled,
+    ];
+    for from in statuses {
+        for to in statuses {
+            let allowed = from == StreamStatus::Active && to != StreamStatus::Active;
+            assert_eq!(from.can_transition_to(to), allowed, "{from:?} -> {to:?}");
+        }
+    }
+}
+
+#[test]
+fn test_each_exit_path_records_its_terminal_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+
+    let drained = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let cancelled = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let expired = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    client.cancel_stream(&sender, &cancelled);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.withdraw(&recipient, &drained);
+    client.settle_expired(&keeper, &vec![&env, expired]);
+
+    let status = |id: u64| client.get_stream(&id).unwrap().status;
+    assert_eq!(status(drained), StreamStatus::Completed);
+    assert_eq!(status(cancelled), StreamStatus::Cancelled);
+    assert_eq!(status(expired), StreamStatus::Settled);
+
+    // Terminal statuses are final, whichever exit path is tried next.
+    for id in [drained, cancelled, expired] {
+        assert_eq!(
+            client.try_cancel_stream(&sender, &id),
+            Err(Ok(StreamError::StreamInactive))
+        );
+        assert_eq!(
+            client.try_withdraw(&recipient, &id),
+            Err(Ok(StreamError::StreamInactive))
+        );
+    }
+    assert_eq!(
+        client
+            .settle_expired(&keeper, &vec![&env, drained, cancelled, expired])
+            .len(),
+        0
+    );
+    assert_eq!(status(expired), StreamStatus::Settled);
+}
+
+// ─── Minimum Claim ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_withdrawals_below_minimum_claim_are_rejected_until_final() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_min_claim_amount(&admin, &100);
+    assert_eq!(client.get_limits().min_claim_amount, 100);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 5);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::ClaimBelowMinimum))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 95);
+    assert_eq!(client.withdraw(&recipient, &id), 950);
+
+    // The last 50 empties the stream, so it is allowed.
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.withdraw(&recipient, &id), 50);
+
+    assert_eq!(
+        client.try_set_min_claim_amount(&admin, &-1),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+}
+
+// ─── Auto-Claim ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_auto_claim_only_runs_on_the_recipient_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let vault = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    assert_eq!(
+        client.try_execute_auto_claim(&id),
+        Err(Ok(StreamError::RecordNotFound))
+    );
+    assert_eq!(
+        client.try_set_auto_claim(&sender, &id, &30, &None),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    client.set_auto_claim(&recipient, &id, &30, &Some(vault.clone()));
+
+    env.ledger().with_mut(|li| li.timestamp = 29);
+    assert_eq!(
+        client.try_execute_auto_claim(&id),
+        Err(Ok(StreamError::ClaimNotDue))
+    );
+
+    // A keeper arriving late skips the missed slot at t=60.
+    env.ledger().with_mut(|li| li.timestamp = 65);
+    assert_eq!(client.execute_auto_claim(&id), 650);
+    assert_eq!(client.get_auto_claim(&id).unwrap().next_claim_time, 90);
+    assert_eq!(
+        client.try_execute_auto_claim(&id),
+        Err(Ok(StreamError::ClaimNotDue))
+    );
+    assert_eq!(token::Client::new(&env, &token).balance(&vault), 650);
+
+    client.set_auto_claim(&recipient, &id, &0, &None);
+    assert_eq!(client.get_auto_claim(&id), None);
+}
+
+// ─── Payee Allowlists ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_payee_allowlist_rejects_unlisted_recipients() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let org = Address::generate(&env);
+    let vetted = Address::generate(&env);
+    let unvetted = Address::generate(&env);
+    mint(&env, &token, &org, 2_000);
+    let client = create_contract(&env);
+
+    client.set_payee_allowlist_enabled(&org, &true);
+    client.set_payee_allowed(&org, &vetted, &true);
+    assert!(client.is_payee_allowed(&org, &vetted));
+    assert!(!client.is_payee_allowed(&org, &unvetted));
+
+    client.create_stream(&org, &vetted, &token, &1_000, &100);
+    assert_eq!(
+        client.try_create_stream(&org, &unvetted, &token, &1_000, &100),
+        Err(Ok(StreamError::PayeeNotAllowed))
+    );
+
+    // Other senders are unaffected by the org's list.
+    let other = Address::generate(&env);
+    assert!(client.is_payee_allowed(&other, &unvetted));
+
+    client.set_payee_allowlist_enabled(&org, &false);
+    client.create_stream(&org, &unvetted, &token, &1_000, &100);
+}
+
+// ─── Stream Groups ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_group_top_up_is_pro_rata_and_cancel_covers_every_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+
+    let group = Symbol::new(&env, "project_x");
+    let options = StreamOptions {
+        group: Some(group.clone()),
+        ..StreamOptions::new(&env)
+    };
+    let a = client.create_stream_with_options(&sender, &alice, &token, &1_000, &100, &options);
+    let b = client.create_stream_with_options(&sender, &bob, &token, &3_000, &100, &options);
+    let outside = client.create_stream(&sender, &bob, &token, &1_000, &100);
+    assert_eq!(client.get_group_streams(&sender, &group), vec![&env, a, b]);
+
+    // Rates 10 and 30: a 400 top-up splits 100 / 300, adding 10s to both.
+    assert_eq!(client.top_up_group(&sender, &group, &400), 400);
+    let (sa, sb) = (
+        client.get_stream(&a).unwrap(),
+        client.get_stream(&b).unwrap(),
+    );
+    assert_eq!((sa.deposited_amount, sb.deposited_amount), (1_100, 3_300));
+    assert_eq!((sa.end_time, sb.end_time), (110, 110));
+
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    assert_eq!(client.cancel_group(&sender, &group), vec![&env, a, b]);
+    assert!(!client.get_stream(&a).unwrap().is_active());
+    assert!(!client.get_stream(&b).unwrap().is_active());
+    assert!(client.get_stream(&outside).unwrap().is_active());
+
+    assert_eq!(
+        client.try_cancel_group(&sender, &group),
+        Err(Ok(StreamError::RecordNotFound))
+    );
+}
+
+#[test]
+fn test_split_stream_pays_each_recipient_its_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let group = Symbol::new(&env, "payroll");
+    let options = StreamOptions {
+        group: Some(group.clone()),
+        ..StreamOptions::new(&env)
+    };
+    for shares in [
+        vec![&env],
+        vec![&env, (alice.clone(), 5_000), (bob.clone(), 4_000)],
+        vec![&env, (alice.clone(), 5_000), (alice.clone(), 5_000)],
+        vec![&env, (alice.clone(), 10_000), (bob.clone(), 0)],
+    ] {
+        assert_eq!(
+            client.try_create_split_stream(&sender, &token, &1_000, &100, &shares, &options),
+            Err(Ok(StreamError::InvalidSplit))
+        );
+    }
+
+    let shares = vec![&env, (alice.clone(), 2_500), (bob.clone(), 7_500)];
+    let ids = client.create_split_stream(&sender, &token, &1_000, &100, &shares, &options);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(
+        client
+            .get_stream(&ids.get(0).unwrap())
+            .unwrap()
+            .deposited_amount,
+        250
+    );
+    assert_eq!(
+        client
+            .get_stream(&ids.get(1).unwrap())
+            .unwrap()
+            .deposited_amount,
+        750
+    );
+
+    // Each recipient withdraws its own share independently.
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.withdraw(&alice, &ids.get(0).unwrap()), 100);
+    assert_eq!(
+        client.try_withdraw(&alice, &ids.get(1).unwrap()),
+        Err(Ok(StreamError::NotRecipient))
+    );
+
+    // Cancelling the group settles every party pro rata.
+    assert_eq!(client.cancel_group(&sender, &group), ids);
+    assert_eq!(token_client.balance(&alice), 100);
+    assert_eq!(token_client.balance(&bob), 300);
+    assert_eq!(token_client.balance(&sender), 9_600);
+}
+
+#[test]
+fn test_split_stream_scales_cap_and_rejects_overflowing_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+
+    let shares = vec![&env, (alice.clone(), 2_500), (bob.clone(), 7_500)];
+    let options = StreamOptions::new(&env);
+    assert_eq!(
+        client.try_create_split_stream(&sender, &token, &(i128::MAX / 2), &100, &shares, &options),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    let huge_bonus = StreamOptions {
+        bonus_amount: i128::MAX / 2,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_split_stream(&sender, &token, &1_000, &100, &shares, &huge_bonus),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+
+    // Each share is capped at its own part of the cap.
+    let capped = StreamOptions {
+        deposit_cap: 4_000,
+        ..StreamOptions::new(&env)
+    };
+    let ids = client.create_split_stream(&sender, &token, &1_000, &100, &shares, &capped);
+    assert_eq!(
+        client.get_remaining_capacity(&ids.get(0).unwrap()),
+        Some(750)
+    );
+    assert_eq!(
+        client.get_remaining_capacity(&ids.get(1).unwrap()),
+        Some(2_250)
+    );
+    assert_eq!(
+        client.try_top_up_stream(&sender, &ids.get(0).unwrap(), &1_000),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+}
+
+// ─── Organizations ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_org_managers_act_within_their_permissions_without_org_keys() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let org = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let hr = Address::generate(&env);
+    let intern = Address::generate(&env);
+    let employee = Address::generate(&env);
+    mint(&env, &token, &org, 5_000);
+    let client = create_contract(&env);
+    token::Client::new(&env, &token).approve(&org, &client.address, &3_000, &1_000);
+
+    client.register_org(&org, &owner);
+    let hr_permissions = ManagerPermissions {
+        can_create: true,
+        can_top_up: true,
+        can_cancel: false,
+    };
+    client.set_org_manager(&owner, &org, &hr, &Some(hr_permissions.clone()));
+    assert_eq!(client.get_org_manager(&org, &hr), Some(hr_permissions));
+    assert_eq!(
+        client.try_set_org_manager(&hr, &org, &intern, &None),
+        Err(Ok(StreamError::Unauthorized))
+    );
+
+    let options = StreamOptions::new(&env);
+    let id = client.manager_create_stream(&hr, &org, &employee, &token, &1_000, &100, &options);
+    // Only the manager signed; the org's key was never needed.
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert_eq!(signers, std::vec![hr.clone()]);
+    assert_eq!(client.get_stream(&id).unwrap().sender, org);
+
+    client.manager_top_up_stream(&hr, &org, &id, &500);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_500);
+    assert_eq!(token::Client::new(&env, &token).balance(&org), 3_500);
+
+    assert_eq!(
+        client.try_manager_cancel_stream(&hr, &org, &id),
+        Err(Ok(StreamError::ManagerNotAuthorized))
+    );
+    assert_eq!(
+        client.try_manager_create_stream(&intern, &org, &employee, &token, &1_000, &100, &options),
+        Err(Ok(StreamError::ManagerNotAuthorized))
+    );
+
+    client.set_org_manager(&owner, &org, &hr, &None);
+    assert_eq!(client.get_org_manager(&org, &hr), None);
+}
+
+#[test]
+fn test_manager_budget_is_drawn_down_and_replenished_by_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let org = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let manager = Address::generate(&env);
+    let employee = Address::generate(&env);
+    mint(&env, &token, &org, 10_000);
+    let client = create_contract(&env);
+    token::Client::new(&env, &token).approve(&org, &client.address, &10_000, &1_000);
+
+    client.register_org(&org, &owner);
+    let permissions = ManagerPermissions {
+        can_create: true,
+        can_top_up: true,
+        can_cancel: true,
+    };
+    client.set_org_manager(&owner, &org, &manager, &Some(permissions));
+    client.set_manager_budget(&owner, &org, &manager, &token, &1_500, &1_000);
+
+    let options = StreamOptions::new(&env);
+    let id =
+        client.manager_create_stream(&manager, &org, &employee, &token, &1_000, &100, &options);
+    assert_eq!(
+        client.get_manager_budget_remaining(&org, &manager, &token),
+        Some(500)
+    );
+    assert_eq!(
+        client.try_manager_top_up_stream(&manager, &org, &id, &600),
+        Err(Ok(StreamError::BudgetExceeded))
+    );
+
+    assert_eq!(
+        client.try_replenish_manager_budget(&manager, &org, &manager, &token, &100),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(
+        client.replenish_manager_budget(&owner, &org, &manager, &token, &100),
+        600
+    );
+    client.manager_top_up_stream(&manager, &org, &id, &600);
+    assert_eq!(
+        client.get_manager_budget_remaining(&org, &manager, &token),
+        Some(0)
+    );
+
+    // A new epoch restores the envelope.
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(
+        client.get_manager_budget_remaining(&org, &manager, &token),
+        Some(1_500)
+    );
+}
+
+// ─── Fee History ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_fee_history_records_terms_of_each_charge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let new_treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &100);
+    client.set_withdrawal_fee(&admin, &50);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.update_fee_config(&admin, &new_treasury, &300);
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.top_up_stream(&sender, &id, &500);
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.withdraw(&recipient, &id);
+
+    let history = client.get_fee_history(&id);
+    assert_eq!(history.len(), 3);
+    let creation = history.get(0).unwrap();
+    assert_eq!(creation.kind, FeeKind::Deposit);
+    assert_eq!(
+        (
+            creation.gross_amount,
+            creation.fee_rate_bps,
+            creation.fee_amount
+        ),
+        (1_000, 100, 10)
+    );
+    assert_eq!(creation.treasury, treasury);
+    // The top-up keeps the locked-in rate but pays the current treasury.
+    let top_up = history.get(1).unwrap();
+    assert_eq!(
+        (top_up.timestamp, top_up.fee_rate_bps, top_up.fee_amount),
+        (10, 100, 5)
+    );
+    assert_eq!(top_up.treasury, new_treasury);
+    let withdrawal = history.get(2).unwrap();
+    assert_eq!(withdrawal.kind, FeeKind::Withdrawal);
+    assert_eq!(withdrawal.fee_rate_bps, 50);
+    assert_eq!(withdrawal.fee_amount, withdrawal.gross_amount * 50 / 10_000);
+
+    assert_eq!(client.get_fee_history(&999).len(), 0);
+}
+
+// ─── Idle Yield ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_idle_yield_is_split_per_policy_at_withdrawal_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let adapter = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    mint(&env, &token, &adapter, 1_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_yield_adapter(&admin, &Some(adapter.clone()));
+
+    let options = StreamOptions {
+        yield_policy: YieldPolicy::Split(2_500),
+        ..StreamOptions::new(&env)
+    };
+    let id = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options);
+    assert_eq!(
+        client.try_report_yield(&sender, &id, &100),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    client.report_yield(&adapter, &id, &100);
+    assert_eq!(client.get_stream(&id).unwrap().pending_yield, 100);
+
+    let token_client = token::Client::new(&env, &token);
+    let sender_before = token_client.balance(&sender);
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.withdraw(&recipient, &id);
+    // 100 streamed plus a quarter of the yield.
+    assert_eq!(token_client.balance(&recipient), 125);
+    assert_eq!(token_client.balance(&sender) - sender_before, 75);
+    assert_eq!(client.get_stream(&id).unwrap().pending_yield, 0);
+
+    client.report_yield(&adapter, &id, &40);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&recipient), 135);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+
+    let bad = StreamOptions {
+        yield_policy: YieldPolicy::Split(10_001),
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(&sender, &recipient, &token, &500, &100, &bad),
+        Err(Ok(StreamError::InvalidFeeRate))
+    );
+}
+
+// ─── Migration ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_migrate_streams_upgrades_legacy_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &250);
+    client.set_withdrawal_fee(&admin, &100);
+    mint(&env, &token, &client.address, 1_000);
+
+    let legacy = |is_active: bool, withdrawn_amount: i128| StreamV1 {
+        sender: sender.clone(),
+        recipient: recipient.clone(),
+        token_address: token.clone(),
+        rate_per_second: 10,
+        deposited_amount: 1_000,
+        withdrawn_amount,
+        start_time: 0,
+        last_update_time: 0,
+        is_active,
+    };
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Stream(1), &legacy(true, 0));
+        storage.set(&DataKey::Stream(2), &legacy(false, 1_000));
+    });
+
+    assert_eq!(
+        client.try_migrate_streams(&sender, &0, &10),
+        Err(Ok(StreamError::NotAdmin))
+    );
+    assert_eq!(client.migrate_streams(&admin, &0, &10), 2);
+    // Already-current records are left alone.
+    assert_eq!(client.migrate_streams(&admin, &0, &10), 0);
+
+    let stream = client.get_stream(&1).unwrap();
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(stream.end_time, 100);
+    assert_eq!(stream.token_decimals, 7);
+    // Fees are snapshotted from the config, as at creation.
+    assert_eq!(stream.fee_rate_bps, 250);
+    assert_eq!(stream.withdrawal_fee_bps, 100);
+    assert_eq!(
+        client.get_stream(&2).unwrap().status,
+        StreamStatus::Completed
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &1);
+    assert_eq!(token::Client::new(&env, &token).balance(&recipient), 297);
+    // The 3 withdrawal fee stays accounted until the treasury claims it.
+    assert_eq!(client.get_accounted_balance(&token), 703);
+}
+
+// ─── Recent Events ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_recent_events_ring_buffer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 100_000);
+    let client = create_contract(&env);
+
+    assert_eq!(client.get_recent_events(&10).len(), 0);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.top_up_stream(&sender, &id, &500);
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.withdraw(&recipient, &id);
+    client.cancel_stream(&sender, &id);
+
+    let events = client.get_recent_events(&10);
+    let kinds: std::vec::Vec<_> = events.iter().map(|e| (e.seq, e.kind, e.amount)).collect();
+    assert_eq!(
+        kinds,
+        [
+            (3, ActivityKind::Cancelled, 1_400),
+            (2, ActivityKind::Withdrawn, 100),
+            (1, ActivityKind::ToppedUp, 500),
+            (0, ActivityKind::Created, 1_000),
+        ]
+    );
+    assert_eq!(client.get_recent_events(&1).get(0).unwrap().seq, 3);
+
+    // Older records are overwritten once the buffer is full.
+    for _ in 0..40 {
+        client.create_stream(&sender, &recipient, &token, &100, &100);
+    }
+    let events = client.get_recent_events(&100);
+    assert_eq!(events.len(), 32);
+    assert_eq!(events.get(0).unwrap().seq, 43);
+    assert_eq!(events.get(31).unwrap().seq, 12);
+}
+
+// ─── Depletion Warnings ───────────────────────────────────────────────────────
+
+#[test]
+fn test_stream_depleting_emitted_below_warning_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    assert_eq!(client.get_limits().depletion_warning_period, 7 * 86_400);
+
+    let depleting = |env: &Env| -> Option<StreamDepletingEvent> {
+        env.events()
+            .all()
+            .iter()
+            .find(|e| {
+                Symbol::try_from_val(env, &e.1.get(0).unwrap()).unwrap()
+                    == Symbol::new(env, "stream_depleting")
+            })
+            .map(|e| StreamDepletingEvent::try_from_val(env, &e.2).unwrap())
+    };
+
+    // 30 days of runway.
+    let id = client.create_stream(&sender, &recipient, &token, &2_592_000, &(30 * 86_400));
+    env.ledger().with_mut(|li| li.timestamp = 86_400);
+    client.withdraw(&recipient, &id);
+    assert_eq!(depleting(&env), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 25 * 86_400);
+    client.withdraw(&recipient, &id);
+    assert_eq!(
+        depleting(&env),
+        Some(StreamDepletingEvent {
+            stream_id: id,
+            runway: 5 * 86_400,
+            end_time: 30 * 86_400,
+        })
+    );
+
+    client.set_depletion_warning_period(&admin, &0);
+    env.ledger().with_mut(|li| li.timestamp = 26 * 86_400);
+    client.withdraw(&recipient, &id);
+    assert_eq!(depleting(&env), None);
+}
+
+// ─── Fee Sponsorship ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_fee_credit_pays_sponsored_sender_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let other_sponsor = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000);
+    mint(&env, &token, &sponsor, 1_000);
+    mint(&env, &token, &other_sponsor, 1_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &250);
+
+    assert_eq!(client.fund_fee_credit(&sponsor, &sender, &token, &30), 30);
+    assert_eq!(
+        client.try_fund_fee_credit(&other_sponsor, &sender, &token, &10),
+        Err(Ok(StreamError::Unauthorized))
+    );
+
+    // The 25 fee is fully covered, so the whole deposit streams.
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_000);
+    assert_eq!(client.get_fee_credit(&sender, &token).unwrap().balance, 5);
+
+    // Only 5 of the next fee is covered, and the spent credit is removed.
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 980);
+    assert_eq!(client.get_fee_credit(&sender, &token), None);
+
+    let token_client = token::Client::new(&env, &token);
+    client.claim_fees(&token);
+    assert_eq!(token_client.balance(&treasury), 50);
+
+    client.fund_fee_credit(&other_sponsor, &sender, &token, &100);
+    assert_eq!(
+        client.try_reclaim_fee_credit(&sponsor, &sender, &token),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(
+        client.reclaim_fee_credit(&other_sponsor, &sender, &token),
+        100
+    );
+    assert_eq!(token_client.balance(&other_sponsor), 1_000);
+    assert_eq!(client.get_accounted_balance(&token), 1_980);
+}
+
+// ─── Custom Fees ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_custom_fee_rate_applies_to_reserved_stream_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &250);
+
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    assert_eq!(
+        client.try_set_custom_fee_rate(&admin, &sender, &salt, &Some(1_001)),
+        Err(Ok(StreamError::InvalidFeeRate))
+    );
+    client.set_custom_fee_rate(&admin, &sender, &salt, &Some(50));
+    assert_eq!(client.get_custom_fee_rate(&sender, &salt), Some(50));
+
+    let id = client.create_stream_with_salt(&sender, &recipient, &token, &1_000, &100, &salt);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.fee_rate_bps, 50);
+    assert_eq!(stream.deposited_amount, 995);
+    assert_eq!(client.get_custom_fee_rate(&sender, &salt), None);
+    assert_eq!(
+        client.try_set_custom_fee_rate(&admin, &sender, &salt, &Some(50)),
+        Err(Ok(StreamError::DuplicateSalt))
+    );
+
+    // Other streams keep the global rate.
+    let other = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_stream(&other).unwrap().fee_rate_bps, 250);
+}
+
+// ─── Restarts ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_restart_stream_re_escrows_refunded_remainder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_restart_stream(&sender, &id, &80),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    client.cancel_stream(&sender, &id);
+
+    assert_eq!(
+        client.try_restart_stream(&recipient, &id, &80),
+        Err(Ok(StreamError::NotSender))
+    );
+    let continuation = client.restart_stream(&sender, &id, &80);
+    let stream = client.get_stream(&continuation).unwrap();
+    assert_eq!(stream.recipient, recipient);
+    assert_eq!(stream.deposited_amount, 800);
+    assert_eq!(stream.rate_per_second(), 10);
+    assert_eq!(client.get_restarted_as(&id), Some(continuation));
+    assert_eq!(client.get_restarted_from(&continuation), Some(id));
+    assert_eq!(
+        client.try_restart_stream(&sender, &id, &80),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+
+    // Past the window a cancellation is final.
+    client.cancel_stream(&sender, &continuation);
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    assert_eq!(
+        client.try_restart_stream(&sender, &continuation, &80),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+}
+
+// ─── Merging ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_merge_streams_pools_balances_and_rates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    let (other_token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000);
+    mint(&env, &other_token, &sender, 10_000);
+    let client = create_contract(&env);
+
+    let a = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let b = client.create_stream(&sender, &recipient, &token, &2_000, &100);
+    let c = client.create_stream(&sender, &recipient, &other_token, &1_000, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    assert_eq!(
+        client.try_merge_streams(&sender, &vec![&env, a, c]),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    assert_eq!(
+        client.try_merge_streams(&sender, &vec![&env, a, a]),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    assert_eq!(client.merge_streams(&sender, &vec![&env, a, b]), a);
+
+    // 500 + 1_000 accrued so far stays claimable; 1_500 left streams at 30/s.
+    let merged = client.get_stream(&a).unwrap();
+    assert_eq!(merged.rate_per_second(), 30);
+    assert_eq!(merged.deposited_amount, 3_000);
+    assert_eq!(merged.end_time, 100);
+    let absorbed = client.get_stream(&b).unwrap();
+    assert_eq!(absorbed.status, StreamStatus::Completed);
+    assert_eq!(absorbed.deposited_amount, 0);
+
+    assert_eq!(client.withdraw(&recipient, &a), 1_500);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.withdraw(&recipient, &a), 1_500);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+#[test]
+fn test_merge_streams_keeps_fractional_rates_exact() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 4_000);
+    let client = create_contract(&env);
+
+    // 3/2 and 5/4 per second, whose whole-unit rates would sum to 2/s.
+    let a = client.create_stream(&sender, &recipient, &token, &1_500, &1_000);
+    let b = client.create_stream(&sender, &recipient, &token, &2_500, &2_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.merge_streams(&sender, &vec![&env, a, b]), a);
+
+    // 150 + 125 accrued so far; the remaining 3_725 streams over 1_900
+    // seconds.
+    let merged = client.get_stream(&a).unwrap();
+    assert_eq!(merged.end_time, 2_000);
+    env.ledger().with_mut(|li| li.timestamp = 1_050);
+    assert_eq!(client.get_claimable_amount(&a), Some(275 + 1_862));
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.withdraw(&recipient, &a), 4_000);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+#[test]
+fn test_merge_streams_respects_survivor_deposit_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+
+    let capped = StreamOptions {
+        deposit_cap: 2_000,
+        ..StreamOptions::new(&env)
+    };
+    let a = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &capped);
+    let big = client.create_stream(&sender, &recipient, &token, &2_000, &100);
+    let small = client.create_stream(&sender, &recipient, &token, &500, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    assert_eq!(
+        client.try_merge_streams(&sender, &vec![&env, a, big]),
+        Err(Ok(StreamError::DepositCapExceeded))
+    );
+    assert_eq!(client.merge_streams(&sender, &vec![&env, a, small]), a);
+    assert_eq!(client.get_remaining_capacity(&a), Some(500));
+}
+
+// ─── Master Streams ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_master_stream_shares_shortfall_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 10_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let master = client.create_master_stream(&sender, &token, &1_000);
+    let a = client.add_child_stream(&sender, &master, &alice, &6);
+    let b = client.add_child_stream(&sender, &master, &bob, &4);
+    assert_eq!(
+        client.try_withdraw_child_stream(&bob, &a),
+        Err(Ok(StreamError::NotRecipient))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    assert_eq!(client.get_child_claimable(&a), Some(300));
+    assert_eq!(client.get_child_claimable(&b), Some(200));
+
+    // 1_000 more would be owed by t=150, but only 500 is left: split 6:4.
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    assert_eq!(client.withdraw_child_stream(&alice, &a), 600);
+    assert_eq!(client.get_master_stream(&master).unwrap().balance, 0);
+    assert_eq!(client.get_child_claimable(&b), Some(400));
+
+    // Refunding the master resumes full-rate accrual.
+    client.fund_master_stream(&sender, &master, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 160);
+    assert_eq!(client.remove_child_stream(&sender, &b), 440);
+    assert_eq!(token_client.balance(&bob), 440);
+    assert_eq!(client.get_master_stream(&master).unwrap().total_rate, 6);
+
+    assert_eq!(client.cancel_master_stream(&sender, &master), 900);
+    assert_eq!(token_client.balance(&alice), 660);
+    assert!(!client.get_child_stream(&a).unwrap().is_active);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+// ─── Vaults ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_vault_funds_streams_without_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 5_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    assert_eq!(client.deposit_to_vault(&sender, &token, &3_000), 3_000);
+    assert_eq!(token_client.balance(&sender), 2_000);
+
+    let options = StreamOptions::new(&env);
+    let id = client.create_stream_from_vault(&sender, &recipient, &token, &1_000, &100, &options);
+    client.top_up_from_vault(&sender, &id, &500);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.deposited_amount, 1_500);
+    assert_eq!(stream.end_time, 150);
+    assert_eq!(client.get_vault_balance(&sender, &token), 1_500);
+    assert_eq!(token_client.balance(&sender), 2_000);
+    assert_eq!(client.get_accounted_balance(&token), 3_000);
+
+    assert_eq!(
+        client.try_create_stream_from_vault(&sender, &recipient, &token, &1_501, &100, &options),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_top_up_from_vault(&sender, &id, &1_501),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+
+    assert_eq!(client.withdraw_from_vault(&sender, &token, &1_500), 0);
+    assert_eq!(token_client.balance(&sender), 3_500);
+    assert_eq!(client.get_accounted_balance(&token), 1_500);
+}
+
+// ─── Scheduled Top-Ups ────────────────────────────────────────────────────────
+
+#[test]
+fn test_scheduled_top_up_escrows_until_due() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(
+        client.try_schedule_top_up(&sender, &id, &500, &0),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let due = client.schedule_top_up(&sender, &id, &500, &50);
+    let dropped = client.schedule_top_up(&sender, &id, &700, &60);
+    assert_eq!(token_client.balance(&sender), 800);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(
+        client.try_execute_scheduled_top_up(&due),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    assert_eq!(
+        client.try_cancel_scheduled_top_up(&recipient, &dropped),
+        Err(Ok(StreamError::NotSender))
+    );
+    assert_eq!(client.cancel_scheduled_top_up(&sender, &dropped), 700);
+    assert_eq!(token_client.balance(&sender), 1_500);
+    assert_eq!(client.get_scheduled_top_up(&dropped), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.execute_scheduled_top_up(&due);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.deposited_amount, 1_500);
+    assert_eq!(stream.end_time, 150);
+    assert_eq!(client.get_scheduled_top_up(&due), None);
+    assert_eq!(
+        client.try_execute_scheduled_top_up(&due),
+        Err(Ok(StreamError::StreamNotFound))
+    );
+    assert_eq!(client.get_accounted_balance(&token), 1_500);
+}
+
+#[test]
+fn test_scheduled_top_up_refunds_after_stream_ends() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_500);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    let top_up_id = client.schedule_top_up(&sender, &id, &500, &50);
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&sender), 800);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.execute_scheduled_top_up(&top_up_id);
+    assert_eq!(token_client.balance(&sender), 1_300);
+    assert_eq!(client.get_stream(&id).unwrap().deposited_amount, 1_000);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+// ─── Acceleration ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_accelerate_releases_remaining_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &id);
+    assert_eq!(
+        client.try_accelerate(&recipient, &id),
+        Err(Ok(StreamError::NotSender))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.accelerate(&sender, &id), 700);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.status, StreamStatus::Completed);
+    assert_eq!(stream.withdrawn_amount, 1_000);
+    assert_eq!(stream.end_time, 40);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+    assert_eq!(
+        client.try_accelerate(&sender, &id),
+        Err(Ok(StreamError::StreamInactive))
+    );
+}
+
+// ─── Condition Gating ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_condition_gated_stream_starts_when_oracle_reports_true() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let oracle = env.register(MockConditionOracle, ());
+    let oracle_client = MockConditionOracleClient::new(&env, &oracle);
+    let signed = Symbol::new(&env, "signed");
+
+    let options = StreamOptions {
+        condition: StreamCondition::Oracle(oracle.clone(), signed.clone()),
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    client.set_condition_oracle(&admin, &oracle, &true);
+    let id = client.create_stream_with_options(&sender, &recipient, &token, &1_000, &100, &options);
+    assert_eq!(client.get_stream(&id).unwrap().end_time, u64::MAX);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    assert_eq!(client.get_claimable_amount(&id), Some(0));
+    assert!(!client.check_condition(&id));
+
+    oracle_client.set_condition_met(&signed, &true);
+    assert!(client.check_condition(&id));
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.condition, StreamCondition::Unconditional);
+    assert_eq!(stream.end_time, 150);
+    assert_eq!(
+        client.try_check_condition(&id),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 80);
+    assert_eq!(client.get_claimable_amount(&id), Some(300));
+}
+
+#[test]
+fn test_set_stream_condition_pauses_running_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let oracle = env.register(MockConditionOracle, ());
+    client.set_condition_oracle(&admin, &oracle, &true);
+    let launched = Symbol::new(&env, "launched");
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    client.set_stream_condition(&sender, &id, &oracle, &launched);
+    assert_eq!(
+        client.try_set_stream_condition(&sender, &id, &oracle, &launched),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 60);
+    assert_eq!(client.get_claimable_amount(&id), Some(200));
+    assert_eq!(client.settle_expired(&admin, &vec![&env, id]).len(), 0);
+
+    MockConditionOracleClient::new(&env, &oracle).set_condition_met(&launched, &true);
+    assert!(client.check_condition(&id));
+    assert_eq!(client.get_stream(&id).unwrap().end_time, 140);
+    env.ledger().with_mut(|li| li.timestamp = 70);
+    assert_eq!(client.get_claimable_amount(&id), Some(300));
+}
+
+// ─── Exact Rates ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_exact_rate_accrues_without_truncation_drift() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    // 1000 over 300 s is 3.33… per second; a whole-unit rate would leave 100
+    // unstreamed at `end_time`.
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &300);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.rate_per_second(), 3);
+    assert_eq!(stream.end_time, 300);
+
+    for t in (7..300).step_by(7) {
+        env.ledger().with_mut(|li| li.timestamp = t);
+        client.withdraw(&recipient, &id);
+        assert_eq!(token_client.balance(&recipient), 1_000 * t as i128 / 300);
+    }
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.withdraw(&recipient, &id);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+    assert_eq!(
+        client.get_stream(&id).unwrap().status,
+        StreamStatus::Completed
+    );
+}
+
+// ─── Timelocks ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_timelock_releases_everything_at_unlock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(
+        client.try_create_timelock(&sender, &recipient, &token, &1_000, &100),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let id = client.create_timelock(&sender, &recipient, &token, &1_000, &500);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.end_time, 500);
+    assert_eq!(stream.rate.cliff, 500);
+    assert_eq!(
+        client.try_top_up_stream(&sender, &id, &100),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    assert_eq!(
+        client.try_schedule_top_up(&sender, &id, &100, &200),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    assert_eq!(token_client.balance(&sender), 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 499);
+    assert_eq!(client.get_claimable_amount(&id), Some(0));
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.get_claimable_amount(&id), Some(1_000));
+    client.withdraw(&recipient, &id);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+
+    // Cancelling before the unlock refunds the sender in full.
+    let id = client.create_timelock(&sender, &recipient, &token, &1_000, &900);
+    env.ledger().with_mut(|li| li.timestamp = 899);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&sender), 1_000);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+// ─── Vesting ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_vesting_unlocks_at_cliff_then_vests_linearly() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    assert_eq!(
+        client.try_create_vesting(&sender, &recipient, &token, &1_000, &400, &300, &true),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let id = client.create_vesting(&sender, &recipient, &token, &1_000, &100, &400, &true);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.category, StreamCategory::Vesting);
+    assert_eq!(stream.rate.cliff, 100);
+    assert!(!client.is_irrevocable(&id));
+
+    env.ledger().with_mut(|li| li.timestamp = 99);
+    assert_eq!(client.get_claimable_amount(&id), Some(0));
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.get_claimable_amount(&id), Some(250));
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.withdraw(&recipient, &id);
+    assert_eq!(token_client.balance(&recipient), 500);
+
+    // Revoking keeps what has vested and refunds the rest.
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&sender), 1_500);
+}
+
+#[test]
+fn test_stream_option_cliff_holds_back_withdrawals_and_cancels() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let with_cliff = |cliff_duration| StreamOptions {
+        cliff_duration,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(
+            &sender,
+            &recipient,
+            &token,
+            &1_000,
+            &100,
+            &with_cliff(101)
+        ),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let id = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &with_cliff(40),
+    );
+    assert_eq!(client.get_stream(&id).unwrap().rate.cliff, 40);
+
+    env.ledger().with_mut(|li| li.timestamp = 39);
+    assert_eq!(client.get_claimable_amount(&id), Some(0));
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(client.withdraw(&recipient, &id), 400);
+
+    // Cancelling before the cliff pays the recipient nothing.
+    let early = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &with_cliff(50),
+    );
+    env.ledger().with_mut(|li| li.timestamp = 80);
+    client.cancel_stream(&sender, &early);
+    assert_eq!(token_client.balance(&sender), 1_000);
+    assert_eq!(token_client.balance(&recipient), 400);
+}
+
+#[test]
+fn test_stream_option_start_time_defers_accrual() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+
+    let starting_at = |start_time| StreamOptions {
+        start_time,
+        ..StreamOptions::new(&env)
+    };
+    assert_eq!(
+        client.try_create_stream_with_options(
+            &sender,
+            &recipient,
+            &token,
+            &1_000,
+            &100,
+            &starting_at(99)
+        ),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let id = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &starting_at(1_000),
+    );
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!((stream.start_time, stream.end_time), (1_000, 1_100));
+
+    // Nothing streams before the start, even across a top-up.
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.top_up_stream(&sender, &id, &1_000);
+    assert_eq!(client.get_claimable_amount(&id), Some(0));
+    assert_eq!(client.get_stream(&id).unwrap().end_time, 1_200);
+    env.ledger().with_mut(|li| li.timestamp = 1_050);
+    assert_eq!(client.withdraw(&recipient, &id), 500);
+
+    // Cancelling before the start refunds the whole deposit.
+    let later = client.create_stream_with_options(
+        &sender,
+        &recipient,
+        &token,
+        &1_000,
+        &100,
+        &starting_at(2_000),
+    );
+    client.cancel_stream(&sender, &later);
+    assert_eq!(token_client.balance(&sender), 1_000);
+}
+
+#[test]
+fn test_create_stream_with_times_uses_absolute_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+
+    assert_eq!(
+        client.try_create_stream_with_times(&sender, &recipient, &token, &1_000, &300, &300),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    assert_eq!(
+        client.try_create_stream_with_times(&sender, &recipient, &token, &1_000, &50, &300),
+        Err(Ok(StreamError::InvalidDuration))
+    );
+    let id = client.create_stream_with_times(&sender, &recipient, &token, &1_000, &200, &400);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!((stream.start_time, stream.end_time), (200, 400));
+    assert_eq!(stream.rate_per_second(), 5);
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    assert_eq!(client.get_claimable_amount(&id), Some(500));
+}
+
+#[test]
+fn test_irrevocable_vesting_cannot_be_cancelled_or_paused() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let oracle = env.register(MockConditionOracle, ());
+    client.set_condition_oracle(&admin, &oracle, &true);
+
+    let id = client.create_vesting(&sender, &recipient, &token, &1_000, &0, &400, &false);
+    assert!(client.is_irrevocable(&id));
+    assert_eq!(
+        client.try_cancel_stream(&sender, &id),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_cancel_stream_at(&sender, &id, &200),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_stream_condition(&sender, &id, &oracle, &Symbol::new(&env, "go")),
+        Err(Ok(StreamError::Unauthorized))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 400);
+    assert_eq!(client.withdraw(&recipient, &id), 1_000);
+}
+
+// ─── Pause Switches ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_pause_switches_block_operations_independently() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 3_000);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    assert_eq!(client.get_pause_flags(), PauseFlags::default());
+
+    // Stop inflows; recipients can still withdraw and senders still cancel.
+    let inflows = PauseFlags {
+        creations: true,
+        top_ups: true,
+        withdrawals: false,
+    };
+    client.set_pause_flags(&admin, &inflows);
+    assert_eq!(client.get_pause_flags(), inflows);
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &1_000, &1_000),
+        Err(Ok(StreamError::Paused))
+    );
+    assert_eq!(
+        client.try_top_up_stream(&sender, &id, &100),
+        Err(Ok(StreamError::Paused))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.withdraw(&recipient, &id), 100);
+
+    client.set_pause_flags(
+        &admin,
+        &PauseFlags {
+            withdrawals: true,
+            ..PauseFlags::default()
+        },
+    );
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::Paused))
+    );
+    client.top_up_stream(&sender, &id, &100);
+    client.cancel_stream(&sender, &id);
+    assert!(client
+        .try_set_pause_flags(&sender, &PauseFlags::default())
+        .is_err());
+}
+
+// ─── Wind-Down ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_wind_down_lets_recipients_settle_past_operator_controls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    client.set_withdrawal_fee(&admin, &100);
+
+    let options = StreamOptions {
+        withdrawal_cap: 100,
+        withdrawal_cap_period: 300,
+        ..StreamOptions::new(&env)
+    };
+    let id =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
+    client.freeze_token(&admin, &token);
 
-    // Cancel stream: should pay final 100 to recipient and refund 700 to sender
-    client.cancel_stream(&sender, &stream_id);
+    env.ledger().with_mut(|li| li.timestamp = 400);
+    assert_eq!(
+        client.try_sunset_withdraw(&recipient, &id),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    client.enter_wind_down(&admin);
+    assert!(client.is_wound_down());
+    client.unfreeze_token(&admin, &token);
+    assert_eq!(
+        client.try_create_stream(&sender, &recipient, &token, &1_000, &1_000),
+        Err(Ok(StreamError::Paused))
+    );
+    client.freeze_token(&admin, &token);
 
-    let sender_balance_after = token_client.balance(&sender);
-    let contract_balance_after = token_client.balance(&contract_id);
-    let recipient_balance_after = token_client.balance(&recipient);
+    // The whole accrual, past the freeze, the cap and the withdrawal fee.
+    assert_eq!(client.sunset_withdraw(&recipient, &id), 400);
+    assert_eq!(token_client.balance(&recipient), 400);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&sender), 1_600);
+}
 
-    // Sender should receive 700 tokens back
-    assert_eq!(sender_balance_after - sender_balance_before, 700);
-    // Recipient should now hold total 300 (200 withdrawn earlier + 100 settled at cancel)
-    assert_eq!(recipient_balance_after, 300);
-    // Contract should be fully drained
-    assert_eq!(contract_balance_after, 0);
+#[test]
+fn test_renouncing_admin_winds_down_for_good() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &100);
+    assert!(!client.is_wound_down());
+
+    client.renounce_admin(&admin);
+    assert!(client.is_wound_down());
+    assert_eq!(client.get_fee_config(), None);
+    assert_eq!(
+        client.try_set_keeper_bounty(&admin, &0),
+        Err(Ok(StreamError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_initialize(&admin, &admin, &0),
+        Err(Ok(StreamError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_renounced_protocol_stops_withdrawal_fees_and_pays_out_accrued() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let token_client = token::Client::new(&env, &token);
+    let client = create_contract(&env);
+    client.initialize(&admin, &treasury, &0);
+    client.set_withdrawal_fee(&admin, &100);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.withdraw(&recipient, &id);
+    assert_eq!(client.get_accrued_fees(&token), 5);
+
+    // No fee is taken once nobody can collect it, and what accrued before
+    // still reaches the last treasury.
+    client.renounce_admin(&admin);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.withdraw(&recipient, &id), 500);
+    assert_eq!(token_client.balance(&recipient), 995);
+    assert_eq!(client.claim_fees(&token), 5);
+    assert_eq!(token_client.balance(&treasury), 5);
+    assert_eq!(client.get_accounted_balance(&token), 0);
+}
+
+// ─── Storage Layout ───────────────────────────────────────────────────────────
+
+#[test]
+fn test_withdraw_rewrites_only_stream_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+
+    let plain = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    let mut tags = Vec::new(&env);
+    for name in ["payroll_engineering_team", "q3_contractor_budget"] {
+        tags.push_back(Symbol::new(&env, name));
+    }
+    let options = StreamOptions {
+        tags,
+        ..StreamOptions::new(&env)
+    };
+    let tagged =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
+
+    // Warm up the recipient's token balance so both measured calls touch the
+    // same ledger entries.
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    client.withdraw(&recipient, &plain);
+    client.withdraw(&recipient, &tagged);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.withdraw(&recipient, &plain);
+    let plain_resources = env.cost_estimate().resources();
+    client.withdraw(&recipient, &tagged);
+    let tagged_resources = env.cost_estimate().resources();
+
+    // The terms record is neither read nor written back.
+    assert_eq!(tagged_resources.read_bytes, plain_resources.read_bytes);
+    assert_eq!(tagged_resources.write_bytes, plain_resources.write_bytes);
+    assert_eq!(client.get_stream(&tagged).unwrap().tags, options.tags);
+}
+
+#[test]
+fn test_claimable_queries_read_only_stream_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+
+    let plain = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    let mut tags = Vec::new(&env);
+    for name in ["payroll_engineering_team", "q3_contractor_budget"] {
+        tags.push_back(Symbol::new(&env, name));
+    }
+    let options = StreamOptions {
+        tags,
+        ..StreamOptions::new(&env)
+    };
+    let tagged =
+        client.create_stream_with_options(&sender, &recipient, &token, &1_000, &1_000, &options);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.get_claimable_amount(&plain), Some(100));
+    let plain_resources = env.cost_estimate().resources();
+    assert_eq!(client.get_claimable_amount(&tagged), Some(100));
+    let tagged_resources = env.cost_estimate().resources();
+
+    // Larger terms cost the query nothing: only the state record is read.
+    assert_eq!(tagged_resources.read_bytes, plain_resources.read_bytes);
+}
+
+#[test]
+fn test_recent_activity_expires_from_temporary_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (token, _) = create_token(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    client.withdraw(&recipient, &id);
+    client.bump_stream_ttl(&id);
+    assert_eq!(client.get_recent_events(&10).len(), 2);
+
+    // The records lapse on their own; the stream itself is untouched.
+    env.ledger()
+        .with_mut(|li| li.sequence_number += storage::ACTIVITY_TTL + 1);
+    assert_eq!(client.get_recent_events(&10).len(), 0);
+    assert_eq!(client.get_stream(&id).unwrap().withdrawn_amount, 100);
+}
+
+// ─── Error Codes ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_error_codes_are_stable() {
+    // Clients map these numbers; changing one is a breaking change.
+    let codes = [
+        (StreamError::InvalidAmount, 1),
+        (StreamError::StreamNotFound, 2),
+        (StreamError::Unauthorized, 3),
+        (StreamError::StreamInactive, 4),
+        (StreamError::AlreadyInitialized, 5),
+        (StreamError::NotAdmin, 6),
+        (StreamError::InvalidFeeRate, 7),
+        (StreamError::NotInitialized, 8),
+        (StreamError::InvalidDuration, 9),
+        (StreamError::InvalidTokenAddress, 10),
+        (StreamError::CooldownActive, 11),
+        (StreamError::WithdrawalCapReached, 12),
+        (StreamError::TooManyTags, 13),
+        (StreamError::InvalidBasket, 14),
+        (StreamError::BonusLocked, 15),
+        (StreamError::HoldContested, 16),
+        (StreamError::ClawbackWindowOpen, 17),
+        (StreamError::ClawbackWindowClosed, 18),
+        (StreamError::InvalidSplit, 19),
+        (StreamError::BeneficiaryNotEligible, 20),
+        (StreamError::AliasNotFound, 21),
+        (StreamError::OraclePriceUnavailable, 22),
+        (StreamError::SenderNotAllowed, 23),
+        (StreamError::AttestationMissing, 24),
+        (StreamError::DuplicateSalt, 25),
+        (StreamError::CancelAlreadyEffective, 26),
+        (StreamError::CancelNotDue, 27),
+        (StreamError::RateTooLow, 28),
+        (StreamError::InvalidApprovalPolicy, 29),
+        (StreamError::ApprovalRequired, 30),
+        (StreamError::NotApprover, 31),
+        (StreamError::AlreadyApproved, 32),
+        (StreamError::ProposalNotFound, 33),
+        (StreamError::ProposalExecuted, 34),
+        (StreamError::BudgetExceeded, 35),
+        (StreamError::PartnerNotFound, 36),
+        (StreamError::TokenFrozen, 37),
+        (StreamError::DelegationFailed, 38),
+        (StreamError::NoteTooLarge, 40),
+        (StreamError::DepositCapExceeded, 41),
+        (StreamError::InvalidTransition, 42),
+        (StreamError::ClaimBelowMinimum, 43),
+        (StreamError::ClaimNotDue, 45),
+        (StreamError::PayeeNotAllowed, 46),
+        (StreamError::OrgNotFound, 48),
+        (StreamError::ManagerNotAuthorized, 49),
+        (StreamError::RecordNotFound, 51),
+        (StreamError::NotSender, 52),
+        (StreamError::NotRecipient, 53),
+        (StreamError::Paused, 54),
+    ];
+    for (error, code) in codes {
+        assert_eq!(error as u32, code);
+    }
+}
+
+// ─── Claim Links ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_link_stream_is_claimed_once_by_preimage_holder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let payee = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+
+    let preimage = Bytes::from_slice(&env, b"open sesame");
+    let hash = env.crypto().sha256(&preimage).to_bytes();
+    let id = client.create_link_stream(&sender, &token, &1_000, &100, &hash);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, client.address);
+
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(
+        client.try_claim_link(&payee, &id, &Bytes::from_slice(&env, b"guess")),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    client.claim_link(&payee, &id, &preimage);
+    assert_eq!(client.get_stream(&id).unwrap().recipient, payee);
+    assert_eq!(
+        client.try_claim_link(&Address::generate(&env), &id, &preimage),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+
+    // Everything accrued before the claim belongs to the claimant.
+    assert_eq!(client.withdraw(&payee, &id), 400);
+}
+
+#[test]
+fn test_cancelling_unclaimed_link_refunds_sender_in_full() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let hash = env
+        .crypto()
+        .sha256(&Bytes::from_slice(&env, b"never shared"))
+        .to_bytes();
+    let id = client.create_link_stream(&sender, &token, &1_000, &100, &hash);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    assert!(client
+        .settle_expired(&Address::generate(&env), &vec![&env, id])
+        .is_empty());
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&sender), 1_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(
+        client.try_restart_stream(&sender, &id, &100),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+}
+
+// ─── Hash-Locked Tranches ─────────────────────────────────────────────────────
+
+#[test]
+fn test_tranche_is_claimed_with_preimage_before_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    let preimage = Bytes::from_slice(&env, b"swap secret");
+    let hash = env.crypto().sha256(&preimage).to_bytes();
+    env.ledger().with_mut(|li| li.timestamp = 20);
+    client.lock_tranche(&sender, &id, &300, &hash, &50);
+    assert_eq!(client.get_stream(&id).unwrap().end_time, 70);
+    assert_eq!(
+        client.try_lock_tranche(&sender, &id, &100, &hash, &50),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 40);
+    assert_eq!(
+        client.try_claim_tranche(&recipient, &id, &Bytes::from_slice(&env, b"nope")),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    assert_eq!(client.claim_tranche(&recipient, &id, &preimage), 300);
+    assert_eq!(token_client.balance(&recipient), 300);
+    assert_eq!(client.get_tranche(&id), None);
+
+    // The rest of the stream runs on unaffected.
+    env.ledger().with_mut(|li| li.timestamp = 70);
+    assert_eq!(client.withdraw(&recipient, &id), 700);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.deposited_amount, 1_000);
+    assert_eq!(stream.withdrawn_amount, 1_000);
+}
+
+#[test]
+fn test_expired_tranche_returns_to_accrual_or_sender() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 2_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    let preimage = Bytes::from_slice(&env, b"unrevealed");
+    let hash = env.crypto().sha256(&preimage).to_bytes();
+
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.lock_tranche(&sender, &id, &400, &hash, &30);
+    assert_eq!(
+        client.try_release_tranche(&id),
+        Err(Ok(StreamError::ClaimNotDue))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    assert_eq!(
+        client.try_claim_tranche(&recipient, &id, &preimage),
+        Err(Ok(StreamError::InvalidTransition))
+    );
+    client.release_tranche(&id);
+    let stream = client.get_stream(&id).unwrap();
+    assert_eq!(stream.deposited_amount, 1_000);
+    assert_eq!(stream.end_time, 100);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(client.withdraw(&recipient, &id), 1_000);
+
+    // Cancelling leaves the tranche claimable until expiry, then it goes
+    // back to the sender.
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    client.lock_tranche(&sender, &id, &500, &hash, &150);
+    env.ledger().with_mut(|li| li.timestamp = 110);
+    client.cancel_stream(&sender, &id);
+    assert_eq!(token_client.balance(&recipient), 1_100);
+    assert_eq!(token_client.balance(&sender), 400);
+    env.ledger().with_mut(|li| li.timestamp = 150);
+    client.release_tranche(&id);
+    assert_eq!(token_client.balance(&sender), 900);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+// ─── Distributors ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_distribute_pays_every_approving_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let employer = Address::generate(&env);
+    let operator = Address::generate(&env);
+    mint(&env, &token, &employer, 3_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    let a = client.create_stream(&employer, &alice, &token, &1_000, &100);
+    let b = client.create_stream(&employer, &bob, &token, &1_000, &100);
+    let c = client.create_stream(&employer, &carol, &token, &1_000, &100);
+    client.set_distributor(&alice, &Some(operator.clone()));
+    client.set_distributor(&bob, &Some(operator.clone()));
+    assert_eq!(client.get_distributor(&carol), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 50);
+    let paid = client.distribute(&operator, &vec![&env, a, b, c, 99]);
+    assert_eq!(paid, vec![&env, a, b]);
+    assert_eq!(token_client.balance(&alice), 500);
+    assert_eq!(token_client.balance(&bob), 500);
+    assert_eq!(token_client.balance(&carol), 0);
+    assert_eq!(token_client.balance(&operator), 0);
+
+    // Nothing new has accrued, and revoked approvals are honored.
+    assert!(client.distribute(&operator, &vec![&env, a]).is_empty());
+    client.set_distributor(&bob, &None);
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    assert_eq!(
+        client.distribute(&operator, &vec![&env, a, b]),
+        vec![&env, a]
+    );
+    assert_eq!(token_client.balance(&alice), 1_000);
+}
+
+// ─── Admin Log ────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_admin_log_records_successful_admin_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = create_contract(&env);
+    client.initialize(&admin, &Address::generate(&env), &0);
+    let (token, _) = create_token(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 10);
+    client.set_keeper_bounty(&admin, &50);
+    client.freeze_token(&admin, &token);
+    client.unfreeze_token(&admin, &token);
+    // Rejected calls leave no record.
+    assert_eq!(
+        client.try_set_keeper_bounty(&admin, &101),
+        Err(Ok(StreamError::InvalidFeeRate))
+    );
+    assert_eq!(
+        client.try_set_keeper_bounty(&Address::generate(&env), &10),
+        Err(Ok(StreamError::NotAdmin))
+    );
+
+    let log = client.get_admin_log(&0, &10);
+    assert_eq!(log.len(), 3);
+    let actions = ["set_keeper_bounty", "freeze_token", "unfreeze_token"];
+    for (seq, (record, action)) in log.iter().zip(actions).enumerate() {
+        assert_eq!(record.seq, seq as u64);
+        assert_eq!(record.action, Symbol::new(&env, action));
+        assert_eq!(record.admin, admin);
+        assert_eq!(record.timestamp, 10);
+    }
+
+    let page = client.get_admin_log(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().seq, 1);
+    assert!(client.get_admin_log(&3, &10).is_empty());
+}
+
+// ─── Treasury Fees ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_fees_accrue_until_claimed_for_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let token_client = token::Client::new(&env, &token);
+    client.initialize(&Address::generate(&env), &treasury, &100);
+
+    assert_eq!(
+        client.try_claim_fees(&token),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+    client.create_stream(&sender, &recipient, &token, &1_000, &100);
+    assert_eq!(client.get_accrued_fees(&token), 10);
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(token_client.balance(&client.address), 1_000);
+    assert_eq!(client.get_token_surplus(&token), 0);
+
+    assert_eq!(client.claim_fees(&token), 10);
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert_eq!(client.get_accrued_fees(&token), 0);
+    assert_eq!(
+        client.try_claim_fees(&token),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+}
+
+// ─── Acknowledgements ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_recipient_acknowledgements_are_kept_on_stream() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    let terms = BytesN::from_array(&env, &[1; 32]);
+    let payout = BytesN::from_array(&env, &[2; 32]);
+    assert_eq!(
+        client.try_acknowledge(&sender, &id, &terms),
+        Err(Ok(StreamError::NotRecipient))
+    );
+    client.acknowledge(&recipient, &id, &terms);
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &id);
+    client.acknowledge(&recipient, &id, &payout);
+
+    let acks = client.get_acknowledgements(&id);
+    assert_eq!(acks.len(), 2);
+    let first = acks.get(0).unwrap();
+    assert_eq!(
+        (first.memo_hash, first.withdrawn_amount, first.timestamp),
+        (terms, 0, 0)
+    );
+    let second = acks.get(1).unwrap();
+    assert_eq!(
+        (second.memo_hash, second.withdrawn_amount, second.timestamp),
+        (payout, 300, 30)
+    );
+    assert!(client.get_acknowledgements(&99).is_empty());
+}
+
+// ─── Subscribers ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_subscribers_get_their_own_activity_topic() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (token, _) = create_token(&env);
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let accountant = Address::generate(&env);
+    mint(&env, &token, &sender, 1_000);
+    let client = create_contract(&env);
+    let id = client.create_stream(&sender, &recipient, &token, &1_000, &100);
+
+    assert_eq!(
+        client.try_add_subscriber(&accountant, &id, &accountant),
+        Err(Ok(StreamError::Unauthorized))
+    );
+    client.add_subscriber(&recipient, &id, &accountant);
+    client.add_subscriber(&sender, &id, &accountant);
+    for _ in 0..2 {
+        client.add_subscriber(&sender, &id, &Address::generate(&env));
+    }
+    assert_eq!(
+        client.try_add_subscriber(&sender, &id, &Address::generate(&env)),
+        Err(Ok(StreamError::TooManyTags))
+    );
+    assert_eq!(client.get_subscribers(&id).get(0), Some(accountant.clone()));
+
+    env.ledger().with_mut(|li| li.timestamp = 30);
+    client.withdraw(&recipient, &id);
+    let topics = (Symbol::new(&env, "stream_activity"), accountant.clone(), id).into_val(&env);
+    let notice = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .expect("subscriber notified");
+    let event = StreamActivityEvent::try_from_val(&env, &notice.2).unwrap();
+    assert_eq!((event.kind, event.amount), (ActivityKind::Withdrawn, 300));
+
+    // Subscribers may drop themselves.
+    client.remove_subscriber(&accountant, &id, &accountant);
+    assert_eq!(client.get_subscribers(&id).len(), 2);
+    env.ledger().with_mut(|li| li.timestamp = 60);
+    client.withdraw(&recipient, &id);
+    assert!(!env
+        .events()
+        .all()
+        .iter()
+        .any(|(_, event_topics, _)| event_topics == topics));
 }
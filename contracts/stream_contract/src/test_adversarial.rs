@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+//! Adversarial token tests: a token that re-enters the stream contract from
+//! `transfer`, rejects transfers to chosen addresses, or misreports its
+//! decimals. Every case checks the stream and balances stay consistent.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{contracttype, testutils::Address as _, testutils::Ledger, Val};
+
+use errors::StreamError;
+use types::StreamStatus;
+
+// ─── Malicious Token ──────────────────────────────────────────────────────────
+
+#[contracttype]
+enum MockKey {
+    Balance(Address),
+    Decimals,
+    Attack,
+    RejectTo,
+    ReentrySucceeded,
+}
+
+/// A call the token makes back into `target` during its next transfer.
+#[contracttype]
+#[derive(Clone)]
+struct Attack {
+    target: Address,
+    entrypoint: Symbol,
+    caller: Address,
+    stream_id: u64,
+}
+
+#[contract]
+struct MaliciousToken;
+
+#[contractimpl]
+impl MaliciousToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .instance()
+            .set(&MockKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MockKey::Decimals)
+            .unwrap_or(7)
+    }
+
+    pub fn set_decimals(env: Env, decimals: u32) {
+        env.storage().instance().set(&MockKey::Decimals, &decimals);
+    }
+
+    /// Arms a one-shot call of `entrypoint(caller, stream_id)` on `target`
+    /// from inside the next transfer.
+    pub fn arm(env: Env, target: Address, entrypoint: Symbol, caller: Address, stream_id: u64) {
+        env.storage().instance().set(
+            &MockKey::Attack,
+            &Attack {
+                target,
+                entrypoint,
+                caller,
+                stream_id,
+            },
+        );
+    }
+
+    /// Makes every transfer to `to` panic, or stops doing so for `None`.
+    pub fn reject_to(env: Env, to: Option<Address>) {
+        match to {
+            Some(to) => env.storage().instance().set(&MockKey::RejectTo, &to),
+            None => env.storage().instance().remove(&MockKey::RejectTo),
+        }
+    }
+
+    /// Whether the last re-entry attempt returned successfully.
+    pub fn reentry_succeeded(env: Env) -> Option<bool> {
+        env.storage().instance().get(&MockKey::ReentrySucceeded)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let reject_to: Option<Address> = env.storage().instance().get(&MockKey::RejectTo);
+        if reject_to.as_ref() == Some(&to) {
+            panic!("transfer rejected");
+        }
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        env.storage()
+            .instance()
+            .set(&MockKey::Balance(from), &(from_balance - amount));
+        Self::mint(env.clone(), to, amount);
+
+        if let Some(attack) = env.storage().instance().get::<_, Attack>(&MockKey::Attack) {
+            env.storage().instance().remove(&MockKey::Attack);
+            let result = env.try_invoke_contract::<Val, InvokeError>(
+                &attack.target,
+                &attack.entrypoint,
+                vec![
+                    &env,
+                    attack.caller.into_val(&env),
+                    attack.stream_id.into_val(&env),
+                ],
+            );
+            env.storage()
+                .instance()
+                .set(&MockKey::ReentrySucceeded, &matches!(result, Ok(Ok(_))));
+        }
+    }
+}
+
+// ─── Test Helpers ─────────────────────────────────────────────────────────────
+
+struct Setup<'a> {
+    env: Env,
+    client: StreamContractClient<'a>,
+    token: MaliciousTokenClient<'a>,
+    sender: Address,
+    recipient: Address,
+    stream_id: u64,
+}
+
+/// A 1 000-token stream over 1 000 s in the malicious token, at `t = 500`.
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = StreamContractClient::new(&env, &env.register(StreamContract, ()));
+    let token = MaliciousTokenClient::new(&env, &env.register(MaliciousToken, ()));
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token.mint(&sender, &1_000);
+
+    let stream_id = client.create_stream(&sender, &recipient, &token.address, &1_000, &1_000);
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    Setup {
+        env,
+        client,
+        token,
+        sender,
+        recipient,
+        stream_id,
+    }
+}
+
+// ─── Re-entrancy ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_reentrant_withdraw_during_withdraw_is_rejected() {
+    let s = setup();
+    s.token.arm(
+        &s.client.address,
+        &Symbol::new(&s.env, "withdraw"),
+        &s.recipient,
+        &s.stream_id,
+    );
+
+    assert_eq!(s.client.withdraw(&s.recipient, &s.stream_id), 500);
+    assert_eq!(s.token.reentry_succeeded(), Some(false));
+
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.withdrawn_amount, 500);
+    assert_eq!(s.token.balance(&s.recipient), 500);
+    assert_eq!(s.token.balance(&s.client.address), 500);
+    assert_eq!(s.client.get_token_surplus(&s.token.address), 0);
+}
+
+#[test]
+fn test_reentrant_cancel_during_withdraw_is_rejected() {
+    let s = setup();
+    s.token.arm(
+        &s.client.address,
+        &Symbol::new(&s.env, "cancel_stream"),
+        &s.sender,
+        &s.stream_id,
+    );
+
+    assert_eq!(s.client.withdraw(&s.recipient, &s.stream_id), 500);
+    assert_eq!(s.token.reentry_succeeded(), Some(false));
+
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(s.token.balance(&s.sender), 0);
+    assert_eq!(s.token.balance(&s.client.address), 500);
+}
+
+#[test]
+fn test_reentrant_withdraw_during_cancel_is_rejected() {
+    let s = setup();
+    s.token.arm(
+        &s.client.address,
+        &Symbol::new(&s.env, "withdraw"),
+        &s.recipient,
+        &s.stream_id,
+    );
+
+    s.client.cancel_stream(&s.sender, &s.stream_id);
+    assert_eq!(s.token.reentry_succeeded(), Some(false));
+
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.status, StreamStatus::Cancelled);
+    assert_eq!(s.token.balance(&s.recipient), 500);
+    assert_eq!(s.token.balance(&s.sender), 500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+// ─── Reverting Transfers ──────────────────────────────────────────────────────
+
+#[test]
+fn test_reverting_transfer_leaves_stream_untouched() {
+    let s = setup();
+    s.token.reject_to(&Some(s.recipient.clone()));
+    assert!(s.client.try_withdraw(&s.recipient, &s.stream_id).is_err());
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.withdrawn_amount, 0);
+    assert_eq!(s.client.get_claimable_amount(&s.stream_id), Some(500));
+
+    // A cancellation that cannot refund the sender rolls back entirely.
+    s.token.reject_to(&Some(s.sender.clone()));
+    assert!(s.client.try_cancel_stream(&s.sender, &s.stream_id).is_err());
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+
+    // The recipient is unaffected by the sender's refusal.
+    assert_eq!(s.client.withdraw(&s.recipient, &s.stream_id), 500);
+    s.token.reject_to(&None);
+    s.client.cancel_stream(&s.sender, &s.stream_id);
+    assert_eq!(s.token.balance(&s.sender), 500);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+// ─── Misreported Decimals ─────────────────────────────────────────────────────
+
+#[test]
+fn test_misreported_decimals_never_affect_accounting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = StreamContractClient::new(&env, &env.register(StreamContract, ()));
+    let token = MaliciousTokenClient::new(&env, &env.register(MaliciousToken, ()));
+    let sender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token.mint(&sender, &86_400);
+
+    token.set_decimals(&u32::MAX);
+    let id = client.create_stream(&sender, &recipient, &token.address, &86_400, &86_400);
+    let rate = client.get_stream_rate(&id).unwrap();
+    assert_eq!(rate.decimals, u32::MAX);
+    assert_eq!(rate.whole_tokens_per_day, 0);
+    assert_eq!(rate.per_day_remainder, 86_400);
+
+    // Decimals reported later are ignored.
+    token.set_decimals(&0);
+    env.ledger().with_mut(|li| li.timestamp = 43_200);
+    assert_eq!(client.withdraw(&recipient, &id), 43_200);
+    assert_eq!(client.get_stream(&id).unwrap().token_decimals, u32::MAX);
+    assert_eq!(
+        client.try_withdraw(&recipient, &id),
+        Err(Ok(StreamError::InvalidAmount))
+    );
+}
@@ -0,0 +1,296 @@
+#![cfg(test)]
+
+//! Scenario builder for lifecycle tests: each step drives the contract or
+//! the ledger clock and each `expect_*` asserts on the result, so feature
+//! combinations (cliffs, pauses, fees, cancels) read as a short script.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, TryFromVal,
+};
+
+use errors::StreamError;
+use types::{PauseFlags, StreamStatus};
+
+const DAY: u64 = 86_400;
+
+/// Parties whose balances a scenario can check.
+#[derive(Clone, Copy, Debug)]
+enum Who {
+    Sender,
+    Recipient,
+    Treasury,
+    Contract,
+}
+
+struct Scenario<'a> {
+    env: Env,
+    client: StreamContractClient<'a>,
+    token: token::Client<'a>,
+    admin: Address,
+    sender: Address,
+    recipient: Address,
+    treasury: Address,
+    stream_id: Option<u64>,
+}
+
+/// A fresh contract, initialized fee-free, with a sender, a recipient and a
+/// Stellar asset token. Time starts at 0.
+fn scenario<'a>() -> Scenario<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = StreamContractClient::new(&env, &env.register(StreamContract, ()));
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin, &treasury, &0);
+    Scenario {
+        token: token::Client::new(&env, &token_address),
+        sender: Address::generate(&env),
+        recipient: Address::generate(&env),
+        env,
+        client,
+        admin,
+        treasury,
+        stream_id: None,
+    }
+}
+
+impl Scenario<'_> {
+    fn address(&self, who: Who) -> Address {
+        match who {
+            Who::Sender => self.sender.clone(),
+            Who::Recipient => self.recipient.clone(),
+            Who::Treasury => self.treasury.clone(),
+            Who::Contract => self.client.address.clone(),
+        }
+    }
+
+    fn id(&self) -> u64 {
+        self.stream_id.expect("no stream created yet")
+    }
+
+    // ─── Setup ────────────────────────────────────────────────────────────────
+
+    /// Mints `amount` to the sender.
+    fn fund(self, amount: i128) -> Self {
+        token::StellarAssetClient::new(&self.env, &self.token.address).mint(&self.sender, &amount);
+        self
+    }
+
+    /// Sets the deposit-side protocol fee for streams created from now on.
+    fn fee_bps(self, fee_rate_bps: u32) -> Self {
+        self.client
+            .update_fee_config(&self.admin, &self.treasury, &fee_rate_bps);
+        self
+    }
+
+    /// Sets the withdrawal fee for streams created from now on.
+    fn withdrawal_fee_bps(self, withdrawal_fee_bps: u32) -> Self {
+        self.client
+            .set_withdrawal_fee(&self.admin, &withdrawal_fee_bps);
+        self
+    }
+
+    // ─── Steps ────────────────────────────────────────────────────────────────
+
+    /// Funds and opens a stream of `amount` over `duration` seconds.
+    fn create(mut self, amount: i128, duration: u64) -> Self {
+        self = self.fund(amount);
+        self.stream_id = Some(self.client.create_stream(
+            &self.sender,
+            &self.recipient,
+            &self.token.address,
+            &amount,
+            &duration,
+        ));
+        self
+    }
+
+    /// Funds and opens a revocable vesting stream with a cliff.
+    fn create_vesting(mut self, amount: i128, cliff: u64, duration: u64) -> Self {
+        self = self.fund(amount);
+        self.stream_id = Some(self.client.create_vesting(
+            &self.sender,
+            &self.recipient,
+            &self.token.address,
+            &amount,
+            &cliff,
+            &duration,
+            &true,
+        ));
+        self
+    }
+
+    fn advance(self, seconds: u64) -> Self {
+        self.env
+            .ledger()
+            .with_mut(|li| li.timestamp = li.timestamp.saturating_add(seconds));
+        self
+    }
+
+    fn advance_days(self, days: u64) -> Self {
+        self.advance(days * DAY)
+    }
+
+    /// Funds and tops the stream up by `amount`.
+    fn top_up(self, amount: i128) -> Self {
+        let this = self.fund(amount);
+        this.client.top_up_stream(&this.sender, &this.id(), &amount);
+        this
+    }
+
+    /// Withdraws and asserts the gross amount taken from the stream.
+    fn withdraw(self, expected: i128) -> Self {
+        assert_eq!(self.client.withdraw(&self.recipient, &self.id()), expected);
+        self
+    }
+
+    fn cancel(self) -> Self {
+        self.client.cancel_stream(&self.sender, &self.id());
+        self
+    }
+
+    fn pause_withdrawals(self, paused: bool) -> Self {
+        self.client.set_pause_flags(
+            &self.admin,
+            &PauseFlags {
+                withdrawals: paused,
+                ..PauseFlags::default()
+            },
+        );
+        self
+    }
+
+    fn claim_fees(self) -> Self {
+        self.client.claim_fees(&self.token.address);
+        self
+    }
+
+    // ─── Expectations ─────────────────────────────────────────────────────────
+
+    fn expect_balance(self, who: Who, amount: i128) -> Self {
+        assert_eq!(
+            self.token.balance(&self.address(who)),
+            amount,
+            "balance of {:?}",
+            who
+        );
+        self
+    }
+
+    fn expect_claimable(self, amount: i128) -> Self {
+        assert_eq!(
+            self.client.get_claimable_amount(&self.id()),
+            Some(amount),
+            "claimable amount"
+        );
+        self
+    }
+
+    fn expect_status(self, status: StreamStatus) -> Self {
+        assert_eq!(self.client.get_stream(&self.id()).unwrap().status, status);
+        self
+    }
+
+    fn expect_withdraw_error(self, error: StreamError) -> Self {
+        assert_eq!(
+            self.client.try_withdraw(&self.recipient, &self.id()),
+            Err(Ok(error))
+        );
+        self
+    }
+
+    /// Asserts the last contract call emitted an event named `name`.
+    fn expect_event(self, name: &str) -> Self {
+        let expected = Symbol::new(&self.env, name);
+        let emitted = self.env.events().all().iter().any(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|topic| Symbol::try_from_val(&self.env, &topic).ok())
+                == Some(expected.clone())
+        });
+        assert!(emitted, "expected a `{}` event", name);
+        self
+    }
+}
+
+// ─── Scenarios ────────────────────────────────────────────────────────────────
+
+#[test]
+fn scenario_monthly_salary_paid_in_instalments() {
+    scenario()
+        .create(3_000_000, 30 * DAY)
+        .advance_days(10)
+        .withdraw(1_000_000)
+        .expect_event("tokens_withdrawn")
+        .advance_days(20)
+        .expect_claimable(2_000_000)
+        .withdraw(2_000_000)
+        .expect_status(StreamStatus::Completed)
+        .expect_balance(Who::Recipient, 3_000_000)
+        .expect_balance(Who::Contract, 0);
+}
+
+#[test]
+fn scenario_cliffs_pauses_fees_and_cancels() {
+    // Nothing vests before the cliff, then the backlog unlocks at once.
+    scenario()
+        .create_vesting(1_200, 90, 360)
+        .advance(89)
+        .expect_withdraw_error(StreamError::InvalidAmount)
+        .advance(1)
+        .withdraw(300)
+        .cancel()
+        .expect_event("stream_cancelled")
+        .expect_balance(Who::Recipient, 300)
+        .expect_balance(Who::Sender, 900);
+
+    // A paused protocol holds withdrawals without losing accrual.
+    scenario()
+        .create(1_000, 100)
+        .advance(40)
+        .pause_withdrawals(true)
+        .expect_withdraw_error(StreamError::Paused)
+        .advance(20)
+        .pause_withdrawals(false)
+        .withdraw(600);
+
+    // Deposit and withdrawal fees accrue to the treasury until claimed.
+    scenario()
+        .fee_bps(100)
+        .withdrawal_fee_bps(100)
+        .create(10_000, 100)
+        .top_up(1_000)
+        .advance(200)
+        .withdraw(10_890)
+        .expect_balance(Who::Recipient, 10_782)
+        .expect_balance(Who::Treasury, 0)
+        .claim_fees()
+        .expect_balance(Who::Treasury, 218)
+        .expect_balance(Who::Contract, 0);
+}
+
+#[test]
+fn scenario_table_cancel_at_different_points() {
+    // (days elapsed, paid to recipient, refunded to sender)
+    let cases = [
+        (0, 0, 3_000_000),
+        (10, 1_000_000, 2_000_000),
+        (30, 3_000_000, 0),
+    ];
+    for (days, paid, refunded) in cases {
+        scenario()
+            .create(3_000_000, 30 * DAY)
+            .advance_days(days)
+            .cancel()
+            .expect_status(StreamStatus::Cancelled)
+            .expect_balance(Who::Recipient, paid)
+            .expect_balance(Who::Sender, refunded);
+    }
+}
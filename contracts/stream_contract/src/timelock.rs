@@ -0,0 +1,53 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::types::StreamOptions;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Timelocks ────────────────────────────────────────────────────────────
+
+    /// Lock `amount` for `recipient` until `unlock_time`, then release all
+    /// of it at once.
+    ///
+    /// A timelock is an ordinary stream whose cliff is its `end_time`:
+    /// nothing is claimable before `unlock_time` and the whole net deposit
+    /// is claimable from then on. It is funded, charged and cancelled like
+    /// any stream, so cancelling before `unlock_time` refunds the sender in
+    /// full. Timelocks cannot be topped up.
+    ///
+    /// Returns the new stream ID.
+    ///
+    /// # Errors
+    /// - `InvalidDuration` — `unlock_time` is not in the future.
+    /// - Otherwise the same as `create_stream` with a `duration` running
+    ///   until `unlock_time`.
+    pub fn create_timelock(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        unlock_time: u64,
+    ) -> Result<u64, StreamError> {
+        let now = env.ledger().timestamp();
+        if unlock_time <= now {
+            return Err(StreamError::InvalidDuration);
+        }
+
+        let duration = unlock_time - now;
+        Self::create_stream_with_options(
+            env.clone(),
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            StreamOptions {
+                cliff_duration: duration,
+                ..StreamOptions::new(&env)
+            },
+        )
+    }
+}
@@ -0,0 +1,205 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::events::{
+    stream_topics, SenderTransferProposedEvent, SenderTransferredEvent, StreamTransferredEvent,
+};
+use crate::storage::{
+    append_to_tag_index, load_stream, remove_from_tag_index, save_auto_claim, save_pending_sender,
+    save_stream, try_load_pending_sender,
+};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Stream Transfers ─────────────────────────────────────────────────────
+
+    /// Hand the recipient role of a stream over to `new_recipient`, e.g. when
+    /// moving to a new wallet. Recipient-only.
+    ///
+    /// Everything accrued so far is first withdrawn to the current recipient
+    /// as by `withdraw`; only future accrual goes to `new_recipient`. The
+    /// settlement is held to the stream's withdrawal limits, so while a
+    /// cooldown, cap window or minimum claim stops `withdraw`, it stops the
+    /// handover too. Streams with a clawback window cannot be handed over,
+    /// as their settlement would sit in a hold the new recipient releases.
+    /// The recipient's own settings (payout address, split, beneficiary,
+    /// relayer fee, auto-claim schedule) are cleared. Returns the amount
+    /// settled to the current recipient.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotRecipient`    — caller is not the stream's recipient.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn,
+    ///   including by the settling withdrawal.
+    /// - `ClawbackWindowOpen` — the stream has a clawback window.
+    /// - `PayeeNotAllowed`, `AttestationMissing` — `new_recipient` may not
+    ///   receive from the sender.
+    /// - Any error of `withdraw` while settling the accrued amount, e.g.
+    ///   `CooldownActive` or `WithdrawalCapReached`.
+    pub fn transfer_stream_recipient(
+        env: Env,
+        current_recipient: Address,
+        stream_id: u64,
+        new_recipient: Address,
+    ) -> Result<i128, StreamError> {
+        current_recipient.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        if stream.recipient != current_recipient {
+            return Err(StreamError::NotRecipient);
+        }
+        Self::validate_stream_active(&stream)?;
+        if stream.clawback_window > 0 || stream.held_amount > 0 {
+            return Err(StreamError::ClawbackWindowOpen);
+        }
+        Self::require_payee_allowed(&env, &stream.sender, &new_recipient)?;
+        Self::require_attested(&env, &stream.sender, &new_recipient)?;
+
+        let accrued = Self::calculate_claimable(&stream, env.ledger().timestamp());
+        let settled_amount = if accrued > 0 {
+            Self::execute_withdrawal(
+                env.clone(),
+                current_recipient.clone(),
+                stream_id,
+                None,
+                None,
+                Some(accrued),
+            )?
+        } else {
+            0
+        };
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_active(&stream)?;
+        stream.recipient = new_recipient.clone();
+        stream.payout_address = None;
+        stream.split_address = None;
+        stream.split_bps = 0;
+        stream.beneficiary = None;
+        stream.beneficiary_inactivity_period = 0;
+        stream.relayer_fee = 0;
+        save_stream(&env, stream_id, &stream);
+        save_auto_claim(&env, stream_id, None);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "stream_transferred",
+                stream_id,
+                &stream.sender,
+                &new_recipient,
+            ),
+            StreamTransferredEvent {
+                stream_id,
+                previous_recipient: current_recipient,
+                new_recipient,
+                settled_amount,
+            },
+        );
+        Ok(settled_amount)
+    }
+
+    /// Propose handing the sender role of a stream over to `new_sender`,
+    /// e.g. when migrating treasuries, or pass `None` to withdraw a pending
+    /// proposal. Sender-only. Nothing changes until `new_sender` calls
+    /// `accept_sender_transfer`; a new proposal replaces the previous one.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `NotSender`       — caller is not the stream's sender.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    pub fn propose_sender_transfer(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        new_sender: Option<Address>,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let stream = load_stream(&env, stream_id)?;
+        Self::validate_stream_ownership(&stream, &sender)?;
+        Self::validate_stream_active(&stream)?;
+        save_pending_sender(&env, stream_id, new_sender.as_ref());
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "sender_transfer_proposed",
+                stream_id,
+                &sender,
+                &stream.recipient,
+            ),
+            SenderTransferProposedEvent {
+                stream_id,
+                new_sender,
+            },
+        );
+        Ok(())
+    }
+
+    /// Take over the sender role of a stream as its proposed new sender.
+    ///
+    /// From then on `new_sender` alone may top up, cancel and otherwise
+    /// manage the stream, and cancellation refunds go to it. The stream's
+    /// tags move from the previous sender to `new_sender`; group membership
+    /// stays with the previous sender, whose group calls skip the stream
+    /// from now on. Top-ups the previous sender scheduled are refunded to
+    /// it when executed rather than joining the stream.
+    ///
+    /// # Errors
+    /// - `StreamNotFound`  — no stream exists with `stream_id`.
+    /// - `Unauthorized`    — `new_sender` is not the proposed new sender.
+    /// - `StreamInactive`  — stream has been cancelled or fully withdrawn.
+    /// - `SenderNotAllowed` — permissioned mode is on and `new_sender` is
+    ///   not allowlisted.
+    /// - `PayeeNotAllowed`, `AttestationMissing` — the recipient may not
+    ///   receive from `new_sender`.
+    pub fn accept_sender_transfer(
+        env: Env,
+        new_sender: Address,
+        stream_id: u64,
+    ) -> Result<(), StreamError> {
+        new_sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        if try_load_pending_sender(&env, stream_id).as_ref() != Some(&new_sender) {
+            return Err(StreamError::Unauthorized);
+        }
+        Self::validate_stream_active(&stream)?;
+        Self::require_sender_allowed(&env, &new_sender)?;
+        Self::require_payee_allowed(&env, &new_sender, &stream.recipient)?;
+        Self::require_attested(&env, &new_sender, &stream.recipient)?;
+
+        let previous_sender = stream.sender.clone();
+        stream.sender = new_sender.clone();
+        for tag in stream.tags.iter() {
+            remove_from_tag_index(&env, &previous_sender, &tag, stream_id);
+            append_to_tag_index(&env, &new_sender, &tag, stream_id);
+        }
+        save_stream(&env, stream_id, &stream);
+        save_pending_sender(&env, stream_id, None);
+
+        env.events().publish(
+            stream_topics(
+                &env,
+                "sender_transferred",
+                stream_id,
+                &new_sender,
+                &stream.recipient,
+            ),
+            SenderTransferredEvent {
+                stream_id,
+                previous_sender,
+                new_sender,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the address proposed to take over the sender role of
+    /// `stream_id`, if any.
+    pub fn get_pending_sender(env: Env, stream_id: u64) -> Option<Address> {
+        try_load_pending_sender(&env, stream_id)
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(unused)]
 
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 
 /// Centralized storage key strategy.
 ///
@@ -8,20 +8,255 @@ use soroban_sdk::{contracttype, Address};
 /// - No ad-hoc string keys scattered through the codebase.
 /// - Deterministic, collision-free key serialization via `#[contracttype]`.
 /// - O(1) key construction and lookup cost.
+///
+/// Keys are only built inside `storage.rs`, so that module is the complete
+/// map of what the contract stores. A subsystem with several keys of the same
+/// scope nests them in its own enum under one variant (see [`TokenKey`]);
+/// the outer variant name then namespaces every key beneath it.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     /// Global monotonic counter for assigning stream IDs.
     StreamCounter,
-    /// Individual stream record, keyed by its unique u64 ID.
+    /// Mutable state of a stream, keyed by its unique u64 ID.
     Stream(u64),
     /// Protocol-level fee configuration (singleton).
     ProtocolConfig,
+    /// IDs of streams created by a sender under a given tag, in creation order.
+    SenderTagStreams(Address, Symbol),
+    /// IDs of streams a sender filed under a group, in creation order.
+    GroupStreams(Address, Symbol),
+    /// Multi-token basket stream record, sharing the stream ID space.
+    BasketStream(u64),
+    /// Stable-value stream record, sharing the stream ID space.
+    StableStream(u64),
+    /// Master stream record, sharing the stream ID space.
+    MasterStream(u64),
+    /// Child stream drawing on a master stream, sharing the stream ID space.
+    ChildStream(u64),
+    /// Presence marks an address as allowed to create streams in permissioned mode.
+    AllowedSender(Address),
+    /// Set while an organization restricts which recipients it may pay.
+    PayeeAllowlistEnabled(Address),
+    /// Recipient approved by an organization, keyed by (org, payee).
+    AllowedPayee(Address, Address),
+    /// Soulbound receipt minted for a stream.
+    Receipt(u64),
+    /// IDs of receipts held by an address, in minting order.
+    HolderReceipts(Address),
+    /// Per-sender nonce mixed into parameter-derived stream IDs.
+    DerivedIdNonce(Address),
+    /// An organization's multi-approver policy for stream creation.
+    ApprovalPolicy(Address),
+    /// Monotonic counter for stream-creation proposal IDs.
+    ProposalCounter,
+    /// Stream-creation proposal awaiting approvals, keyed by proposal ID.
+    Proposal(u64),
+    /// Per-epoch spending cap for a (sender, token) pair.
+    SpendingLimit(Address, Address),
+    /// Per-period budget envelope for an (org, manager, token) triple.
+    ManagerBudget(Address, Address, Address),
+    /// Fee rate override for a stream category.
+    CategoryFeeRate(StreamCategory),
+    /// Registered partner frontend, keyed by partner ID.
+    Partner(Symbol),
+    /// Voting delegation of a stream's unvested balance.
+    Delegation(u64),
+    /// Share of settled residue paid to keepers by `settle_expired` (bps).
+    KeeperBountyBps,
+    /// Contract allowed to report idle yield on streams.
+    YieldAdapter,
+    /// Encrypted note attached to a stream.
+    StreamNote(u64),
+    /// Owner of a registered organization, keyed by its funding address.
+    OrgOwner(Address),
+    /// Permissions of an organization's manager, keyed by (org, manager).
+    OrgManager(Address, Address),
+    /// Fees charged on a stream, in order.
+    FeeHistory(u64),
+    /// Acknowledgements the recipient recorded on a stream, in order.
+    Acknowledgements(u64),
+    /// Recipient-configured auto-claim schedule of a stream.
+    AutoClaim(u64),
+    /// Lifetime amount of a token a sender has streamed out to recipients.
+    SenderVolume(Address, Address),
+    /// Ring-buffer log record, namespaced by log.
+    Log(LogKey),
+    /// Sponsor-funded fee credit of a sender, keyed by (sender, token).
+    FeeCredit(Address, Address),
+    /// Admin-negotiated fee rate reserved for a not-yet-created stream ID.
+    CustomFeeRate(u64),
+    /// Continuation a cancelled stream was restarted as.
+    RestartedAs(u64),
+    /// Cancelled stream a continuation was restarted from.
+    RestartedFrom(u64),
+    /// Sender's vault balance, keyed by (sender, token).
+    Vault(Address, Address),
+    /// Monotonic counter for scheduled top-up IDs.
+    ScheduledTopUpCounter,
+    /// Escrowed top-up awaiting its execution time, keyed by its ID.
+    ScheduledTopUp(u64),
+    /// Presence marks a contract as a registered condition oracle.
+    ConditionOracle(Address),
+    /// Presence marks a stream its sender may not cancel or pause.
+    Irrevocable(u64),
+    /// Protocol-wide pause switches set by the admin.
+    PauseFlags,
+    /// Presence marks the protocol as winding down; see `enter_wind_down`.
+    /// Holds the treasury accrued fees still go to after `renounce_admin`.
+    WindDown,
+    /// Parties and terms of a stream, keyed by its ID.
+    StreamTerms(u64),
+    /// SHA-256 hash whose preimage claims the recipient role of a link stream.
+    ClaimLink(u64),
+    /// Hash- and time-locked tranche set aside from a stream.
+    Tranche(u64),
+    /// Operator a recipient lets claim all its streams via `distribute`.
+    Distributor(Address),
+    /// Per-token record, namespaced under the token's address.
+    Token(TokenKey),
+    /// Auxiliary per-stream record, namespaced under the stream's ID.
+    PerStream(StreamKey),
+}
+
+/// Storage keys of the contract's ring-buffer logs, nested under
+/// `DataKey::Log`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LogKey {
+    /// Number of activity records ever written to the recent-events buffer.
+    ActivityCount,
+    /// Slot of the recent-events ring buffer, kept in temporary storage.
+    ActivitySlot(u32),
+    /// Number of admin actions ever recorded.
+    AdminCount,
+    /// Slot of the admin audit log.
+    AdminSlot(u32),
+}
+
+/// Auxiliary storage keys scoped to a single stream, nested under
+/// `DataKey::PerStream`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamKey {
+    /// Addresses that receive their own copy of a stream's lifecycle events.
+    Subscribers(u64),
+    /// Creation-time cap on a stream's net deposit.
+    DepositCap(u64),
+    /// Address the sender has proposed to hand the stream over to.
+    PendingSender(u64),
+}
+
+/// Storage keys scoped to a single token, nested under `DataKey::Token`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenKey {
+    /// Amount of a token the contract holds on behalf of its streams.
+    AccountedBalance(Address),
+    /// Presence marks a token as frozen by the admin.
+    Frozen(Address),
+    /// IDs of streams escrowing a token, in creation order.
+    Streams(Address),
+    /// Admin cap on a token's accounted balance.
+    DepositCap(Address),
+    /// Lifetime amount of a token streamed out to recipients.
+    Volume(Address),
+    /// Metadata of a token, cached on its first use.
+    Info(Address),
+    /// Protocol fees in a token accrued for the treasury to claim.
+    AccruedFees(Address),
+    /// Payouts the compliance oracle withheld, keyed by (token, account).
+    ComplianceHold(Address, Address),
+}
+
+/// Kind of payment a stream represents, used to select its protocol fee.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamCategory {
+    /// Anything not otherwise categorized.
+    General,
+    /// Recurring salary or contractor pay.
+    Payroll,
+    /// One-off payment.
+    Payment,
+    /// Token vesting.
+    Vesting,
+    /// Open donation stream funded by anyone; see `create_donation_stream`.
+    Donation,
+}
+
+/// How yield earned on a stream's idle principal is divided when it is
+/// settled at withdrawal, cancellation or keeper settlement.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YieldPolicy {
+    /// All yield goes to the sender, who owns the unstreamed principal.
+    Sender,
+    /// All yield goes to the recipient.
+    Recipient,
+    /// The given bps go to the recipient, the rest to the sender.
+    Split(u32),
+}
+
+/// Lifecycle status of a stream. `Active` is the only non-terminal status;
+/// see `StreamStatus::can_transition_to` for the allowed transitions.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamStatus {
+    /// Streaming, or funded and awaiting withdrawal.
+    Active,
+    /// Fully withdrawn by the recipient, bonus included.
+    Completed,
+    /// Cancelled by the sender; accrued funds paid, the rest refunded.
+    Cancelled,
+    /// Finalized by a keeper after `end_time` via `settle_expired`.
+    Settled,
 }
 
-/// Immutable state of a payment stream.
+/// Compiled-in limits and configured rates returned by `get_limits`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolLimits {
+    /// Highest protocol fee rate the admin may set (bps).
+    pub max_fee_rate_bps: u32,
+    /// Current global fee rate (bps); 0 before initialization.
+    pub fee_rate_bps: u32,
+    /// Smallest gross deposit, in token base units.
+    pub min_amount: i128,
+    /// Longest accepted duration in seconds. In practice the net deposit
+    /// bounds it, since the rate must be at least `rate_granularity`.
+    pub max_duration: u64,
+    /// Smallest non-zero rate, in token base units per second.
+    pub rate_granularity: i128,
+    /// Maximum tags per stream.
+    pub max_tags: u32,
+    /// Maximum tokens in a basket stream.
+    pub max_basket_tokens: u32,
+    /// Highest keeper bounty the admin may set (bps).
+    pub max_keeper_bounty_bps: u32,
+    /// Current keeper bounty (bps).
+    pub keeper_bounty_bps: u32,
+    /// Remaining ledgers below which stream writes emit `ttl_low`.
+    pub ttl_low_threshold: u32,
+    /// Fixed-point scale of stable-stream oracle prices.
+    pub price_scale: i128,
+    /// Largest encrypted note a stream may carry, in bytes.
+    pub max_note_bytes: u32,
+    /// Highest withdrawal fee the admin may set (bps).
+    pub max_withdrawal_fee_bps: u32,
+    /// Current withdrawal fee (bps); 0 before initialization.
+    pub withdrawal_fee_bps: u32,
+    /// Smallest amount a withdrawal may claim (0 = no minimum).
+    pub min_claim_amount: i128,
+    /// Runway in seconds below which stream writes emit `stream_depleting`
+    /// (0 = never).
+    pub depletion_warning_period: u64,
+}
+
+/// State of a payment stream, as returned to callers.
 ///
-/// Stored in persistent storage under `DataKey::Stream(id)`.
+/// Stored split in two, a `StreamState` and a `StreamTerms`, so that hot
+/// paths rewrite only the small mutable part.
 /// Space: O(1) per stream.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -32,8 +267,9 @@ pub struct Stream {
     pub recipient: Address,
     /// Token being streamed.
     pub token_address: Address,
-    /// Net tokens dripped per ledger-second (after fee deduction).
-    pub rate_per_second: i128,
+    /// Net tokens dripped per ledger-second (after fee deduction), kept as
+    /// an exact fraction; `rate_per_second()` gives it in whole units.
+    pub rate: ExactRate,
     /// Net deposited amount available to the stream (after fee deduction).
     pub deposited_amount: i128,
     /// Cumulative amount already withdrawn by the recipient.
@@ -42,10 +278,556 @@ pub struct Stream {
     pub start_time: u64,
     /// Ledger timestamp of the last state mutation.
     pub last_update_time: u64,
-    /// `false` once fully withdrawn or cancelled.
+    /// Lifecycle status; changed only through `StreamContract::transition`.
+    pub status: StreamStatus,
+    /// Protocol fee rate (bps) locked in at creation and applied to every
+    /// top-up, regardless of later changes to the global config.
+    pub fee_rate_bps: u32,
+    /// Minimum number of seconds between successive withdrawals (0 = none).
+    pub withdrawal_cooldown: u64,
+    /// Ledger timestamp of the last withdrawal (creation time until the first).
+    pub last_withdrawal_time: u64,
+    /// Accrued tokens materialized at `last_update_time` but not yet withdrawn.
+    pub settled_balance: i128,
+    /// Maximum amount withdrawable per cap window (0 = uncapped).
+    pub withdrawal_cap: i128,
+    /// Length of a cap window in seconds.
+    pub withdrawal_cap_period: u64,
+    /// Ledger timestamp at which the current cap window opened.
+    pub cap_window_start: u64,
+    /// Amount withdrawn so far in the current cap window.
+    pub cap_window_withdrawn: i128,
+    /// Sender-chosen labels (e.g. `payroll`, `grant`) for grouping streams.
+    pub tags: Vec<Symbol>,
+    /// Ledger timestamp at which the deposit is fully streamed at the current
+    /// rate. Extended by top-ups.
+    pub end_time: u64,
+    /// Net completion bonus escrowed alongside the stream (0 = none).
+    pub bonus_amount: i128,
+    /// `true` once the bonus has been paid out or refunded.
+    pub bonus_claimed: bool,
+    /// Seconds each withdrawal is held before release (0 = paid immediately).
+    pub clawback_window: u64,
+    /// Withdrawn tokens held in the contract pending release.
+    pub held_amount: i128,
+    /// Ledger timestamp at which the current hold may be finalized.
+    pub held_until: u64,
+    /// `true` while the sender's contest freezes the held amount.
+    pub hold_contested: bool,
+    /// Recipient-chosen address receiving `split_bps` of every payout.
+    pub split_address: Option<Address>,
+    /// Share of each payout sent to `split_address`, in basis points.
+    pub split_bps: u32,
+    /// Wallet receiving the recipient's payouts, if different from `recipient`.
+    pub payout_address: Option<Address>,
+    /// Backup address that may take over as recipient after inactivity.
+    pub beneficiary: Option<Address>,
+    /// Seconds without a withdrawal after which `beneficiary` may claim.
+    pub beneficiary_inactivity_period: u64,
+    /// Scheduled cancellation time; accrual stops here. 0 if none.
+    pub cancel_at: u64,
+    /// Category chosen at creation; selects the category fee rate.
+    pub category: StreamCategory,
+    /// Partner frontend the stream was created through, if any.
+    pub partner: Option<Symbol>,
+    /// Ledger the stream's storage entry is known to live until.
+    pub live_until_ledger: u32,
+    /// Withdrawal fee rate (bps) locked in at creation.
+    pub withdrawal_fee_bps: u32,
+    /// Sender-approved amount paid to a relayer per `withdraw_for` claim.
+    pub relayer_fee: i128,
+    /// The token's decimals, recorded at creation.
+    pub token_decimals: u32,
+    /// Who gets yield earned on the stream's idle principal.
+    pub yield_policy: YieldPolicy,
+    /// Yield reported by the adapter and not yet settled.
+    pub pending_yield: i128,
+    /// While gated, the stream is paused until the oracle reports the
+    /// condition true; `end_time` is `u64::MAX` until then.
+    pub condition: StreamCondition,
+}
+
+/// The frequently read and written part of a stream: its parties, the
+/// limits and payout settings a withdrawal applies, balances, timestamps
+/// and status. `withdraw` reads and writes only this record, and so do
+/// accrual queries such as `get_claimable_amount`.
+///
+/// Stored in persistent storage under `DataKey::Stream(id)`; together with
+/// its `StreamTerms` it makes up the `Stream` callers see.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamState {
+    /// Address that created and funds this stream.
+    pub sender: Address,
+    /// Address entitled to withdraw from this stream.
+    pub recipient: Address,
+    /// Token being streamed.
+    pub token_address: Address,
+    /// Net tokens dripped per ledger-second (after fee deduction), kept as
+    /// an exact fraction; `rate_per_second()` gives it in whole units.
+    pub rate: ExactRate,
+    /// Net deposited amount available to the stream (after fee deduction).
+    pub deposited_amount: i128,
+    /// Cumulative amount already withdrawn by the recipient.
+    pub withdrawn_amount: i128,
+    /// Ledger timestamp of the last state mutation.
+    pub last_update_time: u64,
+    /// Lifecycle status; changed only through `StreamContract::transition`.
+    pub status: StreamStatus,
+    /// Minimum number of seconds between successive withdrawals (0 = none).
+    pub withdrawal_cooldown: u64,
+    /// Ledger timestamp of the last withdrawal (creation time until the first).
+    pub last_withdrawal_time: u64,
+    /// Accrued tokens materialized at `last_update_time` but not yet withdrawn.
+    pub settled_balance: i128,
+    /// Maximum amount withdrawable per cap window (0 = uncapped).
+    pub withdrawal_cap: i128,
+    /// Length of a cap window in seconds.
+    pub withdrawal_cap_period: u64,
+    /// Ledger timestamp at which the current cap window opened.
+    pub cap_window_start: u64,
+    /// Amount withdrawn so far in the current cap window.
+    pub cap_window_withdrawn: i128,
+    /// Ledger timestamp at which the deposit is fully streamed at the current
+    /// rate. Extended by top-ups.
+    pub end_time: u64,
+    /// Net completion bonus escrowed alongside the stream (0 = none).
+    pub bonus_amount: i128,
+    /// `true` once the bonus has been paid out or refunded.
+    pub bonus_claimed: bool,
+    /// Seconds each withdrawal is held before release (0 = paid immediately).
+    pub clawback_window: u64,
+    /// Withdrawn tokens held in the contract pending release.
+    pub held_amount: i128,
+    /// Ledger timestamp at which the current hold may be finalized.
+    pub held_until: u64,
+    /// `true` while the sender's contest freezes the held amount.
+    pub hold_contested: bool,
+    /// Recipient-chosen address receiving `split_bps` of every payout.
+    pub split_address: Option<Address>,
+    /// Share of each payout sent to `split_address`, in basis points.
+    pub split_bps: u32,
+    /// Wallet receiving the recipient's payouts, if different from `recipient`.
+    pub payout_address: Option<Address>,
+    /// Backup address that may take over as recipient after inactivity.
+    pub beneficiary: Option<Address>,
+    /// Seconds without a withdrawal after which `beneficiary` may claim.
+    pub beneficiary_inactivity_period: u64,
+    /// Scheduled cancellation time; accrual stops here. 0 if none.
+    pub cancel_at: u64,
+    /// Category chosen at creation; selects the category fee rate.
+    pub category: StreamCategory,
+    /// Ledger the stream's storage entry is known to live until.
+    pub live_until_ledger: u32,
+    /// Withdrawal fee rate (bps) locked in at creation.
+    pub withdrawal_fee_bps: u32,
+    /// Sender-approved amount paid to a relayer per `withdraw_for` claim.
+    pub relayer_fee: i128,
+    /// Who gets yield earned on the stream's idle principal.
+    pub yield_policy: YieldPolicy,
+    /// Yield reported by the adapter and not yet settled.
+    pub pending_yield: i128,
+    /// While gated, the stream is paused until the oracle reports the
+    /// condition true; `end_time` is `u64::MAX` until then.
+    pub condition: StreamCondition,
+}
+
+/// The part of a stream fixed at creation and never needed to withdraw:
+/// its start, deposit fee terms, tags and token metadata.
+///
+/// Stored in persistent storage under `DataKey::StreamTerms(id)`, written
+/// once when the stream is created and never rewritten.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamTerms {
+    /// Ledger timestamp at stream creation.
+    pub start_time: u64,
+    /// Protocol fee rate (bps) locked in at creation and applied to every
+    /// top-up, regardless of later changes to the global config.
+    pub fee_rate_bps: u32,
+    /// Sender-chosen labels (e.g. `payroll`, `grant`) for grouping streams.
+    pub tags: Vec<Symbol>,
+    /// Partner frontend the stream was created through, if any.
+    pub partner: Option<Symbol>,
+    /// The token's decimals, recorded at creation.
+    pub token_decimals: u32,
+}
+
+/// Exact streaming rate of `numerator / denominator` tokens per second, e.g.
+/// a stream's net deposit over its duration, measured from `anchor`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExactRate {
+    pub numerator: i128,
+    /// Never 0.
+    pub denominator: u64,
+    /// Time the rate took effect; accrual is measured from here so that
+    /// rounding never accumulates across settlements.
+    pub anchor: u64,
+    /// Time before which nothing is claimable, 0 for none. Accrual still
+    /// runs from `anchor`, so everything accrued by then unlocks at once.
+    pub cliff: u64,
+}
+
+/// The original `Stream` layout, read only by `migrate_streams` to upgrade
+/// records written before the current layout.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamV1 {
+    pub sender: Address,
+    pub recipient: Address,
+    pub token_address: Address,
+    pub rate_per_second: i128,
+    pub deposited_amount: i128,
+    pub withdrawn_amount: i128,
+    pub start_time: u64,
+    pub last_update_time: u64,
     pub is_active: bool,
 }
 
+/// Funding snapshot of a stream returned by `get_stream_health`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamHealth {
+    /// Seconds until the unstreamed balance runs out at the current rate.
+    pub runway_seconds: u64,
+    /// Ledger timestamp at which the unstreamed balance runs out.
+    pub depletes_at: u64,
+    /// Escrowed tokens not yet accrued to the recipient.
+    pub unstreamed_balance: i128,
+    /// Tokens accrued to the recipient but not yet withdrawn.
+    pub owed_amount: i128,
+    /// `true` if the balance runs out before the stream's `end_time`.
+    pub is_underfunded: bool,
+}
+
+/// Progress of a stream returned by `get_progress`, for progress bars.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamProgress {
+    /// Share of the deposit accrued to the recipient so far (bps).
+    pub streamed_bps: u32,
+    /// Seconds from `start_time` to now, capped at `total_duration`.
+    pub elapsed_seconds: u64,
+    /// Seconds from `start_time` to `end_time`.
+    pub total_duration: u64,
+    /// Tokens withdrawn so far.
+    pub withdrawn_amount: i128,
+    /// Net tokens deposited.
+    pub deposited_amount: i128,
+    /// Seconds until the unstreamed balance runs out at the current rate.
+    pub runway_seconds: u64,
+}
+
+/// Part of a stream's unstreamed balance set aside under a hashlock: the
+/// recipient takes it by revealing the preimage of `hash` before `expiry`,
+/// after which it returns to the stream. Returned by `get_tranche`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HtlcTranche {
+    pub amount: i128,
+    /// SHA-256 digest of the preimage that unlocks the tranche.
+    pub hash: BytesN<32>,
+    /// Time from which the tranche can no longer be claimed.
+    pub expiry: u64,
+}
+
+/// Token metadata cached on the token's first use, returned by
+/// `get_token_info`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenInfo {
+    pub decimals: u32,
+    /// The token's symbol, if it reports one.
+    pub symbol: Option<String>,
+}
+
+/// Stream rate in raw and human units, returned by `get_stream_rate`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamRate {
+    /// Raw token units (e.g. stroops) per second.
+    pub rate_per_second: i128,
+    /// Raw token units per day.
+    pub rate_per_day: i128,
+    /// The token's decimals.
+    pub decimals: u32,
+    /// Whole tokens per day, rounded down.
+    pub whole_tokens_per_day: i128,
+    /// Raw units per day beyond `whole_tokens_per_day`.
+    pub per_day_remainder: i128,
+}
+
+/// Credit a sponsor has deposited to pay a sender's protocol fees in one
+/// token.
+///
+/// Stored in persistent storage under `DataKey::FeeCredit(sender, token)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCredit {
+    pub sponsor: Address,
+    /// Unspent credit, in token base units.
+    pub balance: i128,
+}
+
+/// External condition gating a stream, e.g. "contract signed" or "mainnet
+/// launched".
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamCondition {
+    /// Not gated.
+    Unconditional,
+    /// Paused until the registered oracle contract reports the condition
+    /// true through `is_condition_met(condition) -> bool`.
+    Oracle(Address, Symbol),
+}
+
+/// Top-up escrowed now and added to a stream at `execute_at`.
+///
+/// Stored in persistent storage under `DataKey::ScheduledTopUp(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledTopUp {
+    pub stream_id: u64,
+    pub sender: Address,
+    /// Gross amount escrowed; fees are charged when it is added.
+    pub amount: i128,
+    /// Earliest time `execute_scheduled_top_up` may add it.
+    pub execute_at: u64,
+}
+
+/// Lifecycle step recorded in the recent-events buffer.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActivityKind {
+    Created,
+    ToppedUp,
+    Withdrawn,
+    Cancelled,
+    Settled,
+}
+
+/// Compact record of a lifecycle event, as returned by `get_recent_events`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityRecord {
+    /// Position in the contract's activity sequence, starting at 0; gaps
+    /// between polls mean records were overwritten.
+    pub seq: u64,
+    pub kind: ActivityKind,
+    pub stream_id: u64,
+    /// Deposited, topped-up, withdrawn, refunded or settled amount.
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Recipient's on-chain acknowledgement of a stream's terms or of a payout,
+/// as returned by `get_acknowledgements`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Acknowledgement {
+    /// Hash of the off-chain memo being acknowledged.
+    pub memo_hash: BytesN<32>,
+    /// The stream's total withdrawn amount when acknowledged, tying the
+    /// acknowledgement to the payouts made so far.
+    pub withdrawn_amount: i128,
+    pub timestamp: u64,
+}
+
+/// Admin-privileged action, as returned by `get_admin_log`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminRecord {
+    /// Position in the admin log, starting at 0.
+    pub seq: u64,
+    /// Name of the entrypoint the admin called.
+    pub action: Symbol,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Which side of a stream a fee was charged on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeeKind {
+    /// Charged on a deposit: creation, bonus, top-up or donation.
+    Deposit,
+    /// Charged on a withdrawal.
+    Withdrawal,
+}
+
+/// One fee charged on a stream, as returned by `get_fee_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecord {
+    pub kind: FeeKind,
+    /// Ledger timestamp the fee was charged at.
+    pub timestamp: u64,
+    /// Amount the fee was charged on.
+    pub gross_amount: i128,
+    /// Rate applied, in bps.
+    pub fee_rate_bps: u32,
+    /// Total fee, partner share included.
+    pub fee_amount: i128,
+    /// Treasury the (non-partner) fee was paid to.
+    pub treasury: Address,
+}
+
+/// What an organization manager may do with the org's streams.
+///
+/// Stored in persistent storage under `DataKey::OrgManager(org, manager)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManagerPermissions {
+    /// May open streams funded by the org with `manager_create_stream`.
+    pub can_create: bool,
+    /// May top up the org's streams with `manager_top_up_stream`.
+    pub can_top_up: bool,
+    /// May cancel the org's streams with `manager_cancel_stream`.
+    pub can_cancel: bool,
+}
+
+/// Recipient-configured claim schedule executed by `execute_auto_claim`.
+///
+/// Stored in persistent storage under `DataKey::AutoClaim(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoClaimSchedule {
+    /// Seconds between claims.
+    pub interval: u64,
+    /// Address the claimed tokens are paid to.
+    pub destination: Address,
+    /// Earliest ledger timestamp of the next claim.
+    pub next_claim_time: u64,
+}
+
+/// Preview of a stream creation, returned by `simulate_create_stream`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreateSimulation {
+    /// Fee rate (bps) the stream would lock in.
+    pub fee_rate_bps: u32,
+    /// Protocol fee deducted from the deposit, partner share included.
+    pub fee_amount: i128,
+    /// Deposit credited to the stream after the fee.
+    pub net_amount: i128,
+    /// Completion bonus escrowed after the fee.
+    pub net_bonus_amount: i128,
+    pub rate_per_second: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Preview of a withdrawal, returned by `simulate_withdraw`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawSimulation {
+    /// Gross amount withdrawn from the stream.
+    pub amount: i128,
+    /// Part of `amount` accrued for the treasury.
+    pub withdrawal_fee: i128,
+    /// Part of `amount` sent to the recipient's split address.
+    pub split_amount: i128,
+    /// Part of `amount` reaching the payout destination (or the holding
+    /// bucket, for clawback-window streams).
+    pub recipient_amount: i128,
+    /// Escrowed balance left in the stream afterwards.
+    pub remaining_balance: i128,
+    /// `true` if the withdrawal would leave the stream inactive.
+    pub closes_stream: bool,
+}
+
+/// Preview of a cancellation, returned by `simulate_cancel`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelSimulation {
+    /// Accrued amount paid to the recipient.
+    pub recipient_amount: i128,
+    /// Unaccrued balance refunded to the sender.
+    pub refunded_amount: i128,
+    /// Escrowed bonus paid to the recipient (0 if refunded or none).
+    pub bonus_to_recipient: i128,
+    /// Escrowed bonus refunded to the sender (0 if paid or none).
+    pub bonus_to_sender: i128,
+}
+
+/// Optional creation-time settings accepted by `create_stream_with_options`.
+///
+/// `StreamOptions::new` yields a plain stream, identical to one made by
+/// `create_stream`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamOptions {
+    /// Minimum number of seconds between successive withdrawals (0 = none).
+    ///
+    /// The first withdrawal is allowed one cooldown period after creation.
+    pub withdrawal_cooldown: u64,
+    /// Maximum amount withdrawable per `withdrawal_cap_period` (0 = uncapped).
+    ///
+    /// Accrual is unaffected; anything above the cap stays claimable later.
+    pub withdrawal_cap: i128,
+    /// Length of a withdrawal cap window in seconds. Required when capped.
+    pub withdrawal_cap_period: u64,
+    /// Up to `MAX_TAGS` labels; each one indexes the stream for
+    /// `get_streams_by_tag`. Duplicates are ignored.
+    pub tags: Vec<Symbol>,
+    /// Completion bonus escrowed on top of `amount` (0 = none). Paid to the
+    /// recipient once `end_time` is reached; refunded to the sender on an
+    /// earlier cancel. Subject to the same protocol fee as the deposit.
+    pub bonus_amount: i128,
+    /// Seconds each withdrawal sits in a holding bucket, during which the
+    /// sender may contest it, before the recipient can finalize (0 = off).
+    pub clawback_window: u64,
+    /// Fee category of the stream; see `set_category_fee_rate`.
+    pub category: StreamCategory,
+    /// Partner frontend the stream was created through, if any.
+    pub partner: Option<Symbol>,
+    /// Sender-scoped group to file the stream under, for `cancel_group`,
+    /// `top_up_group` and `get_group_streams`.
+    pub group: Option<Symbol>,
+    /// Who gets yield earned on the stream's idle principal.
+    pub yield_policy: YieldPolicy,
+    /// Condition a registered oracle must report true before the stream
+    /// starts accruing; see `check_condition`.
+    pub condition: StreamCondition,
+    /// Most the stream's net deposit may ever reach through top-ups and
+    /// donations (0 = uncapped). Enforced after fees.
+    pub deposit_cap: i128,
+    /// Seconds after the start before anything is claimable (0 = none). What
+    /// accrues meanwhile unlocks at once when the cliff passes. A cliff as
+    /// long as the duration makes the stream a timelock.
+    pub cliff_duration: u64,
+    /// Ledger time the stream starts accruing (0 = at creation). Must not be
+    /// in the past. Nothing is claimable before it, and cancelling before it
+    /// refunds the whole deposit. The cliff counts from this time.
+    pub start_time: u64,
+}
+
+impl StreamOptions {
+    /// Options for a plain stream: no cooldown, cap, tags, bonus, or clawback,
+    /// in the `General` fee category, without a partner, group, start
+    /// condition, deposit cap or cliff, starting at creation, and with any
+    /// idle yield going to the sender.
+    pub fn new(env: &Env) -> Self {
+        StreamOptions {
+            withdrawal_cooldown: 0,
+            withdrawal_cap: 0,
+            withdrawal_cap_period: 0,
+            tags: Vec::new(env),
+            bonus_amount: 0,
+            clawback_window: 0,
+            category: StreamCategory::General,
+            partner: None,
+            group: None,
+            yield_policy: YieldPolicy::Sender,
+            condition: StreamCondition::Unconditional,
+            deposit_cap: 0,
+            cliff_duration: 0,
+            start_time: 0,
+        }
+    }
+}
+
 /// Protocol-wide fee configuration.
 ///
 /// Stored as a singleton in instance storage under `DataKey::ProtocolConfig`.
@@ -58,4 +840,251 @@ pub struct ProtocolConfig {
     pub treasury: Address,
     /// Fee expressed in basis points (1 bps = 0.01%). Max: 1 000 bps = 10%.
     pub fee_rate_bps: u32,
+    /// When `true`, only allowlisted senders may create streams.
+    pub sender_allowlist_enabled: bool,
+    /// Optional registry both parties must be attested in to open a stream.
+    pub attestation_registry: Option<Address>,
+    /// Fee on each withdrawal in basis points. Max: `MAX_WITHDRAWAL_FEE_BPS`.
+    pub withdrawal_fee_bps: u32,
+    /// Smallest amount a withdrawal may claim, unless it empties the stream.
+    pub min_claim_amount: i128,
+    /// Runway in seconds below which stream writes emit `stream_depleting`
+    /// (0 = never).
+    pub depletion_warning_period: u64,
+    /// Optional contract screening payouts before they leave the contract.
+    pub compliance_oracle: Option<Address>,
+}
+
+/// Independent protocol-wide pause switches; all off by default.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseFlags {
+    /// Blocks opening new streams of any kind.
+    pub creations: bool,
+    /// Blocks top-ups, donations and other deposits into the contract.
+    pub top_ups: bool,
+    /// Blocks recipients' withdrawals and claims.
+    pub withdrawals: bool,
+}
+
+/// One token leg of a basket stream.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasketComponent {
+    /// Token being streamed in this leg.
+    pub token_address: Address,
+    /// Net deposited amount for this leg (after fee deduction).
+    pub deposited_amount: i128,
+    /// Cumulative amount of this leg already withdrawn by the recipient.
+    pub withdrawn_amount: i128,
+}
+
+/// A stream that escrows several tokens and releases them proportionally
+/// over the same schedule.
+///
+/// Stored in persistent storage under `DataKey::BasketStream(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BasketStream {
+    /// Address that created and funds this stream.
+    pub sender: Address,
+    /// Address entitled to withdraw from this stream.
+    pub recipient: Address,
+    /// Per-token legs, each accruing linearly over `duration`.
+    pub components: Vec<BasketComponent>,
+    /// Ledger timestamp at stream creation.
+    pub start_time: u64,
+    /// Seconds over which every leg is fully released.
+    pub duration: u64,
+    /// `false` once fully withdrawn or cancelled.
+    pub is_active: bool,
+    /// Protocol fee rate (bps) applied at creation.
+    pub fee_rate_bps: u32,
+}
+
+/// A pool funded by one sender from which child streams to several
+/// recipients draw their principal.
+///
+/// Stored in persistent storage under `DataKey::MasterStream(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterStream {
+    /// Address that created and funds this master.
+    pub sender: Address,
+    pub token_address: Address,
+    /// Deposit not yet accrued to any child.
+    pub balance: i128,
+    /// Sum of the active children's rates, per second.
+    pub total_rate: i128,
+    /// IDs of the active children.
+    pub children: Vec<u64>,
+    /// Ledger timestamp accrual was last settled to the children.
+    pub last_update_time: u64,
+    /// `false` once cancelled.
+    pub is_active: bool,
+    /// Protocol fee rate (bps) applied at creation.
+    pub fee_rate_bps: u32,
+}
+
+/// A stream to one recipient whose principal is drawn from a master stream.
+///
+/// Stored in persistent storage under `DataKey::ChildStream(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChildStream {
+    pub master_id: u64,
+    pub recipient: Address,
+    pub rate_per_second: i128,
+    /// Accrued from the master and not yet withdrawn, as of the master's
+    /// last settlement.
+    pub accrued: i128,
+    pub withdrawn_amount: i128,
+    /// `false` once removed or its master is cancelled.
+    pub is_active: bool,
+}
+
+/// A stream scheduled in a stable reference unit but escrowed in a token,
+/// converted at an oracle price on every withdrawal.
+///
+/// Stored in persistent storage under `DataKey::StableStream(id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StableStream {
+    /// Address that created and funds this stream.
+    pub sender: Address,
+    /// Address entitled to withdraw from this stream.
+    pub recipient: Address,
+    /// Token held in escrow and paid out.
+    pub token_address: Address,
+    /// Price oracle contract consulted at each withdrawal.
+    pub oracle: Address,
+    /// Total reference value streamed over `duration`.
+    pub reference_amount: i128,
+    /// Cumulative reference value already paid to the recipient.
+    pub reference_paid: i128,
+    /// Tokens currently held in escrow (net of fees).
+    pub escrow_balance: i128,
+    /// Ledger timestamp at stream creation.
+    pub start_time: u64,
+    /// Seconds over which `reference_amount` accrues.
+    pub duration: u64,
+    /// `false` once fully paid or cancelled.
+    pub is_active: bool,
+    /// Protocol fee rate (bps) locked in at creation.
+    pub fee_rate_bps: u32,
+}
+
+/// Non-transferable record of a stream's opening terms, held by the original
+/// recipient as proof of participation. Carries no claim rights.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamReceipt {
+    pub stream_id: u64,
+    pub holder: Address,
+    pub sender: Address,
+    pub token_address: Address,
+    pub deposited_amount: i128,
+    pub rate_per_second: i128,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub issued_at: u64,
+}
+
+/// Canonical snapshot of a stream's terms and settlement state, serialized
+/// to XDR by `get_statement_bytes`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamStatement {
+    pub stream_id: u64,
+    pub sender: Address,
+    pub recipient: Address,
+    pub token_address: Address,
+    pub rate_per_second: i128,
+    pub deposited_amount: i128,
+    pub withdrawn_amount: i128,
+    pub fee_rate_bps: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub last_withdrawal_time: u64,
+    pub is_active: bool,
+    /// Ledger time the statement was rendered at.
+    pub ledger_timestamp: u64,
+}
+
+/// Amount threshold at and above which stream creation needs approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalTier {
+    /// Smallest gross amount this tier applies to.
+    pub min_amount: i128,
+    /// Distinct approvers required at this tier.
+    pub approvals: u32,
+}
+
+/// An organization's multi-approver policy. When several tiers match an
+/// amount, the strictest one applies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPolicy {
+    pub approvers: Vec<Address>,
+    pub tiers: Vec<ApprovalTier>,
+}
+
+/// A stream creation proposed on behalf of an organization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamProposal {
+    pub org: Address,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token_address: Address,
+    pub amount: i128,
+    pub duration: u64,
+    /// Approvers who have approved so far, including the proposer.
+    pub approvals: Vec<Address>,
+    /// Approvals needed, fixed from the policy when proposed.
+    pub required_approvals: u32,
+    /// ID of the created stream once executed; 0 while pending.
+    pub stream_id: u64,
+}
+
+/// Cap on what may be committed in one token per epoch: an admin-set
+/// sender spending limit, or an owner-set manager budget.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingLimit {
+    /// Maximum gross amount per epoch.
+    pub limit: i128,
+    /// Epoch length in seconds.
+    pub epoch_length: u64,
+    /// Start of the current epoch.
+    pub epoch_start: u64,
+    /// Gross amount committed so far in the current epoch, less any
+    /// replenishment (so it may be negative).
+    pub spent: i128,
+}
+
+/// Approved partner frontend with its own fee economics.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Partner {
+    /// Receives the partner's revenue share of fees.
+    pub payout_address: Address,
+    /// Fee rate for the partner's streams, overriding the category/global rate.
+    pub fee_rate_bps: Option<u32>,
+    /// Share of every fee on the partner's streams paid to `payout_address`.
+    pub revenue_share_bps: u32,
+}
+
+/// Voting power over a stream's unvested balance, delegated to its recipient
+/// through a governance contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delegation {
+    /// Contract implementing `set_delegation`.
+    pub governance: Address,
+    /// Receives the voting power (the recipient at delegation time).
+    pub delegatee: Address,
+    /// Unvested amount delegated at the last sync.
+    pub amount: i128,
 }
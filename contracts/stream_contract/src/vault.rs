@@ -0,0 +1,175 @@
+use soroban_sdk::{contractimpl, Address, Env, Symbol};
+
+use crate::custody::Funding;
+use crate::errors::StreamError;
+use crate::events::{VaultDepositedEvent, VaultWithdrawnEvent};
+use crate::storage::{load_stream, load_vault_balance, save_stream, save_vault_balance};
+use crate::types::StreamOptions;
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Vaults ───────────────────────────────────────────────────────────────
+
+    /// Deposit `amount` of `token_address` into `sender`'s vault, from which
+    /// `create_stream_from_vault` and `top_up_from_vault` fund streams
+    /// without a token transfer each time.
+    ///
+    /// Returns the vault balance afterwards.
+    ///
+    /// # Errors
+    /// - `InvalidAmount`       — `amount` ≤ 0.
+    /// - `InvalidTokenAddress` — `token_address` is not a token contract.
+    /// - `TokenFrozen`         — the token is frozen.
+    /// - `DepositCapExceeded`  — the deposit would exceed the token's cap.
+    pub fn deposit_to_vault(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        if amount <= 0 {
+            return Err(StreamError::InvalidAmount);
+        }
+        Self::validate_token_contract(&env, &token_address)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+        Self::require_unpaused(&env, |p| p.top_ups)?;
+        Self::require_within_deposit_cap(&env, &token_address, amount)?;
+
+        Self::receive_tokens(&env, &token_address, &sender, amount);
+        let balance = load_vault_balance(&env, &sender, &token_address) + amount;
+        save_vault_balance(&env, &sender, &token_address, balance);
+
+        env.events().publish(
+            (Symbol::new(&env, "vault_deposited"), sender.clone()),
+            VaultDepositedEvent {
+                sender,
+                token: token_address,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /// Withdraw `amount` of `token_address` from `sender`'s vault back to it.
+    ///
+    /// Returns the vault balance afterwards.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` — `amount` ≤ 0 or more than the vault holds.
+    /// - `TokenFrozen`   — the token is frozen.
+    pub fn withdraw_from_vault(
+        env: Env,
+        sender: Address,
+        token_address: Address,
+        amount: i128,
+    ) -> Result<i128, StreamError> {
+        sender.require_auth();
+        Self::require_vault_covers(&env, &sender, &token_address, amount)?;
+        Self::require_token_not_frozen(&env, &token_address)?;
+
+        let balance = load_vault_balance(&env, &sender, &token_address) - amount;
+        save_vault_balance(&env, &sender, &token_address, balance);
+        Self::send_tokens(&env, &token_address, &sender, amount);
+
+        env.events().publish(
+            (Symbol::new(&env, "vault_withdrawn"), sender.clone()),
+            VaultWithdrawnEvent {
+                sender,
+                token: token_address,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /// Returns `sender`'s vault balance of `token_address`.
+    pub fn get_vault_balance(env: Env, sender: Address, token_address: Address) -> i128 {
+        load_vault_balance(&env, &sender, &token_address)
+    }
+
+    /// `create_stream_with_options`, funded from `sender`'s vault instead of
+    /// a token transfer. The vault must cover `amount` plus any bonus.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` — the vault holds less than `amount` plus the bonus.
+    /// - Same as `create_stream_with_options` otherwise.
+    pub fn create_stream_from_vault(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        amount: i128,
+        duration: u64,
+        options: StreamOptions,
+    ) -> Result<u64, StreamError> {
+        Self::require_no_approval_needed(&env, &sender, amount)?;
+        Self::require_vault_covers(
+            &env,
+            &sender,
+            &token_address,
+            amount.saturating_add(options.bonus_amount.max(0)),
+        )?;
+        Self::open_stream(
+            env,
+            None,
+            sender,
+            recipient,
+            token_address,
+            amount,
+            duration,
+            options,
+            Funding::Vault,
+        )
+    }
+
+    /// `top_up_stream`, funded from `sender`'s vault instead of a token
+    /// transfer.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` — `amount` ≤ 0 or more than the vault holds.
+    /// - Same as `top_up_stream` otherwise.
+    pub fn top_up_from_vault(
+        env: Env,
+        sender: Address,
+        stream_id: u64,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        sender.require_auth();
+
+        let mut stream = load_stream(&env, stream_id)?;
+        Self::require_vault_covers(&env, &sender, &stream.token_address, amount)?;
+        let net_amount = Self::deposit_top_up(
+            &env,
+            &sender,
+            stream_id,
+            &mut stream,
+            amount,
+            Funding::Vault,
+        )?;
+        let extension = Self::seconds_to_stream(&stream, net_amount);
+        stream.end_time = stream.end_time.saturating_add(extension);
+
+        save_stream(&env, stream_id, &stream);
+        Self::emit_top_up(&env, sender, stream_id, net_amount, &stream);
+        Ok(())
+    }
+}
+
+impl StreamContract {
+    /// Rejects a non-positive `amount` or one `sender`'s vault cannot cover.
+    fn require_vault_covers(
+        env: &Env,
+        sender: &Address,
+        token_address: &Address,
+        amount: i128,
+    ) -> Result<(), StreamError> {
+        if amount <= 0 || load_vault_balance(env, sender, token_address) < amount {
+            return Err(StreamError::InvalidAmount);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::errors::StreamError;
+use crate::storage::{is_irrevocable, set_irrevocable};
+use crate::types::{StreamCategory, StreamOptions};
+use crate::{StreamContract, StreamContractArgs, StreamContractClient};
+
+#[contractimpl]
+impl StreamContract {
+    // ─── Vesting ──────────────────────────────────────────────────────────────
+
+    /// Vest `total` to `recipient` linearly over `total_duration` seconds,
+    /// with nothing claimable for the first `cliff_duration` seconds.
+    ///
+    /// The usual grant in one call: at the cliff everything accrued so far
+    /// unlocks at once, and the rest keeps vesting until `end_time`. The
+    /// stream is filed under `StreamCategory::Vesting` with otherwise
+    /// default options. A `cliff_duration` of 0 vests from the start.
+    ///
+    /// An irrevocable grant (`revocable = false`) can never be cancelled or
+    /// paused by the sender, so the recipient is certain to vest in full.
+    /// A revocable one is cancelled like any stream: the recipient keeps
+    /// what has vested, which is nothing before the cliff.
+    ///
+    /// Returns the new stream ID.
+    ///
+    /// # Errors
+    /// - `InvalidDuration` — `total_duration` is 0 or shorter than
+    ///   `cliff_duration`.
+    /// - Same as `create_stream_with_options` otherwise.
+    pub fn create_vesting(
+        env: Env,
+        sender: Address,
+        recipient: Address,
+        token_address: Address,
+        total: i128,
+        cliff_duration: u64,
+        total_duration: u64,
+        revocable: bool,
+    ) -> Result<u64, StreamError> {
+        let options = StreamOptions {
+            category: StreamCategory::Vesting,
+            cliff_duration,
+            ..StreamOptions::new(&env)
+        };
+        let stream_id = Self::create_stream_with_options(
+            env.clone(),
+            sender,
+            recipient,
+            token_address,
+            total,
+            total_duration,
+            options,
+        )?;
+        if !revocable {
+            set_irrevocable(&env, stream_id, true);
+        }
+        Ok(stream_id)
+    }
+
+    /// Returns whether the sender may never cancel or pause `stream_id`.
+    pub fn is_irrevocable(env: Env, stream_id: u64) -> bool {
+        is_irrevocable(&env, stream_id)
+    }
+}
+
+impl StreamContract {
+    /// Rejects cancelling or pausing a stream created irrevocable.
+    pub(crate) fn require_revocable(env: &Env, stream_id: u64) -> Result<(), StreamError> {
+        if is_irrevocable(env, stream_id) {
+            return Err(StreamError::Unauthorized);
+        }
+        Ok(())
+    }
+}